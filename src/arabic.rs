@@ -0,0 +1,170 @@
+//! Romanization of the Arabic letters, with the Persian ones (simplified ALA-LC, without diacritics)
+
+use core::fmt;
+
+use crate::romanization::{chars, chars_back};
+
+/// How the Arabic letters are romanized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArabicScheme {
+    /// Arabic: `ذ` -> `dh`, `و` -> `w`, the kasra is `i` and the damma `u`
+    Arabic,
+    /// Persian: `ذ` -> `z`, `و` -> `v`, the kasra is `e` and the damma `o`
+    Persian,
+}
+
+impl fmt::Display for ArabicScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArabicScheme::Arabic => write!(f, "arabic"),
+            ArabicScheme::Persian => write!(f, "persian"),
+        }
+    }
+}
+
+/// Fathatan, the `an` ending
+const FATHATAN: char = '\u{064B}';
+
+/// Fatha, the short `a`
+const FATHA: char = '\u{064E}';
+
+/// Damma, the short `u`
+const DAMMA: char = '\u{064F}';
+
+/// Kasra, the short `i`
+const KASRA: char = '\u{0650}';
+
+/// Shadda, doubling the consonant
+const SHADDA: char = '\u{0651}';
+
+/// Check if a character is an Arabic letter
+fn is_letter(one_char: char) -> bool {
+    matches!(one_char, '\u{0621}'..='\u{063F}' | '\u{0641}'..='\u{064A}' | '\u{0671}'..='\u{06D3}')
+}
+
+/// Check if a character is a vowel sign (haraka), written over or under a letter
+fn is_haraka(one_char: char) -> bool {
+    matches!(one_char, '\u{064B}'..='\u{0652}' | '\u{0670}')
+}
+
+/// Romanization of a vowel sign
+fn haraka(mark: char, scheme: ArabicScheme) -> &'static str {
+    match (mark, scheme) {
+        (FATHATAN, _) => "an",
+        ('\u{064C}', _) => "un",
+        ('\u{064D}', _) => "in",
+        (FATHA | '\u{0670}', _) => "a",
+        (DAMMA, ArabicScheme::Arabic) => "u",
+        (DAMMA, ArabicScheme::Persian) => "o",
+        (KASRA, ArabicScheme::Arabic) => "i",
+        (KASRA, ArabicScheme::Persian) => "e",
+        _ => "",
+    }
+}
+
+/// Romanization of a letter, other than `و` and `ي`
+fn consonant(letter: char, scheme: ArabicScheme) -> Option<&'static str> {
+    let persian = scheme == ArabicScheme::Persian;
+    Some(match letter {
+        // the hamza and the ayn are dropped
+        'ء' | 'ؤ' | 'ئ' | 'ع' | 'ـ' => "",
+        'ا' | 'أ' | 'آ' | 'ٱ' | 'ى' => "a",
+        'إ' => "i",
+        'ب' => "b",
+        'پ' => "p",
+        'ت' | 'ط' => "t",
+        'ث' if persian => "s",
+        'ث' => "th",
+        'ج' => "j",
+        'چ' => "ch",
+        'ح' | 'ه' | 'ة' | 'ۀ' => "h",
+        'خ' => "kh",
+        'ذ' | 'ض' if persian => "z",
+        'ذ' => "dh",
+        'د' | 'ض' => "d",
+        'ر' => "r",
+        'ز' | 'ظ' => "z",
+        'ژ' => "zh",
+        'س' | 'ص' => "s",
+        'ش' => "sh",
+        'غ' => "gh",
+        'ف' => "f",
+        'ق' => "q",
+        'ك' | 'ک' => "k",
+        'گ' => "g",
+        'ل' => "l",
+        'م' => "m",
+        'ن' => "n",
+        _ => return None,
+    })
+}
+
+/// Romanization of `و` and `ي`, consonants at the start of a word, with a vowel sign
+/// or before a long `a`, and long vowels otherwise
+fn semivowel(
+    letter: char,
+    previous: Option<char>,
+    starts_word: bool,
+    marks: &[char],
+    next_letter: Option<char>,
+    scheme: ArabicScheme,
+) -> &'static str {
+    let (as_consonant, as_vowel, short_vowel) = match (letter, scheme) {
+        ('و', ArabicScheme::Arabic) => ("w", "u", DAMMA),
+        ('و', ArabicScheme::Persian) => ("v", "u", DAMMA),
+        _ => ("y", "i", KASRA),
+    };
+    let is_consonant = starts_word
+        || previous == Some(FATHA)
+        || marks
+            .iter()
+            .any(|mark| matches!(*mark, '\u{064B}'..='\u{0651}'))
+        || matches!(next_letter, Some('ا' | 'ى' | 'ة'));
+    if is_consonant {
+        as_consonant
+    } else if previous == Some(short_vowel) {
+        // already written by the vowel sign
+        ""
+    } else {
+        as_vowel
+    }
+}
+
+/// Romanization of an Arabic letter or vowel sign, knowing the bytes before and after it
+pub(crate) fn romanize(
+    one_char: char,
+    before: &[u8],
+    rest: &[u8],
+    scheme: ArabicScheme,
+) -> Option<String> {
+    if is_haraka(one_char) {
+        return Some(haraka(one_char, scheme).to_string());
+    }
+    let previous = chars_back(before).next();
+    let mut letters_before = chars_back(before).filter(|before_char| !is_haraka(*before_char));
+    let previous_letter = letters_before.next().filter(|letter| is_letter(*letter));
+    let starts_word = previous_letter.is_none();
+    let marks: Vec<char> = chars(rest).take_while(|mark| is_haraka(*mark)).collect();
+    let next_letter = chars(rest)
+        .find(|next_char| !is_haraka(*next_char))
+        .filter(|letter| is_letter(*letter));
+    let romanized = match one_char {
+        // the long `a` after a fatha, or carrying the `an` ending, is already written
+        'ا' if matches!(previous, Some(FATHA | FATHATAN)) || marks.contains(&FATHATAN) => "",
+        'و' | 'ي' | 'ی' => {
+            semivowel(one_char, previous, starts_word, &marks, next_letter, scheme)
+        }
+        // the article at the start of a word: `الكتاب` -> `al-ktab`
+        'ل' if matches!(previous_letter, Some('ا' | 'ٱ'))
+            && !letters_before.next().is_some_and(is_letter)
+            && next_letter.is_some() =>
+        {
+            "l-"
+        }
+        _ => consonant(one_char, scheme)?,
+    };
+    if marks.contains(&SHADDA) {
+        return Some(romanized.repeat(2));
+    }
+    Some(romanized.to_string())
+}
@@ -0,0 +1,141 @@
+//! Case modes, applied to the cleaned stem once transliterated
+
+use core::fmt;
+
+/// How the letters of the stem are cased, like `My File` -> `my_file` in snake case
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaseMode {
+    /// `lower`: `My_File` -> `my_file`
+    Lower,
+    /// `upper`: `My_File` -> `MY_FILE`
+    Upper,
+    /// `snake`: `MyFile-name` -> `my_file_name`
+    Snake,
+    /// `kebab`: `MyFile_name` -> `my-file-name`
+    Kebab,
+    /// `camel`: `my_file_name` -> `myFileName`
+    Camel,
+    /// `pascal`: `my_file_name` -> `MyFileName`
+    Pascal,
+    /// `title`: `my-file_NAME` -> `My_File_Name`
+    Title,
+}
+
+/// Every case mode, in the order of the help message
+pub const CASE_MODES: &[CaseMode] = &[
+    CaseMode::Lower,
+    CaseMode::Upper,
+    CaseMode::Snake,
+    CaseMode::Kebab,
+    CaseMode::Camel,
+    CaseMode::Pascal,
+    CaseMode::Title,
+];
+
+/// Split a part of a stem in words, on `_`, `-` and the case changes: `HTMLParser_v2` -> `HTML`, `Parser`, `v2`
+fn split_words(part: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    for chunk in part.split(['_', '-']).filter(|chunk| !chunk.is_empty()) {
+        let bytes = chunk.as_bytes();
+        let mut start = 0;
+        for index in 1..bytes.len() {
+            let (previous, current) = (bytes[index - 1], bytes[index]);
+            let lower_to_upper = previous.is_ascii_lowercase() && current.is_ascii_uppercase();
+            // the last capital of an acronym starts the next word: `HTMLParser`
+            let acronym_end = previous.is_ascii_uppercase()
+                && current.is_ascii_uppercase()
+                && bytes.get(index + 1).is_some_and(u8::is_ascii_lowercase);
+            if lower_to_upper || acronym_end {
+                words.push(&chunk[start..index]);
+                start = index;
+            }
+        }
+        words.push(&chunk[start..]);
+    }
+    words
+}
+
+/// Capitalize a word: `fILE` -> `File`
+fn capitalize(word: &str) -> String {
+    let mut capitalized = word.to_ascii_lowercase();
+    if let Some(first) = capitalized.get_mut(..1) {
+        first.make_ascii_uppercase();
+    }
+    capitalized
+}
+
+impl CaseMode {
+    /// Get the case mode from its name
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<CaseMode> {
+        CASE_MODES
+            .iter()
+            .find(|case| case.name().eq_ignore_ascii_case(name))
+            .copied()
+    }
+
+    /// Name of the case mode
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            CaseMode::Lower => "lower",
+            CaseMode::Upper => "upper",
+            CaseMode::Snake => "snake",
+            CaseMode::Kebab => "kebab",
+            CaseMode::Camel => "camel",
+            CaseMode::Pascal => "pascal",
+            CaseMode::Title => "title",
+        }
+    }
+
+    /// Apply the case to a part of a stem, without dots
+    fn apply_part(self, part: &str) -> String {
+        let words = split_words(part);
+        match self {
+            CaseMode::Lower => part.to_ascii_lowercase(),
+            CaseMode::Upper => part.to_ascii_uppercase(),
+            CaseMode::Snake => words.join("_").to_ascii_lowercase(),
+            CaseMode::Kebab => words.join("-").to_ascii_lowercase(),
+            CaseMode::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(index, word)| match index {
+                    0 => word.to_ascii_lowercase(),
+                    _ => capitalize(word),
+                })
+                .collect(),
+            CaseMode::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+            CaseMode::Title => words
+                .iter()
+                .map(|word| capitalize(word))
+                .collect::<Vec<String>>()
+                .join("_"),
+        }
+    }
+
+    /// Apply the case to a cleaned stem, only to its ASCII letters: the dots and the separators
+    /// at the start and at the end of the stem, like in `__init__`, are kept
+    #[must_use]
+    pub fn apply(self, stem: &str) -> String {
+        let is_separator = |one_char: char| matches!(one_char, '_' | '-');
+        stem.split('.')
+            .map(|part| {
+                let middle = part.trim_matches(is_separator);
+                let start = part.len() - part.trim_start_matches(is_separator).len();
+                format!(
+                    "{}{}{}",
+                    &part[..start],
+                    self.apply_part(middle),
+                    &part[start + middle.len()..]
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(".")
+    }
+}
+
+impl fmt::Display for CaseMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
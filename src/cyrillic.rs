@@ -0,0 +1,210 @@
+//! Romanization of the Cyrillic letters, with the Ukrainian, Belarusian, Serbian,
+//! Macedonian and Bulgarian ones
+
+use core::fmt;
+
+use crate::romanization::{apply_case, chars, chars_back, first_char, last_char, to_lower};
+
+/// How the Cyrillic letters are romanized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CyrillicScheme {
+    /// GOST 7.79 system B, the ASCII transliteration of ISO 9: `щ` -> `shh`, `х` -> `x`
+    Gost779B,
+    /// BGN/PCGN: `щ` -> `shch`, `х` -> `kh`, with the Ukrainian and Belarusian spellings: `Київ` -> `Kyiv`
+    BgnPcgn,
+}
+
+impl fmt::Display for CyrillicScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CyrillicScheme::Gost779B => write!(f, "gost-7.79-b"),
+            CyrillicScheme::BgnPcgn => write!(f, "bgn-pcgn"),
+        }
+    }
+}
+
+/// Language of a Cyrillic word, for the letters spelled differently in Ukrainian and Belarusian
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WordLanguage {
+    /// Ukrainian, with `ґ`, `є`, `ї`, or `і` without the letters of Russian and Belarusian
+    Ukrainian,
+    /// Belarusian, with `ў`, or `і` with `ы`, `э`, `ё` or `ъ`
+    Belarusian,
+    /// Russian and the other languages
+    Other,
+}
+
+/// Language of the word of a lowercase letter, from the letters of the word
+fn word_language(letter: char, before: &[u8], rest: &[u8]) -> WordLanguage {
+    let is_word_letter = |word_letter: &char| is_cyrillic(*word_letter);
+    let word = chars_back(before)
+        .map(to_lower)
+        .take_while(is_word_letter)
+        .chain(std::iter::once(letter))
+        .chain(chars(rest).map(to_lower).take_while(is_word_letter))
+        .collect::<String>();
+    if word.contains('ў') {
+        WordLanguage::Belarusian
+    } else if word.contains(['ґ', 'є', 'ї']) {
+        WordLanguage::Ukrainian
+    } else if word.contains('і') {
+        if word.contains(['ы', 'э', 'ё', 'ъ']) {
+            WordLanguage::Belarusian
+        } else {
+            WordLanguage::Ukrainian
+        }
+    } else {
+        WordLanguage::Other
+    }
+}
+
+/// Check if a lowercase letter starts a `ye` in BGN/PCGN: after a vowel, `й`, `ъ` or `ь`
+fn is_softening(letter: char) -> bool {
+    "аеёиоуыэюяєіїйъь".contains(letter)
+}
+
+/// Check if a lowercase letter is Cyrillic
+fn is_cyrillic(letter: char) -> bool {
+    ('\u{0400}'..='\u{04FF}').contains(&letter)
+}
+
+/// Romanization of the lowercase letters common to both schemes
+fn common(letter: char) -> Option<&'static str> {
+    Some(match letter {
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' | 'ґ' => "g",
+        'д' => "d",
+        'ж' => "zh",
+        'з' => "z",
+        'и' | 'і' => "i",
+        'ј' => "j",
+        'к' => "k",
+        'л' => "l",
+        'м' => "m",
+        'н' => "n",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'у' => "u",
+        'ф' | 'ѳ' => "f",
+        'ч' => "ch",
+        'ш' => "sh",
+        'ъ' | 'ь' => "",
+        'ы' | 'ѵ' => "y",
+        'э' => "e",
+        'ю' => "yu",
+        'я' => "ya",
+        'є' | 'ѣ' => "ye",
+        'ї' => "yi",
+        _ => return None,
+    })
+}
+
+/// Romanization of a lowercase letter with BGN/PCGN
+fn bgn_pcgn(letter: char, previous: Option<char>) -> Option<&'static str> {
+    let starts_ye = previous.is_none_or(|previous_letter| {
+        !is_cyrillic(previous_letter) || is_softening(previous_letter)
+    });
+    Some(match letter {
+        'е' | 'ё' if starts_ye => "ye",
+        'е' | 'ё' => "e",
+        'й' => "y",
+        'х' => "kh",
+        'ц' => "ts",
+        'щ' => "shch",
+        'ў' => "w",
+        'ђ' => "dj",
+        'ћ' => "c",
+        'љ' => "lj",
+        'њ' => "nj",
+        'ѓ' => "gj",
+        'ќ' => "kj",
+        'џ' | 'ѕ' => "dz",
+        _ => return common(letter),
+    })
+}
+
+/// Romanization of a lowercase Ukrainian letter with BGN/PCGN, the Ukrainian national system:
+/// `ї` -> `yi` at the start of a word, `i` after
+fn bgn_pcgn_ukrainian(letter: char, previous: Option<char>) -> Option<&'static str> {
+    let starts_word = previous.is_none_or(|previous_letter| !is_cyrillic(previous_letter));
+    Some(match letter {
+        // `зг` is `zgh`, not `zh`
+        'г' if previous == Some('з') => "gh",
+        'г' => "h",
+        'е' => "e",
+        'и' => "y",
+        'є' if starts_word => "ye",
+        'є' => "ie",
+        'ї' if starts_word => "yi",
+        'й' if starts_word => "y",
+        'ї' | 'й' => "i",
+        'ю' if starts_word => "yu",
+        'ю' => "iu",
+        'я' if starts_word => "ya",
+        'я' => "ia",
+        _ => return bgn_pcgn(letter, previous),
+    })
+}
+
+/// Romanization of a lowercase letter with GOST 7.79 system B
+fn gost_7_79_b(letter: char, next: Option<char>) -> Option<&'static str> {
+    Some(match letter {
+        'е' => "e",
+        'ё' => "yo",
+        'й' => "j",
+        'х' => "x",
+        // `c` before `i`, `e`, `y` and `j`
+        'ц' if next.is_some_and(|next_letter| {
+            matches!(next_letter, 'и' | 'і' | 'е' | 'є' | 'ы' | 'й' | 'ј')
+        }) =>
+        {
+            "c"
+        }
+        'ц' => "cz",
+        'щ' => "shh",
+        'ў' => "u",
+        'ђ' => "d",
+        'ћ' => "c",
+        'џ' => "dh",
+        'љ' => "l",
+        'њ' => "n",
+        'ѓ' => "g",
+        'ќ' => "k",
+        'ѕ' => "z",
+        _ => return common(letter),
+    })
+}
+
+/// Romanization of a Cyrillic letter, knowing the bytes before and after it, keeping its case
+pub(crate) fn romanize(
+    one_char: char,
+    before: &[u8],
+    rest: &[u8],
+    scheme: CyrillicScheme,
+) -> Option<String> {
+    let previous = last_char(before);
+    let next = first_char(rest);
+    let letter = to_lower(one_char);
+    let language = word_language(letter, before, rest);
+    let romanized = match (scheme, language) {
+        // `и` is `y'` in Ukrainian, without the apostrophe
+        (CyrillicScheme::Gost779B, WordLanguage::Ukrainian) if letter == 'и' => "y",
+        (CyrillicScheme::Gost779B, _) => gost_7_79_b(letter, next.map(to_lower))?,
+        (CyrillicScheme::BgnPcgn, WordLanguage::Ukrainian) => {
+            bgn_pcgn_ukrainian(letter, previous.map(to_lower))?
+        }
+        (CyrillicScheme::BgnPcgn, WordLanguage::Belarusian) if letter == 'г' => "h",
+        (CyrillicScheme::BgnPcgn, _) => bgn_pcgn(letter, previous.map(to_lower))?,
+    };
+    Some(apply_case(
+        romanized,
+        one_char.is_uppercase(),
+        previous,
+        next,
+    ))
+}
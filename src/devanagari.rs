@@ -0,0 +1,106 @@
+//! Romanization of the Devanagari letters (ISO 15919, without diacritics)
+
+use crate::romanization::chars;
+
+/// Nukta, the dot under a consonant for the sounds of the borrowed words
+const NUKTA: char = '\u{093C}';
+
+/// Virama, removing the inherent `a` of a consonant
+const VIRAMA: char = '\u{094D}';
+
+/// Romanization of a consonant, without its inherent `a`
+fn consonant(letter: char) -> Option<&'static str> {
+    Some(match letter {
+        'क' => "k",
+        'ख' | '\u{0959}' => "kh",
+        'ग' => "g",
+        'घ' | '\u{095A}' => "gh",
+        'ङ' | 'ञ' | 'ण' | 'न' | 'ऩ' => "n",
+        'च' => "c",
+        'छ' => "ch",
+        'ज' => "j",
+        'झ' => "jh",
+        'ट' | 'त' => "t",
+        'ठ' | 'थ' => "th",
+        'ड' | 'द' => "d",
+        'ढ' | 'ध' => "dh",
+        'प' => "p",
+        'फ' => "ph",
+        'ब' => "b",
+        'भ' => "bh",
+        'म' => "m",
+        'य' | '\u{095F}' => "y",
+        'र' | 'ऱ' | '\u{095C}' => "r",
+        '\u{095D}' => "rh",
+        'ल' | 'ळ' | 'ऴ' => "l",
+        'व' => "v",
+        'श' | 'ष' | 'स' => "s",
+        'ह' => "h",
+        '\u{0958}' => "q",
+        '\u{095B}' => "z",
+        '\u{095E}' => "f",
+        _ => return None,
+    })
+}
+
+/// Romanization of a consonant followed by a nukta
+fn with_nukta(letter: char) -> Option<&'static str> {
+    match letter {
+        'क' => consonant('\u{0958}'),
+        'ख' => consonant('\u{0959}'),
+        'ग' => consonant('\u{095A}'),
+        'ज' => consonant('\u{095B}'),
+        'ड' => consonant('\u{095C}'),
+        'ढ' => consonant('\u{095D}'),
+        'फ' => consonant('\u{095E}'),
+        'य' => consonant('\u{095F}'),
+        _ => None,
+    }
+}
+
+/// Romanization of a vowel, written as a letter or as a sign after a consonant
+fn vowel(letter: char) -> Option<&'static str> {
+    Some(match letter {
+        'अ' | 'आ' | 'ा' => "a",
+        'इ' | 'ई' | 'ि' | 'ी' => "i",
+        'उ' | 'ऊ' | 'ु' | 'ू' => "u",
+        'ऋ' | 'ॠ' | 'ृ' | 'ॄ' => "r",
+        'ऌ' | 'ॡ' | 'ॢ' | 'ॣ' => "l",
+        'ए' | 'ऍ' | 'ऎ' | 'े' | 'ॅ' | 'ॆ' => "e",
+        'ऐ' | 'ै' => "ai",
+        'ओ' | 'ऑ' | 'ऒ' | 'ो' | 'ॉ' | 'ॊ' => "o",
+        'औ' | 'ौ' => "au",
+        _ => return None,
+    })
+}
+
+/// Check if a character is a vowel sign or the virama, replacing the inherent `a`
+fn replaces_inherent(sign: char) -> bool {
+    matches!(sign, '\u{093A}' | '\u{093B}' | '\u{093E}'..='\u{094F}' | '\u{0955}'..='\u{0957}' | '\u{0962}' | '\u{0963}')
+}
+
+/// Romanization of a Devanagari letter or sign, knowing the bytes after it
+pub(crate) fn romanize(one_char: char, rest: &[u8]) -> Option<String> {
+    let mut next_chars = chars(rest).peekable();
+    let has_nukta = next_chars.next_if_eq(&NUKTA).is_some();
+    if let Some(romanized) = consonant(one_char) {
+        let romanized = with_nukta(one_char)
+            .filter(|_| has_nukta)
+            .unwrap_or(romanized);
+        if next_chars.next().is_some_and(replaces_inherent) {
+            return Some(romanized.to_string());
+        }
+        return Some(format!("{romanized}a"));
+    }
+    Some(
+        match one_char {
+            // the nasalization, the visarga and the avagraha
+            '\u{0900}'..='\u{0902}' => "m",
+            '\u{0903}' => "h",
+            NUKTA | VIRAMA | 'ऽ' | '\u{0951}'..='\u{0954}' | '\u{0970}' => "",
+            'ॐ' => "om",
+            _ => vowel(one_char)?,
+        }
+        .to_string(),
+    )
+}
@@ -0,0 +1,284 @@
+//! Names of the emoji sequences: skin tones, ZWJ sequences, flags, keycaps and variation selectors
+
+use crate::emoji_names::emoji_name;
+use crate::romanization::first_char;
+use crate::table::builtin_mapping;
+
+/// Zero width joiner, joining emoji in a sequence
+const ZWJ: char = '\u{200D}';
+
+/// Variation selector asking for the text style
+const TEXT_STYLE: char = '\u{FE0E}';
+
+/// Variation selector asking for the emoji style
+const EMOJI_STYLE: char = '\u{FE0F}';
+
+/// Combining enclosing keycap, after a digit, `#` or `*`
+const KEYCAP: char = '\u{20E3}';
+
+/// Cancel tag, ending a tag sequence
+const CANCEL_TAG: char = '\u{E007F}';
+
+/// Black flag, the base of the subdivision flags
+const BLACK_FLAG: char = '🏴';
+
+/// Known ZWJ sequences whose name is not the names of their parts
+const ZWJ_SEQUENCES: &[(&[char], &str)] = &[
+    (&['🏳', '🌈'], "rainbow_flag"),
+    (&['🏳', '⚧'], "transgender_flag"),
+    (&['🏴', '☠'], "pirate_flag"),
+    (&['❤', '🔥'], "heart_on_fire"),
+    (&['❤', '🩹'], "mending_heart"),
+    (&['👁', '🗨'], "eye_in_speech_bubble"),
+    (&['🐕', '🦺'], "service_dog"),
+    (&['🐈', '⬛'], "black_cat"),
+    (&['🐻', '❄'], "polar_bear"),
+    (&['🐦', '⬛'], "black_bird"),
+    (&['🐦', '🔥'], "phoenix"),
+    (&['😶', '🌫'], "face_in_clouds"),
+    (&['😮', '💨'], "face_exhaling"),
+    (&['😵', '💫'], "face_with_spiral_eyes"),
+    (&['🧑', '🎄'], "mx_claus"),
+    (&['🧑', '🤝', '🧑'], "people_holding_hands"),
+];
+
+/// Roles of a person, after a person, a man or a woman and a ZWJ: `👩‍💻` -> `woman_technologist`
+const ROLES: &[(char, &str)] = &[
+    ('💻', "technologist"),
+    ('⚕', "health_worker"),
+    ('🎓', "student"),
+    ('🏫', "teacher"),
+    ('⚖', "judge"),
+    ('🌾', "farmer"),
+    ('🍳', "cook"),
+    ('🔧', "mechanic"),
+    ('🏭', "factory_worker"),
+    ('💼', "office_worker"),
+    ('🔬', "scientist"),
+    ('🎤', "singer"),
+    ('🎨', "artist"),
+    ('✈', "pilot"),
+    ('🚀', "astronaut"),
+    ('🚒', "firefighter"),
+    ('🍼', "person_feeding_baby"),
+    ('🦯', "person_with_white_cane"),
+    ('🦼', "person_in_motorized_wheelchair"),
+    ('🦽', "person_in_manual_wheelchair"),
+];
+
+/// Members of a family, and their name in a family
+const FAMILY: &[(char, &str)] = &[
+    ('👨', "man"),
+    ('👩', "woman"),
+    ('🧑', "adult"),
+    ('👦', "boy"),
+    ('👧', "girl"),
+    ('🧒', "child"),
+    ('👶', "baby"),
+];
+
+/// Check if a character is a skin tone modifier
+fn is_skin_tone(one_char: char) -> bool {
+    matches!(one_char, '\u{1F3FB}'..='\u{1F3FF}')
+}
+
+/// Letter of a regional indicator, like `🇫` -> `f`
+fn regional_letter(one_char: char) -> Option<char> {
+    let offset = u32::from(one_char)
+        .checked_sub(0x1F1E6)
+        .filter(|offset| *offset < 26)?;
+    char::from_u32(u32::from('a') + offset)
+}
+
+/// Letter of a tag, like `U+E0067` -> `g`
+fn tag_letter(one_char: char) -> Option<char> {
+    let offset = u32::from(one_char).checked_sub(0xE0000)?;
+    char::from_u32(offset).filter(char::is_ascii_graphic)
+}
+
+/// Reader of the characters of a sequence, counting the bytes read
+struct Reader<'a> {
+    /// the bytes, from the start of the sequence
+    bytes: &'a [u8],
+    /// the number of bytes read
+    len: usize,
+}
+
+impl Reader<'_> {
+    /// Next character, without reading it
+    fn peek(&self) -> Option<char> {
+        first_char(&self.bytes[self.len..])
+    }
+
+    /// Read the next character if it matches
+    fn next_if(&mut self, matches: impl Fn(char) -> bool) -> Option<char> {
+        let next = self.peek().filter(|next| matches(*next))?;
+        self.len += next.len_utf8();
+        Some(next)
+    }
+}
+
+/// Name of a person for a gender, like `person_running` -> `woman_running`
+fn gendered(name: &str, gender: &str, plural: &str) -> String {
+    if let Some(rest) = name.strip_prefix("person_") {
+        format!("{gender}_{rest}")
+    } else if let Some(rest) = name.strip_prefix("people_") {
+        format!("{plural}_{rest}")
+    } else if let Some(rest) = name.strip_suffix("_person") {
+        format!("{rest}_{gender}")
+    } else {
+        format!("{gender}_{name}")
+    }
+}
+
+/// Name of a person in a couple
+fn partner(one_char: char) -> Option<&'static str> {
+    match one_char {
+        '👨' => Some("man"),
+        '👩' => Some("woman"),
+        '🧑' => Some("person"),
+        _ => None,
+    }
+}
+
+/// Name of the emoji of a ZWJ sequence, without the skin tones
+fn sequence_name(emoji: &[char]) -> String {
+    let name = |one_char: char| emoji_name(one_char).unwrap_or_default();
+    if let Some((_, known)) = ZWJ_SEQUENCES
+        .iter()
+        .find(|(sequence, _)| *sequence == emoji)
+    {
+        return (*known).to_string();
+    }
+    let family_name = |one_char: char| {
+        FAMILY
+            .iter()
+            .find(|(member, _)| *member == one_char)
+            .map(|(_, member_name)| *member_name)
+    };
+    match emoji {
+        [one_emoji] => name(*one_emoji).to_string(),
+        [person, '♀'] => gendered(name(*person), "woman", "women"),
+        [person, '♂'] => gendered(name(*person), "man", "men"),
+        [person, role] if partner(*person).is_some() => {
+            match ROLES.iter().find(|(role_emoji, _)| role_emoji == role) {
+                Some((_, role_name)) => match *person {
+                    '👨' => gendered(role_name, "man", "men"),
+                    '👩' => gendered(role_name, "woman", "women"),
+                    _ => (*role_name).to_string(),
+                },
+                None => format!("{}_{}", name(*person), name(*role)),
+            }
+        }
+        [first, '❤', second] if partner(*first).is_some() && partner(*second).is_some() => {
+            format!(
+                "couple_with_heart_{}_{}",
+                partner(*first).unwrap_or_default(),
+                partner(*second).unwrap_or_default()
+            )
+        }
+        [first, '❤', '💋', second] if partner(*first).is_some() && partner(*second).is_some() =>
+        {
+            format!(
+                "kiss_{}_{}",
+                partner(*first).unwrap_or_default(),
+                partner(*second).unwrap_or_default()
+            )
+        }
+        _ if emoji.iter().all(|member| family_name(*member).is_some()) => {
+            let members: Vec<&str> = emoji
+                .iter()
+                .filter_map(|member| family_name(*member))
+                .collect();
+            format!("family_{}", members.join("_"))
+        }
+        _ => emoji
+            .iter()
+            .map(|one_emoji| name(*one_emoji))
+            .collect::<Vec<&str>>()
+            .join("_"),
+    }
+}
+
+/// Name of a subdivision flag, from its tags: `gbsct` -> `flag_scotland`
+fn subdivision_flag(tags: &str) -> String {
+    match tags {
+        "gbeng" => "flag_england".to_string(),
+        "gbsct" => "flag_scotland".to_string(),
+        "gbwls" => "flag_wales".to_string(),
+        _ => format!("flag_{tags}"),
+    }
+}
+
+/// Name of the emoji sequence at the start of the bytes, with its length in bytes:
+/// `👩🏽‍💻` -> `woman_technologist_medium_skin_tone`, `🇫🇷` -> `flag_fr`, `1️⃣` -> `keycap_1`
+pub(crate) fn emoji_sequence(bytes: &[u8]) -> Option<(usize, String)> {
+    let mut reader = Reader { bytes, len: 0 };
+    let first = reader.peek()?;
+    if matches!(first, '0'..='9' | '#' | '*') {
+        reader.next_if(|_| true);
+        reader.next_if(|next| next == EMOJI_STYLE);
+        reader.next_if(|next| next == KEYCAP)?;
+        let key = match first {
+            '#' => "number_sign".to_string(),
+            '*' => "asterisk".to_string(),
+            digit => digit.to_string(),
+        };
+        return Some((reader.len, format!("keycap_{key}")));
+    }
+    if let Some(letter) = regional_letter(first) {
+        reader.next_if(|_| true);
+        // a lone regional indicator is its letter
+        let name = match reader.next_if(|next| regional_letter(next).is_some()) {
+            Some(second) => format!("flag_{letter}{}", regional_letter(second)?),
+            None => letter.to_string(),
+        };
+        return Some((reader.len, name));
+    }
+    let mut emoji = Vec::new();
+    let mut skin_tones = Vec::new();
+    let mut emoji_style = false;
+    loop {
+        let one_emoji = reader.next_if(|next| emoji_name(next).is_some())?;
+        if reader.next_if(|next| next == TEXT_STYLE || next == EMOJI_STYLE) == Some(EMOJI_STYLE) {
+            emoji_style = true;
+        }
+        if !is_skin_tone(one_emoji) {
+            skin_tones.extend(reader.next_if(is_skin_tone));
+        }
+        if one_emoji == BLACK_FLAG && emoji.is_empty() {
+            let mut tags = String::new();
+            while let Some(tag) =
+                reader.next_if(|next| next != CANCEL_TAG && tag_letter(next).is_some())
+            {
+                tags.extend(tag_letter(tag));
+            }
+            if !tags.is_empty() && reader.next_if(|next| next == CANCEL_TAG).is_some() {
+                return Some((reader.len, subdivision_flag(&tags)));
+            }
+        }
+        emoji.push(one_emoji);
+        // a ZWJ goes on with the sequence only when an emoji follows it
+        let after_zwj = &bytes[reader.len..];
+        let joined = first_char(after_zwj) == Some(ZWJ)
+            && first_char(&after_zwj[ZWJ.len_utf8()..])
+                .is_some_and(|next| emoji_name(next).is_some());
+        if !joined {
+            break;
+        }
+        reader.len += ZWJ.len_utf8();
+    }
+    if let [one_emoji] = emoji[..] {
+        // a character with a mapping, like `©`, is an emoji only in the emoji style
+        if skin_tones.is_empty() && !emoji_style && builtin_mapping(one_emoji).is_some() {
+            return None;
+        }
+    }
+    let mut name = sequence_name(&emoji);
+    skin_tones.dedup();
+    for skin_tone in skin_tones {
+        name.push('_');
+        name.push_str(emoji_name(skin_tone).unwrap_or_default());
+    }
+    Some((reader.len, name))
+}
@@ -0,0 +1,552 @@
+//! Names of the emoji, used by the emoji mode
+
+/// Slugified names of the emoji, sorted by character:
+/// the CLDR short names of the common ones, the Unicode names of the others
+#[rustfmt::skip]
+const EMOJI_NAMES: &[(char, &str)] = &[
+    ('\u{00A9}', "copyright"), ('\u{00AE}', "registered"), ('\u{203C}', "double_exclamation_mark"),
+    ('\u{2049}', "exclamation_question_mark"), ('\u{2122}', "trade_mark"),
+    ('\u{2139}', "information"), ('\u{2194}', "left_right_arrow"), ('\u{2195}', "up_down_arrow"),
+    ('\u{2196}', "up_left_arrow"), ('\u{2197}', "up_right_arrow"), ('\u{2198}', "down_right_arrow"),
+    ('\u{2199}', "down_left_arrow"), ('\u{21A9}', "right_arrow_curving_left"),
+    ('\u{21AA}', "left_arrow_curving_right"), ('\u{231A}', "watch"), ('\u{231B}', "hourglass_done"),
+    ('\u{2328}', "keyboard"), ('\u{23CF}', "eject_button"), ('\u{23E9}', "fast_forward_button"),
+    ('\u{23EA}', "fast_reverse_button"), ('\u{23EB}', "fast_up_button"),
+    ('\u{23EC}', "fast_down_button"), ('\u{23ED}', "next_track_button"),
+    ('\u{23EE}', "last_track_button"), ('\u{23EF}', "play_or_pause_button"),
+    ('\u{23F0}', "alarm_clock"), ('\u{23F1}', "stopwatch"), ('\u{23F2}', "timer_clock"),
+    ('\u{23F3}', "hourglass_not_done"), ('\u{23F8}', "pause_button"), ('\u{23F9}', "stop_button"),
+    ('\u{23FA}', "record_button"), ('\u{24C2}', "circled_m"), ('\u{25AA}', "black_small_square"),
+    ('\u{25AB}', "white_small_square"), ('\u{25B6}', "play_button"), ('\u{25C0}', "reverse_button"),
+    ('\u{25FB}', "white_medium_square"), ('\u{25FC}', "black_medium_square"),
+    ('\u{25FD}', "white_medium_small_square"), ('\u{25FE}', "black_medium_small_square"),
+    ('\u{2600}', "sun"), ('\u{2601}', "cloud"), ('\u{2602}', "umbrella"), ('\u{2603}', "snowman"),
+    ('\u{2604}', "comet"), ('\u{260E}', "telephone"), ('\u{2611}', "ballot_box_with_check"),
+    ('\u{2614}', "umbrella_with_rain_drops"), ('\u{2615}', "hot_beverage"),
+    ('\u{2618}', "shamrock"), ('\u{261D}', "index_pointing_up"),
+    ('\u{2620}', "skull_and_crossbones"), ('\u{2622}', "radioactive"), ('\u{2623}', "biohazard"),
+    ('\u{2626}', "orthodox_cross"), ('\u{262A}', "star_and_crescent"), ('\u{262E}', "peace_symbol"),
+    ('\u{262F}', "yin_yang"), ('\u{2638}', "wheel_of_dharma"), ('\u{2639}', "frowning_face"),
+    ('\u{263A}', "smiling_face"), ('\u{2640}', "female_sign"), ('\u{2642}', "male_sign"),
+    ('\u{2648}', "aries"), ('\u{2649}', "taurus"), ('\u{264A}', "gemini"), ('\u{264B}', "cancer"),
+    ('\u{264C}', "leo"), ('\u{264D}', "virgo"), ('\u{264E}', "libra"), ('\u{264F}', "scorpius"),
+    ('\u{2650}', "sagittarius"), ('\u{2651}', "capricorn"), ('\u{2652}', "aquarius"),
+    ('\u{2653}', "pisces"), ('\u{265F}', "black_chess_pawn"), ('\u{2660}', "spade_suit"),
+    ('\u{2663}', "club_suit"), ('\u{2665}', "heart_suit"), ('\u{2666}', "diamond_suit"),
+    ('\u{2668}', "hot_springs"), ('\u{267B}', "recycling_symbol"), ('\u{267E}', "infinity"),
+    ('\u{267F}', "wheelchair_symbol"), ('\u{2692}', "hammer_and_pick"), ('\u{2693}', "anchor"),
+    ('\u{2694}', "crossed_swords"), ('\u{2695}', "medical_symbol"), ('\u{2696}', "balance_scale"),
+    ('\u{2697}', "alembic"), ('\u{2699}', "gear"), ('\u{269B}', "atom_symbol"),
+    ('\u{269C}', "fleur_de_lis"), ('\u{26A0}', "warning"), ('\u{26A1}', "high_voltage"),
+    ('\u{26A7}', "transgender_symbol"), ('\u{26AA}', "white_circle"), ('\u{26AB}', "black_circle"),
+    ('\u{26B0}', "coffin"), ('\u{26B1}', "funeral_urn"), ('\u{26BD}', "soccer_ball"),
+    ('\u{26BE}', "baseball"), ('\u{26C4}', "snowman_without_snow"),
+    ('\u{26C5}', "sun_behind_cloud"), ('\u{26C8}', "cloud_with_lightning_and_rain"),
+    ('\u{26CE}', "ophiuchus"), ('\u{26CF}', "pick"), ('\u{26D1}', "rescue_workers_helmet"),
+    ('\u{26D3}', "chains"), ('\u{26D4}', "no_entry"), ('\u{26E9}', "shinto_shrine"),
+    ('\u{26EA}', "church"), ('\u{26F0}', "mountain"), ('\u{26F1}', "umbrella_on_ground"),
+    ('\u{26F2}', "fountain"), ('\u{26F3}', "flag_in_hole"), ('\u{26F4}', "ferry"),
+    ('\u{26F5}', "sailboat"), ('\u{26F7}', "skier"), ('\u{26F8}', "ice_skate"),
+    ('\u{26F9}', "person_bouncing_ball"), ('\u{26FA}', "tent"), ('\u{26FD}', "fuel_pump"),
+    ('\u{2702}', "scissors"), ('\u{2705}', "check_mark_button"), ('\u{2708}', "airplane"),
+    ('\u{2709}', "envelope"), ('\u{270A}', "raised_fist"), ('\u{270B}', "raised_hand"),
+    ('\u{270C}', "victory_hand"), ('\u{270D}', "writing_hand"), ('\u{270F}', "pencil"),
+    ('\u{2712}', "black_nib"), ('\u{2714}', "check_mark"), ('\u{2716}', "multiply"),
+    ('\u{271D}', "latin_cross"), ('\u{2721}', "star_of_david"), ('\u{2728}', "sparkles"),
+    ('\u{2733}', "eight_spoked_asterisk"), ('\u{2734}', "eight_pointed_star"),
+    ('\u{2744}', "snowflake"), ('\u{2747}', "sparkle"), ('\u{274C}', "cross_mark"),
+    ('\u{274E}', "cross_mark_button"), ('\u{2753}', "red_question_mark"),
+    ('\u{2754}', "white_question_mark"), ('\u{2755}', "white_exclamation_mark"),
+    ('\u{2757}', "red_exclamation_mark"), ('\u{2763}', "heart_exclamation"),
+    ('\u{2764}', "red_heart"), ('\u{2795}', "plus"), ('\u{2796}', "minus"), ('\u{2797}', "divide"),
+    ('\u{27A1}', "right_arrow"), ('\u{27B0}', "curly_loop"), ('\u{27BF}', "double_curly_loop"),
+    ('\u{2934}', "right_arrow_curving_up"), ('\u{2935}', "right_arrow_curving_down"),
+    ('\u{2B05}', "left_arrow"), ('\u{2B06}', "up_arrow"), ('\u{2B07}', "down_arrow"),
+    ('\u{2B1B}', "black_large_square"), ('\u{2B1C}', "white_large_square"), ('\u{2B50}', "star"),
+    ('\u{2B55}', "hollow_red_circle"), ('\u{3030}', "wavy_dash"),
+    ('\u{303D}', "part_alternation_mark"), ('\u{3297}', "japanese_congratulations_button"),
+    ('\u{3299}', "japanese_secret_button"), ('\u{1F004}', "mahjong_red_dragon"),
+    ('\u{1F0CF}', "joker"), ('\u{1F170}', "a_button_blood_type"),
+    ('\u{1F171}', "b_button_blood_type"), ('\u{1F17E}', "o_button_blood_type"),
+    ('\u{1F17F}', "p_button"), ('\u{1F18E}', "ab_button_blood_type"), ('\u{1F191}', "cl_button"),
+    ('\u{1F192}', "cool_button"), ('\u{1F193}', "free_button"), ('\u{1F194}', "id_button"),
+    ('\u{1F195}', "new_button"), ('\u{1F196}', "ng_button"), ('\u{1F197}', "ok_button"),
+    ('\u{1F198}', "sos_button"), ('\u{1F199}', "up_button"), ('\u{1F19A}', "vs_button"),
+    ('\u{1F201}', "squared_katakana_koko"), ('\u{1F202}', "squared_katakana_sa"),
+    ('\u{1F21A}', "squared_cjk_unified_ideograph_7121"),
+    ('\u{1F22F}', "squared_cjk_unified_ideograph_6307"),
+    ('\u{1F232}', "squared_cjk_unified_ideograph_7981"),
+    ('\u{1F233}', "squared_cjk_unified_ideograph_7a7a"),
+    ('\u{1F234}', "squared_cjk_unified_ideograph_5408"),
+    ('\u{1F235}', "squared_cjk_unified_ideograph_6e80"),
+    ('\u{1F236}', "squared_cjk_unified_ideograph_6709"),
+    ('\u{1F237}', "squared_cjk_unified_ideograph_6708"),
+    ('\u{1F238}', "squared_cjk_unified_ideograph_7533"),
+    ('\u{1F239}', "squared_cjk_unified_ideograph_5272"),
+    ('\u{1F23A}', "squared_cjk_unified_ideograph_55b6"),
+    ('\u{1F250}', "circled_ideograph_advantage"), ('\u{1F251}', "circled_ideograph_accept"),
+    ('\u{1F300}', "cyclone"), ('\u{1F301}', "foggy"), ('\u{1F302}', "closed_umbrella"),
+    ('\u{1F303}', "night_with_stars"), ('\u{1F304}', "sunrise_over_mountains"),
+    ('\u{1F305}', "sunrise"), ('\u{1F306}', "cityscape_at_dusk"), ('\u{1F307}', "sunset"),
+    ('\u{1F308}', "rainbow"), ('\u{1F309}', "bridge_at_night"), ('\u{1F30A}', "water_wave"),
+    ('\u{1F30B}', "volcano"), ('\u{1F30C}', "milky_way"),
+    ('\u{1F30D}', "globe_showing_europe_africa"), ('\u{1F30E}', "globe_showing_americas"),
+    ('\u{1F30F}', "globe_showing_asia_australia"), ('\u{1F310}', "globe_with_meridians"),
+    ('\u{1F311}', "new_moon"), ('\u{1F312}', "waxing_crescent_moon_symbol"),
+    ('\u{1F313}', "first_quarter_moon_symbol"), ('\u{1F314}', "waxing_gibbous_moon_symbol"),
+    ('\u{1F315}', "full_moon"), ('\u{1F316}', "waning_gibbous_moon_symbol"),
+    ('\u{1F317}', "last_quarter_moon_symbol"), ('\u{1F318}', "waning_crescent_moon_symbol"),
+    ('\u{1F319}', "crescent_moon"), ('\u{1F31A}', "new_moon_with_face"),
+    ('\u{1F31B}', "first_quarter_moon_face"), ('\u{1F31C}', "last_quarter_moon_face"),
+    ('\u{1F31D}', "full_moon_face"), ('\u{1F31E}', "sun_with_face"), ('\u{1F31F}', "glowing_star"),
+    ('\u{1F320}', "shooting_star"), ('\u{1F321}', "thermometer"),
+    ('\u{1F324}', "sun_behind_small_cloud"), ('\u{1F325}', "sun_behind_large_cloud"),
+    ('\u{1F326}', "sun_behind_rain_cloud"), ('\u{1F327}', "cloud_with_rain"),
+    ('\u{1F328}', "cloud_with_snow"), ('\u{1F329}', "cloud_with_lightning"),
+    ('\u{1F32A}', "tornado"), ('\u{1F32B}', "fog"), ('\u{1F32C}', "wind_face"),
+    ('\u{1F32D}', "hot_dog"), ('\u{1F32E}', "taco"), ('\u{1F32F}', "burrito"),
+    ('\u{1F330}', "chestnut"), ('\u{1F331}', "seedling"), ('\u{1F332}', "evergreen_tree"),
+    ('\u{1F333}', "deciduous_tree"), ('\u{1F334}', "palm_tree"), ('\u{1F335}', "cactus"),
+    ('\u{1F336}', "hot_pepper"), ('\u{1F337}', "tulip"), ('\u{1F338}', "cherry_blossom"),
+    ('\u{1F339}', "rose"), ('\u{1F33A}', "hibiscus"), ('\u{1F33B}', "sunflower"),
+    ('\u{1F33C}', "blossom"), ('\u{1F33D}', "ear_of_maize"), ('\u{1F33E}', "ear_of_rice"),
+    ('\u{1F33F}', "herb"), ('\u{1F340}', "four_leaf_clover"), ('\u{1F341}', "maple_leaf"),
+    ('\u{1F342}', "fallen_leaf"), ('\u{1F343}', "leaf_fluttering_in_wind"),
+    ('\u{1F344}', "mushroom"), ('\u{1F345}', "tomato"), ('\u{1F346}', "aubergine"),
+    ('\u{1F347}', "grapes"), ('\u{1F348}', "melon"), ('\u{1F349}', "watermelon"),
+    ('\u{1F34A}', "tangerine"), ('\u{1F34B}', "lemon"), ('\u{1F34C}', "banana"),
+    ('\u{1F34D}', "pineapple"), ('\u{1F34E}', "red_apple"), ('\u{1F34F}', "green_apple"),
+    ('\u{1F350}', "pear"), ('\u{1F351}', "peach"), ('\u{1F352}', "cherries"),
+    ('\u{1F353}', "strawberry"), ('\u{1F354}', "hamburger"), ('\u{1F355}', "pizza"),
+    ('\u{1F356}', "meat_on_bone"), ('\u{1F357}', "poultry_leg"), ('\u{1F358}', "rice_cracker"),
+    ('\u{1F359}', "rice_ball"), ('\u{1F35A}', "cooked_rice"), ('\u{1F35B}', "curry_and_rice"),
+    ('\u{1F35C}', "steaming_bowl"), ('\u{1F35D}', "spaghetti"), ('\u{1F35E}', "bread"),
+    ('\u{1F35F}', "french_fries"), ('\u{1F360}', "roasted_sweet_potato"), ('\u{1F361}', "dango"),
+    ('\u{1F362}', "oden"), ('\u{1F363}', "sushi"), ('\u{1F364}', "fried_shrimp"),
+    ('\u{1F365}', "fish_cake_with_swirl_design"), ('\u{1F366}', "soft_ice_cream"),
+    ('\u{1F367}', "shaved_ice"), ('\u{1F368}', "ice_cream"), ('\u{1F369}', "doughnut"),
+    ('\u{1F36A}', "cookie"), ('\u{1F36B}', "chocolate_bar"), ('\u{1F36C}', "candy"),
+    ('\u{1F36D}', "lollipop"), ('\u{1F36E}', "custard"), ('\u{1F36F}', "honey_pot"),
+    ('\u{1F370}', "shortcake"), ('\u{1F371}', "bento_box"), ('\u{1F372}', "pot_of_food"),
+    ('\u{1F373}', "cooking"), ('\u{1F374}', "fork_and_knife"),
+    ('\u{1F375}', "teacup_without_handle"), ('\u{1F376}', "sake_bottle_and_cup"),
+    ('\u{1F377}', "wine_glass"), ('\u{1F378}', "cocktail_glass"), ('\u{1F379}', "tropical_drink"),
+    ('\u{1F37A}', "beer_mug"), ('\u{1F37B}', "clinking_beer_mugs"), ('\u{1F37C}', "baby_bottle"),
+    ('\u{1F37D}', "fork_and_knife_with_plate"), ('\u{1F37E}', "bottle_with_popping_cork"),
+    ('\u{1F37F}', "popcorn"), ('\u{1F380}', "ribbon"), ('\u{1F381}', "wrapped_gift"),
+    ('\u{1F382}', "birthday_cake"), ('\u{1F383}', "jack_o_lantern"),
+    ('\u{1F384}', "christmas_tree"), ('\u{1F385}', "santa_claus"), ('\u{1F386}', "fireworks"),
+    ('\u{1F387}', "sparkler"), ('\u{1F388}', "balloon"), ('\u{1F389}', "party_popper"),
+    ('\u{1F38A}', "confetti_ball"), ('\u{1F38B}', "tanabata_tree"), ('\u{1F38C}', "crossed_flags"),
+    ('\u{1F38D}', "pine_decoration"), ('\u{1F38E}', "japanese_dolls"),
+    ('\u{1F38F}', "carp_streamer"), ('\u{1F390}', "wind_chime"),
+    ('\u{1F391}', "moon_viewing_ceremony"), ('\u{1F392}', "backpack"),
+    ('\u{1F393}', "graduation_cap"), ('\u{1F396}', "military_medal"),
+    ('\u{1F397}', "reminder_ribbon"), ('\u{1F399}', "studio_microphone"),
+    ('\u{1F39A}', "level_slider"), ('\u{1F39B}', "control_knobs"), ('\u{1F39E}', "film_frames"),
+    ('\u{1F39F}', "admission_tickets"), ('\u{1F3A0}', "carousel_horse"),
+    ('\u{1F3A1}', "ferris_wheel"), ('\u{1F3A2}', "roller_coaster"),
+    ('\u{1F3A3}', "fishing_pole_and_fish"), ('\u{1F3A4}', "microphone"),
+    ('\u{1F3A5}', "movie_camera"), ('\u{1F3A6}', "cinema"), ('\u{1F3A7}', "headphone"),
+    ('\u{1F3A8}', "artist_palette"), ('\u{1F3A9}', "top_hat"), ('\u{1F3AA}', "circus_tent"),
+    ('\u{1F3AB}', "ticket"), ('\u{1F3AC}', "clapper_board"), ('\u{1F3AD}', "performing_arts"),
+    ('\u{1F3AE}', "video_game"), ('\u{1F3AF}', "direct_hit"), ('\u{1F3B0}', "slot_machine"),
+    ('\u{1F3B1}', "billiards"), ('\u{1F3B2}', "game_die"), ('\u{1F3B3}', "bowling"),
+    ('\u{1F3B4}', "flower_playing_cards"), ('\u{1F3B5}', "musical_note"),
+    ('\u{1F3B6}', "musical_notes"), ('\u{1F3B7}', "saxophone"), ('\u{1F3B8}', "guitar"),
+    ('\u{1F3B9}', "musical_keyboard"), ('\u{1F3BA}', "trumpet"), ('\u{1F3BB}', "violin"),
+    ('\u{1F3BC}', "musical_score"), ('\u{1F3BD}', "running_shirt_with_sash"),
+    ('\u{1F3BE}', "tennis"), ('\u{1F3BF}', "ski_and_ski_boot"), ('\u{1F3C0}', "basketball"),
+    ('\u{1F3C1}', "chequered_flag"), ('\u{1F3C2}', "snowboarder"), ('\u{1F3C3}', "person_running"),
+    ('\u{1F3C4}', "person_surfing"), ('\u{1F3C5}', "sports_medal"), ('\u{1F3C6}', "trophy"),
+    ('\u{1F3C7}', "horse_racing"), ('\u{1F3C8}', "american_football"),
+    ('\u{1F3C9}', "rugby_football"), ('\u{1F3CA}', "person_swimming"),
+    ('\u{1F3CB}', "person_lifting_weights"), ('\u{1F3CC}', "person_golfing"),
+    ('\u{1F3CD}', "motorcycle"), ('\u{1F3CE}', "racing_car"), ('\u{1F3CF}', "cricket_bat_and_ball"),
+    ('\u{1F3D0}', "volleyball"), ('\u{1F3D1}', "field_hockey_stick_and_ball"),
+    ('\u{1F3D2}', "ice_hockey_stick_and_puck"), ('\u{1F3D3}', "table_tennis_paddle_and_ball"),
+    ('\u{1F3D4}', "snow_capped_mountain"), ('\u{1F3D5}', "camping"),
+    ('\u{1F3D6}', "beach_with_umbrella"), ('\u{1F3D7}', "building_construction"),
+    ('\u{1F3D8}', "houses"), ('\u{1F3D9}', "cityscape"), ('\u{1F3DA}', "derelict_house"),
+    ('\u{1F3DB}', "classical_building"), ('\u{1F3DC}', "desert"), ('\u{1F3DD}', "desert_island"),
+    ('\u{1F3DE}', "national_park"), ('\u{1F3DF}', "stadium"), ('\u{1F3E0}', "house"),
+    ('\u{1F3E1}', "house_with_garden"), ('\u{1F3E2}', "office_building"),
+    ('\u{1F3E3}', "japanese_post_office"), ('\u{1F3E4}', "european_post_office"),
+    ('\u{1F3E5}', "hospital"), ('\u{1F3E6}', "bank"), ('\u{1F3E7}', "automated_teller_machine"),
+    ('\u{1F3E8}', "hotel"), ('\u{1F3E9}', "love_hotel"), ('\u{1F3EA}', "convenience_store"),
+    ('\u{1F3EB}', "school"), ('\u{1F3EC}', "department_store"), ('\u{1F3ED}', "factory"),
+    ('\u{1F3EE}', "izakaya_lantern"), ('\u{1F3EF}', "japanese_castle"), ('\u{1F3F0}', "castle"),
+    ('\u{1F3F3}', "white_flag"), ('\u{1F3F4}', "black_flag"), ('\u{1F3F5}', "rosette"),
+    ('\u{1F3F7}', "label"), ('\u{1F3F8}', "badminton_racquet_and_shuttlecock"),
+    ('\u{1F3F9}', "bow_and_arrow"), ('\u{1F3FA}', "amphora"), ('\u{1F3FB}', "light_skin_tone"),
+    ('\u{1F3FC}', "medium_light_skin_tone"), ('\u{1F3FD}', "medium_skin_tone"),
+    ('\u{1F3FE}', "medium_dark_skin_tone"), ('\u{1F3FF}', "dark_skin_tone"), ('\u{1F400}', "rat"),
+    ('\u{1F401}', "mouse"), ('\u{1F402}', "ox"), ('\u{1F403}', "water_buffalo"),
+    ('\u{1F404}', "cow"), ('\u{1F405}', "tiger"), ('\u{1F406}', "leopard"), ('\u{1F407}', "rabbit"),
+    ('\u{1F408}', "cat"), ('\u{1F409}', "dragon"), ('\u{1F40A}', "crocodile"),
+    ('\u{1F40B}', "whale"), ('\u{1F40C}', "snail"), ('\u{1F40D}', "snake"), ('\u{1F40E}', "horse"),
+    ('\u{1F40F}', "ram"), ('\u{1F410}', "goat"), ('\u{1F411}', "sheep"), ('\u{1F412}', "monkey"),
+    ('\u{1F413}', "rooster"), ('\u{1F414}', "chicken"), ('\u{1F415}', "dog"), ('\u{1F416}', "pig"),
+    ('\u{1F417}', "boar"), ('\u{1F418}', "elephant"), ('\u{1F419}', "octopus"),
+    ('\u{1F41A}', "spiral_shell"), ('\u{1F41B}', "bug"), ('\u{1F41C}', "ant"),
+    ('\u{1F41D}', "honeybee"), ('\u{1F41E}', "lady_beetle"), ('\u{1F41F}', "fish"),
+    ('\u{1F420}', "tropical_fish"), ('\u{1F421}', "blowfish"), ('\u{1F422}', "turtle"),
+    ('\u{1F423}', "hatching_chick"), ('\u{1F424}', "baby_chick"),
+    ('\u{1F425}', "front_facing_baby_chick"), ('\u{1F426}', "bird"), ('\u{1F427}', "penguin"),
+    ('\u{1F428}', "koala"), ('\u{1F429}', "poodle"), ('\u{1F42A}', "camel"),
+    ('\u{1F42B}', "two_hump_camel"), ('\u{1F42C}', "dolphin"), ('\u{1F42D}', "mouse_face"),
+    ('\u{1F42E}', "cow_face"), ('\u{1F42F}', "tiger_face"), ('\u{1F430}', "rabbit_face"),
+    ('\u{1F431}', "cat_face"), ('\u{1F432}', "dragon_face"), ('\u{1F433}', "spouting_whale"),
+    ('\u{1F434}', "horse_face"), ('\u{1F435}', "monkey_face"), ('\u{1F436}', "dog_face"),
+    ('\u{1F437}', "pig_face"), ('\u{1F438}', "frog"), ('\u{1F439}', "hamster_face"),
+    ('\u{1F43A}', "wolf_face"), ('\u{1F43B}', "bear"), ('\u{1F43C}', "panda"),
+    ('\u{1F43D}', "pig_nose"), ('\u{1F43E}', "paw_prints"), ('\u{1F43F}', "chipmunk"),
+    ('\u{1F440}', "eyes"), ('\u{1F441}', "eye"), ('\u{1F442}', "ear"), ('\u{1F443}', "nose"),
+    ('\u{1F444}', "mouth"), ('\u{1F445}', "tongue"), ('\u{1F446}', "backhand_index_pointing_up"),
+    ('\u{1F447}', "backhand_index_pointing_down"), ('\u{1F448}', "backhand_index_pointing_left"),
+    ('\u{1F449}', "backhand_index_pointing_right"), ('\u{1F44A}', "oncoming_fist"),
+    ('\u{1F44B}', "waving_hand"), ('\u{1F44C}', "ok_hand"), ('\u{1F44D}', "thumbs_up"),
+    ('\u{1F44E}', "thumbs_down"), ('\u{1F44F}', "clapping_hands"), ('\u{1F450}', "open_hands"),
+    ('\u{1F451}', "crown"), ('\u{1F452}', "womans_hat"), ('\u{1F453}', "glasses"),
+    ('\u{1F454}', "necktie"), ('\u{1F455}', "t_shirt"), ('\u{1F456}', "jeans"),
+    ('\u{1F457}', "dress"), ('\u{1F458}', "kimono"), ('\u{1F459}', "bikini"),
+    ('\u{1F45A}', "womans_clothes"), ('\u{1F45B}', "purse"), ('\u{1F45C}', "handbag"),
+    ('\u{1F45D}', "pouch"), ('\u{1F45E}', "mans_shoe"), ('\u{1F45F}', "running_shoe"),
+    ('\u{1F460}', "high_heeled_shoe"), ('\u{1F461}', "womans_sandal"),
+    ('\u{1F462}', "womans_boots"), ('\u{1F463}', "footprints"), ('\u{1F464}', "bust_in_silhouette"),
+    ('\u{1F465}', "busts_in_silhouette"), ('\u{1F466}', "boy"), ('\u{1F467}', "girl"),
+    ('\u{1F468}', "man"), ('\u{1F469}', "woman"), ('\u{1F46A}', "family"),
+    ('\u{1F46B}', "woman_and_man_holding_hands"), ('\u{1F46C}', "men_holding_hands"),
+    ('\u{1F46D}', "women_holding_hands"), ('\u{1F46E}', "police_officer"),
+    ('\u{1F46F}', "people_with_bunny_ears"), ('\u{1F470}', "person_with_veil"),
+    ('\u{1F471}', "person_blond_hair"), ('\u{1F472}', "person_with_skullcap"),
+    ('\u{1F473}', "person_wearing_turban"), ('\u{1F474}', "old_man"), ('\u{1F475}', "old_woman"),
+    ('\u{1F476}', "baby"), ('\u{1F477}', "construction_worker"), ('\u{1F478}', "princess"),
+    ('\u{1F479}', "ogre"), ('\u{1F47A}', "goblin"), ('\u{1F47B}', "ghost"),
+    ('\u{1F47C}', "baby_angel"), ('\u{1F47D}', "alien"), ('\u{1F47E}', "alien_monster"),
+    ('\u{1F47F}', "angry_face_with_horns"), ('\u{1F480}', "skull"),
+    ('\u{1F481}', "person_tipping_hand"), ('\u{1F482}', "guard"), ('\u{1F483}', "woman_dancing"),
+    ('\u{1F484}', "lipstick"), ('\u{1F485}', "nail_polish"),
+    ('\u{1F486}', "person_getting_massage"), ('\u{1F487}', "person_getting_haircut"),
+    ('\u{1F488}', "barber_pole"), ('\u{1F489}', "syringe"), ('\u{1F48A}', "pill"),
+    ('\u{1F48B}', "kiss_mark"), ('\u{1F48C}', "love_letter"), ('\u{1F48D}', "ring"),
+    ('\u{1F48E}', "gem_stone"), ('\u{1F48F}', "kiss"), ('\u{1F490}', "bouquet"),
+    ('\u{1F491}', "couple_with_heart"), ('\u{1F492}', "wedding"), ('\u{1F493}', "beating_heart"),
+    ('\u{1F494}', "broken_heart"), ('\u{1F495}', "two_hearts"), ('\u{1F496}', "sparkling_heart"),
+    ('\u{1F497}', "growing_heart"), ('\u{1F498}', "heart_with_arrow"), ('\u{1F499}', "blue_heart"),
+    ('\u{1F49A}', "green_heart"), ('\u{1F49B}', "yellow_heart"), ('\u{1F49C}', "purple_heart"),
+    ('\u{1F49D}', "heart_with_ribbon"), ('\u{1F49E}', "revolving_hearts"),
+    ('\u{1F49F}', "heart_decoration"), ('\u{1F4A0}', "diamond_with_a_dot"),
+    ('\u{1F4A1}', "light_bulb"), ('\u{1F4A2}', "anger_symbol"), ('\u{1F4A3}', "bomb"),
+    ('\u{1F4A4}', "zzz"), ('\u{1F4A5}', "collision"), ('\u{1F4A6}', "sweat_droplets"),
+    ('\u{1F4A7}', "droplet"), ('\u{1F4A8}', "dashing_away"), ('\u{1F4A9}', "pile_of_poo"),
+    ('\u{1F4AA}', "flexed_biceps"), ('\u{1F4AB}', "dizzy"), ('\u{1F4AC}', "speech_balloon"),
+    ('\u{1F4AD}', "thought_balloon"), ('\u{1F4AE}', "white_flower"),
+    ('\u{1F4AF}', "hundred_points"), ('\u{1F4B0}', "money_bag"), ('\u{1F4B1}', "currency_exchange"),
+    ('\u{1F4B2}', "heavy_dollar_sign"), ('\u{1F4B3}', "credit_card"), ('\u{1F4B4}', "yen_banknote"),
+    ('\u{1F4B5}', "dollar_banknote"), ('\u{1F4B6}', "euro_banknote"),
+    ('\u{1F4B7}', "pound_banknote"), ('\u{1F4B8}', "money_with_wings"),
+    ('\u{1F4B9}', "chart_with_upwards_trend_and_yen_sign"), ('\u{1F4BA}', "seat"),
+    ('\u{1F4BB}', "laptop"), ('\u{1F4BC}', "briefcase"), ('\u{1F4BD}', "minidisc"),
+    ('\u{1F4BE}', "floppy_disk"), ('\u{1F4BF}', "optical_disk"), ('\u{1F4C0}', "dvd"),
+    ('\u{1F4C1}', "file_folder"), ('\u{1F4C2}', "open_file_folder"),
+    ('\u{1F4C3}', "page_with_curl"), ('\u{1F4C4}', "page_facing_up"), ('\u{1F4C5}', "calendar"),
+    ('\u{1F4C6}', "tear_off_calendar"), ('\u{1F4C7}', "card_index"),
+    ('\u{1F4C8}', "chart_increasing"), ('\u{1F4C9}', "chart_decreasing"),
+    ('\u{1F4CA}', "bar_chart"), ('\u{1F4CB}', "clipboard"), ('\u{1F4CC}', "pushpin"),
+    ('\u{1F4CD}', "round_pushpin"), ('\u{1F4CE}', "paperclip"), ('\u{1F4CF}', "straight_ruler"),
+    ('\u{1F4D0}', "triangular_ruler"), ('\u{1F4D1}', "bookmark_tabs"), ('\u{1F4D2}', "ledger"),
+    ('\u{1F4D3}', "notebook"), ('\u{1F4D4}', "notebook_with_decorative_cover"),
+    ('\u{1F4D5}', "closed_book"), ('\u{1F4D6}', "open_book"), ('\u{1F4D7}', "green_book"),
+    ('\u{1F4D8}', "blue_book"), ('\u{1F4D9}', "orange_book"), ('\u{1F4DA}', "books"),
+    ('\u{1F4DB}', "name_badge"), ('\u{1F4DC}', "scroll"), ('\u{1F4DD}', "memo"),
+    ('\u{1F4DE}', "telephone_receiver"), ('\u{1F4DF}', "pager"), ('\u{1F4E0}', "fax_machine"),
+    ('\u{1F4E1}', "satellite_antenna"), ('\u{1F4E2}', "public_address_loudspeaker"),
+    ('\u{1F4E3}', "cheering_megaphone"), ('\u{1F4E4}', "outbox_tray"), ('\u{1F4E5}', "inbox_tray"),
+    ('\u{1F4E6}', "package"), ('\u{1F4E7}', "e_mail"), ('\u{1F4E8}', "incoming_envelope"),
+    ('\u{1F4E9}', "envelope_with_arrow"), ('\u{1F4EA}', "closed_mailbox_with_lowered_flag"),
+    ('\u{1F4EB}', "closed_mailbox_with_raised_flag"),
+    ('\u{1F4EC}', "open_mailbox_with_raised_flag"), ('\u{1F4ED}', "open_mailbox_with_lowered_flag"),
+    ('\u{1F4EE}', "postbox"), ('\u{1F4EF}', "postal_horn"), ('\u{1F4F0}', "newspaper"),
+    ('\u{1F4F1}', "mobile_phone"), ('\u{1F4F2}', "mobile_phone_with_rightwards_arrow_at_left"),
+    ('\u{1F4F3}', "vibration_mode"), ('\u{1F4F4}', "mobile_phone_off"),
+    ('\u{1F4F5}', "no_mobile_phones"), ('\u{1F4F6}', "antenna_with_bars"), ('\u{1F4F7}', "camera"),
+    ('\u{1F4F8}', "camera_with_flash"), ('\u{1F4F9}', "video_camera"), ('\u{1F4FA}', "television"),
+    ('\u{1F4FB}', "radio"), ('\u{1F4FC}', "videocassette"), ('\u{1F4FD}', "film_projector"),
+    ('\u{1F4FF}', "prayer_beads"), ('\u{1F500}', "shuffle_tracks_button"),
+    ('\u{1F501}', "repeat_button"), ('\u{1F502}', "repeat_single_button"),
+    ('\u{1F503}', "clockwise_vertical_arrows"), ('\u{1F504}', "counterclockwise_arrows_button"),
+    ('\u{1F505}', "low_brightness_symbol"), ('\u{1F506}', "high_brightness_symbol"),
+    ('\u{1F507}', "muted_speaker"), ('\u{1F508}', "speaker_low_volume"),
+    ('\u{1F509}', "speaker_medium_volume"), ('\u{1F50A}', "speaker_high_volume"),
+    ('\u{1F50B}', "battery"), ('\u{1F50C}', "electric_plug"),
+    ('\u{1F50D}', "magnifying_glass_tilted_left"), ('\u{1F50E}', "magnifying_glass_tilted_right"),
+    ('\u{1F50F}', "lock_with_ink_pen"), ('\u{1F510}', "closed_lock_with_key"), ('\u{1F511}', "key"),
+    ('\u{1F512}', "locked"), ('\u{1F513}', "unlocked"), ('\u{1F514}', "bell"),
+    ('\u{1F515}', "bell_with_slash"), ('\u{1F516}', "bookmark"), ('\u{1F517}', "link"),
+    ('\u{1F518}', "radio_button"), ('\u{1F519}', "back_arrow"), ('\u{1F51A}', "end_arrow"),
+    ('\u{1F51B}', "on_arrow"), ('\u{1F51C}', "soon_arrow"), ('\u{1F51D}', "top_arrow"),
+    ('\u{1F51E}', "no_one_under_eighteen_symbol"), ('\u{1F51F}', "keycap_10"),
+    ('\u{1F520}', "input_symbol_for_latin_capital_letters"),
+    ('\u{1F521}', "input_symbol_for_latin_small_letters"),
+    ('\u{1F522}', "input_symbol_for_numbers"), ('\u{1F523}', "input_symbol_for_symbols"),
+    ('\u{1F524}', "input_symbol_for_latin_letters"), ('\u{1F525}', "fire"),
+    ('\u{1F526}', "electric_torch"), ('\u{1F527}', "wrench"), ('\u{1F528}', "hammer"),
+    ('\u{1F529}', "nut_and_bolt"), ('\u{1F52A}', "hocho"), ('\u{1F52B}', "pistol"),
+    ('\u{1F52C}', "microscope"), ('\u{1F52D}', "telescope"), ('\u{1F52E}', "crystal_ball"),
+    ('\u{1F52F}', "dotted_six_pointed_star"), ('\u{1F530}', "japanese_symbol_for_beginner"),
+    ('\u{1F531}', "trident_emblem"), ('\u{1F532}', "black_square_button"),
+    ('\u{1F533}', "white_square_button"), ('\u{1F534}', "red_circle"), ('\u{1F535}', "blue_circle"),
+    ('\u{1F536}', "large_orange_diamond"), ('\u{1F537}', "large_blue_diamond"),
+    ('\u{1F538}', "small_orange_diamond"), ('\u{1F539}', "small_blue_diamond"),
+    ('\u{1F53A}', "red_triangle_pointed_up"), ('\u{1F53B}', "red_triangle_pointed_down"),
+    ('\u{1F53C}', "upwards_button"), ('\u{1F53D}', "downwards_button"), ('\u{1F549}', "om"),
+    ('\u{1F54A}', "dove"), ('\u{1F54B}', "kaaba"), ('\u{1F54C}', "mosque"),
+    ('\u{1F54D}', "synagogue"), ('\u{1F54E}', "menorah"), ('\u{1F550}', "clock_face_one_oclock"),
+    ('\u{1F551}', "clock_face_two_oclock"), ('\u{1F552}', "clock_face_three_oclock"),
+    ('\u{1F553}', "clock_face_four_oclock"), ('\u{1F554}', "clock_face_five_oclock"),
+    ('\u{1F555}', "clock_face_six_oclock"), ('\u{1F556}', "clock_face_seven_oclock"),
+    ('\u{1F557}', "clock_face_eight_oclock"), ('\u{1F558}', "clock_face_nine_oclock"),
+    ('\u{1F559}', "clock_face_ten_oclock"), ('\u{1F55A}', "clock_face_eleven_oclock"),
+    ('\u{1F55B}', "clock_face_twelve_oclock"), ('\u{1F55C}', "clock_face_one_thirty"),
+    ('\u{1F55D}', "clock_face_two_thirty"), ('\u{1F55E}', "clock_face_three_thirty"),
+    ('\u{1F55F}', "clock_face_four_thirty"), ('\u{1F560}', "clock_face_five_thirty"),
+    ('\u{1F561}', "clock_face_six_thirty"), ('\u{1F562}', "clock_face_seven_thirty"),
+    ('\u{1F563}', "clock_face_eight_thirty"), ('\u{1F564}', "clock_face_nine_thirty"),
+    ('\u{1F565}', "clock_face_ten_thirty"), ('\u{1F566}', "clock_face_eleven_thirty"),
+    ('\u{1F567}', "clock_face_twelve_thirty"), ('\u{1F56F}', "candle"),
+    ('\u{1F570}', "mantelpiece_clock"), ('\u{1F573}', "hole"),
+    ('\u{1F574}', "person_in_suit_levitating"), ('\u{1F575}', "detective"),
+    ('\u{1F576}', "sunglasses"), ('\u{1F577}', "spider"), ('\u{1F578}', "spider_web"),
+    ('\u{1F579}', "joystick"), ('\u{1F57A}', "man_dancing"), ('\u{1F587}', "linked_paperclips"),
+    ('\u{1F58A}', "pen"), ('\u{1F58B}', "fountain_pen"), ('\u{1F58C}', "paintbrush"),
+    ('\u{1F58D}', "crayon"), ('\u{1F590}', "hand_with_fingers_splayed"),
+    ('\u{1F595}', "middle_finger"), ('\u{1F596}', "vulcan_salute"), ('\u{1F5A4}', "black_heart"),
+    ('\u{1F5A5}', "desktop_computer"), ('\u{1F5A8}', "printer"), ('\u{1F5B1}', "computer_mouse"),
+    ('\u{1F5B2}', "trackball"), ('\u{1F5BC}', "framed_picture"),
+    ('\u{1F5C2}', "card_index_dividers"), ('\u{1F5C3}', "card_file_box"),
+    ('\u{1F5C4}', "file_cabinet"), ('\u{1F5D1}', "wastebasket"), ('\u{1F5D2}', "spiral_notepad"),
+    ('\u{1F5D3}', "spiral_calendar"), ('\u{1F5DC}', "clamp"), ('\u{1F5DD}', "old_key"),
+    ('\u{1F5DE}', "rolled_up_newspaper"), ('\u{1F5E1}', "dagger"), ('\u{1F5E3}', "speaking_head"),
+    ('\u{1F5E8}', "left_speech_bubble"), ('\u{1F5EF}', "right_anger_bubble"),
+    ('\u{1F5F3}', "ballot_box_with_ballot"), ('\u{1F5FA}', "world_map"),
+    ('\u{1F5FB}', "mount_fuji"), ('\u{1F5FC}', "tokyo_tower"), ('\u{1F5FD}', "statue_of_liberty"),
+    ('\u{1F5FE}', "silhouette_of_japan"), ('\u{1F5FF}', "moyai"), ('\u{1F600}', "grinning_face"),
+    ('\u{1F601}', "beaming_face_with_smiling_eyes"), ('\u{1F602}', "face_with_tears_of_joy"),
+    ('\u{1F603}', "grinning_face_with_big_eyes"), ('\u{1F604}', "grinning_face_with_smiling_eyes"),
+    ('\u{1F605}', "grinning_face_with_sweat"), ('\u{1F606}', "grinning_squinting_face"),
+    ('\u{1F607}', "smiling_face_with_halo"), ('\u{1F608}', "smiling_face_with_horns"),
+    ('\u{1F609}', "winking_face"), ('\u{1F60A}', "smiling_face_with_smiling_eyes"),
+    ('\u{1F60B}', "face_savoring_food"), ('\u{1F60C}', "relieved_face"),
+    ('\u{1F60D}', "smiling_face_with_heart_eyes"), ('\u{1F60E}', "smiling_face_with_sunglasses"),
+    ('\u{1F60F}', "smirking_face"), ('\u{1F610}', "neutral_face"),
+    ('\u{1F611}', "expressionless_face"), ('\u{1F612}', "unamused_face"),
+    ('\u{1F613}', "face_with_cold_sweat"), ('\u{1F614}', "pensive_face"),
+    ('\u{1F615}', "confused_face"), ('\u{1F616}', "confounded_face"), ('\u{1F617}', "kissing_face"),
+    ('\u{1F618}', "face_blowing_a_kiss"), ('\u{1F619}', "kissing_face_with_smiling_eyes"),
+    ('\u{1F61A}', "kissing_face_with_closed_eyes"), ('\u{1F61B}', "face_with_tongue"),
+    ('\u{1F61C}', "winking_face_with_tongue"), ('\u{1F61D}', "squinting_face_with_tongue"),
+    ('\u{1F61E}', "disappointed_face"), ('\u{1F61F}', "worried_face"), ('\u{1F620}', "angry_face"),
+    ('\u{1F621}', "enraged_face"), ('\u{1F622}', "crying_face"), ('\u{1F623}', "persevering_face"),
+    ('\u{1F624}', "face_with_steam_from_nose"), ('\u{1F625}', "sad_but_relieved_face"),
+    ('\u{1F626}', "frowning_face_with_open_mouth"), ('\u{1F627}', "anguished_face"),
+    ('\u{1F628}', "fearful_face"), ('\u{1F629}', "weary_face"), ('\u{1F62A}', "sleepy_face"),
+    ('\u{1F62B}', "tired_face"), ('\u{1F62C}', "grimacing_face"),
+    ('\u{1F62D}', "loudly_crying_face"), ('\u{1F62E}', "face_with_open_mouth"),
+    ('\u{1F62F}', "hushed_face"), ('\u{1F630}', "anxious_face_with_sweat"),
+    ('\u{1F631}', "face_screaming_in_fear"), ('\u{1F632}', "astonished_face"),
+    ('\u{1F633}', "flushed_face"), ('\u{1F634}', "sleeping_face"),
+    ('\u{1F635}', "face_with_crossed_out_eyes"), ('\u{1F636}', "face_without_mouth"),
+    ('\u{1F637}', "face_with_medical_mask"), ('\u{1F638}', "grinning_cat_with_smiling_eyes"),
+    ('\u{1F639}', "cat_with_tears_of_joy"), ('\u{1F63A}', "grinning_cat"),
+    ('\u{1F63B}', "smiling_cat_with_heart_eyes"), ('\u{1F63C}', "cat_with_wry_smile"),
+    ('\u{1F63D}', "kissing_cat"), ('\u{1F63E}', "pouting_cat"), ('\u{1F63F}', "crying_cat"),
+    ('\u{1F640}', "weary_cat"), ('\u{1F641}', "slightly_frowning_face"),
+    ('\u{1F642}', "slightly_smiling_face"), ('\u{1F643}', "upside_down_face"),
+    ('\u{1F644}', "face_with_rolling_eyes"), ('\u{1F645}', "person_gesturing_no"),
+    ('\u{1F646}', "person_gesturing_ok"), ('\u{1F647}', "person_bowing"),
+    ('\u{1F648}', "see_no_evil_monkey"), ('\u{1F649}', "hear_no_evil_monkey"),
+    ('\u{1F64A}', "speak_no_evil_monkey"), ('\u{1F64B}', "person_raising_hand"),
+    ('\u{1F64C}', "raising_hands"), ('\u{1F64D}', "person_frowning"),
+    ('\u{1F64E}', "person_pouting"), ('\u{1F64F}', "folded_hands"), ('\u{1F680}', "rocket"),
+    ('\u{1F681}', "helicopter"), ('\u{1F682}', "locomotive"), ('\u{1F683}', "railway_car"),
+    ('\u{1F684}', "high_speed_train"), ('\u{1F685}', "high_speed_train_with_bullet_nose"),
+    ('\u{1F686}', "train"), ('\u{1F687}', "metro"), ('\u{1F688}', "light_rail"),
+    ('\u{1F689}', "station"), ('\u{1F68A}', "tram"), ('\u{1F68B}', "tram_car"),
+    ('\u{1F68C}', "bus"), ('\u{1F68D}', "oncoming_bus"), ('\u{1F68E}', "trolleybus"),
+    ('\u{1F68F}', "bus_stop"), ('\u{1F690}', "minibus"), ('\u{1F691}', "ambulance"),
+    ('\u{1F692}', "fire_engine"), ('\u{1F693}', "police_car"), ('\u{1F694}', "oncoming_police_car"),
+    ('\u{1F695}', "taxi"), ('\u{1F696}', "oncoming_taxi"), ('\u{1F697}', "automobile"),
+    ('\u{1F698}', "oncoming_automobile"), ('\u{1F699}', "recreational_vehicle"),
+    ('\u{1F69A}', "delivery_truck"), ('\u{1F69B}', "articulated_lorry"), ('\u{1F69C}', "tractor"),
+    ('\u{1F69D}', "monorail"), ('\u{1F69E}', "mountain_railway"),
+    ('\u{1F69F}', "suspension_railway"), ('\u{1F6A0}', "mountain_cableway"),
+    ('\u{1F6A1}', "aerial_tramway"), ('\u{1F6A2}', "ship"), ('\u{1F6A3}', "person_rowing_boat"),
+    ('\u{1F6A4}', "speedboat"), ('\u{1F6A5}', "horizontal_traffic_light"),
+    ('\u{1F6A6}', "vertical_traffic_light"), ('\u{1F6A7}', "construction"),
+    ('\u{1F6A8}', "police_car_light"), ('\u{1F6A9}', "triangular_flag"), ('\u{1F6AA}', "door"),
+    ('\u{1F6AB}', "prohibited"), ('\u{1F6AC}', "cigarette"), ('\u{1F6AD}', "no_smoking"),
+    ('\u{1F6AE}', "put_litter_in_its_place_symbol"), ('\u{1F6AF}', "do_not_litter_symbol"),
+    ('\u{1F6B0}', "potable_water_symbol"), ('\u{1F6B1}', "non_potable_water_symbol"),
+    ('\u{1F6B2}', "bicycle"), ('\u{1F6B3}', "no_bicycles"), ('\u{1F6B4}', "person_biking"),
+    ('\u{1F6B5}', "person_mountain_biking"), ('\u{1F6B6}', "person_walking"),
+    ('\u{1F6B7}', "no_pedestrians"), ('\u{1F6B8}', "children_crossing"), ('\u{1F6B9}', "mens_room"),
+    ('\u{1F6BA}', "womens_room"), ('\u{1F6BB}', "restroom"), ('\u{1F6BC}', "baby_symbol"),
+    ('\u{1F6BD}', "toilet"), ('\u{1F6BE}', "water_closet"), ('\u{1F6BF}', "shower"),
+    ('\u{1F6C0}', "person_taking_bath"), ('\u{1F6C1}', "bathtub"),
+    ('\u{1F6C2}', "passport_control"), ('\u{1F6C3}', "customs"), ('\u{1F6C4}', "baggage_claim"),
+    ('\u{1F6C5}', "left_luggage"), ('\u{1F6CB}', "couch_and_lamp"),
+    ('\u{1F6CC}', "sleeping_accommodation"), ('\u{1F6CD}', "shopping_bags"),
+    ('\u{1F6CE}', "bellhop_bell"), ('\u{1F6CF}', "bed"), ('\u{1F6D0}', "place_of_worship"),
+    ('\u{1F6D1}', "octagonal_sign"), ('\u{1F6D2}', "shopping_cart"), ('\u{1F6D5}', "hindu_temple"),
+    ('\u{1F6D6}', "hut"), ('\u{1F6D7}', "elevator"), ('\u{1F6DC}', "wireless"),
+    ('\u{1F6DD}', "playground_slide"), ('\u{1F6DE}', "wheel"), ('\u{1F6DF}', "ring_buoy"),
+    ('\u{1F6E0}', "hammer_and_wrench"), ('\u{1F6E1}', "shield"), ('\u{1F6E2}', "oil_drum"),
+    ('\u{1F6E3}', "motorway"), ('\u{1F6E4}', "railway_track"), ('\u{1F6E5}', "motor_boat"),
+    ('\u{1F6E9}', "small_airplane"), ('\u{1F6EB}', "airplane_departure"),
+    ('\u{1F6EC}', "airplane_arrival"), ('\u{1F6F0}', "satellite"), ('\u{1F6F3}', "passenger_ship"),
+    ('\u{1F6F4}', "scooter"), ('\u{1F6F5}', "motor_scooter"), ('\u{1F6F6}', "canoe"),
+    ('\u{1F6F7}', "sled"), ('\u{1F6F8}', "flying_saucer"), ('\u{1F6F9}', "skateboard"),
+    ('\u{1F6FA}', "auto_rickshaw"), ('\u{1F6FB}', "pickup_truck"), ('\u{1F6FC}', "roller_skate"),
+    ('\u{1F7E0}', "orange_circle"), ('\u{1F7E1}', "yellow_circle"), ('\u{1F7E2}', "green_circle"),
+    ('\u{1F7E3}', "purple_circle"), ('\u{1F7E4}', "brown_circle"), ('\u{1F7E5}', "red_square"),
+    ('\u{1F7E6}', "blue_square"), ('\u{1F7E7}', "orange_square"), ('\u{1F7E8}', "yellow_square"),
+    ('\u{1F7E9}', "green_square"), ('\u{1F7EA}', "purple_square"), ('\u{1F7EB}', "brown_square"),
+    ('\u{1F7F0}', "heavy_equals_sign"), ('\u{1F90C}', "pinched_fingers"),
+    ('\u{1F90D}', "white_heart"), ('\u{1F90E}', "brown_heart"), ('\u{1F90F}', "pinching_hand"),
+    ('\u{1F910}', "zipper_mouth_face"), ('\u{1F911}', "money_mouth_face"),
+    ('\u{1F912}', "face_with_thermometer"), ('\u{1F913}', "nerd_face"),
+    ('\u{1F914}', "thinking_face"), ('\u{1F915}', "face_with_head_bandage"), ('\u{1F916}', "robot"),
+    ('\u{1F917}', "smiling_face_with_open_hands"), ('\u{1F918}', "sign_of_the_horns"),
+    ('\u{1F919}', "call_me_hand"), ('\u{1F91A}', "raised_back_of_hand"),
+    ('\u{1F91B}', "left_facing_fist"), ('\u{1F91C}', "right_facing_fist"),
+    ('\u{1F91D}', "handshake"), ('\u{1F91E}', "hand_with_index_and_middle_fingers_crossed"),
+    ('\u{1F91F}', "i_love_you_hand_sign"), ('\u{1F920}', "cowboy_hat_face"),
+    ('\u{1F921}', "clown_face"), ('\u{1F922}', "nauseated_face"),
+    ('\u{1F923}', "rolling_on_the_floor_laughing"), ('\u{1F924}', "drooling_face"),
+    ('\u{1F925}', "lying_face"), ('\u{1F926}', "person_facepalming"),
+    ('\u{1F927}', "sneezing_face"), ('\u{1F928}', "face_with_raised_eyebrow"),
+    ('\u{1F929}', "star_struck"), ('\u{1F92A}', "zany_face"), ('\u{1F92B}', "shushing_face"),
+    ('\u{1F92C}', "face_with_symbols_on_mouth"), ('\u{1F92D}', "face_with_hand_over_mouth"),
+    ('\u{1F92E}', "face_vomiting"), ('\u{1F92F}', "exploding_head"),
+    ('\u{1F930}', "pregnant_woman"), ('\u{1F931}', "breast_feeding"),
+    ('\u{1F932}', "palms_up_together"), ('\u{1F933}', "selfie"), ('\u{1F934}', "prince"),
+    ('\u{1F935}', "person_in_tuxedo"), ('\u{1F936}', "mrs_claus"),
+    ('\u{1F937}', "person_shrugging"), ('\u{1F938}', "person_doing_cartwheel"),
+    ('\u{1F939}', "person_juggling"), ('\u{1F93A}', "fencer"), ('\u{1F93C}', "people_wrestling"),
+    ('\u{1F93D}', "person_playing_water_polo"), ('\u{1F93E}', "person_playing_handball"), ('\u{1F93F}', "diving_mask"),
+    ('\u{1F940}', "wilted_flower"), ('\u{1F941}', "drum_with_drumsticks"),
+    ('\u{1F942}', "clinking_glasses"), ('\u{1F943}', "tumbler_glass"), ('\u{1F944}', "spoon"),
+    ('\u{1F945}', "goal_net"), ('\u{1F947}', "1st_place_medal"), ('\u{1F948}', "2nd_place_medal"),
+    ('\u{1F949}', "3rd_place_medal"), ('\u{1F94A}', "boxing_glove"),
+    ('\u{1F94B}', "martial_arts_uniform"), ('\u{1F94C}', "curling_stone"),
+    ('\u{1F94D}', "lacrosse_stick_and_ball"), ('\u{1F94E}', "softball"),
+    ('\u{1F94F}', "flying_disc"), ('\u{1F950}', "croissant"), ('\u{1F951}', "avocado"),
+    ('\u{1F952}', "cucumber"), ('\u{1F953}', "bacon"), ('\u{1F954}', "potato"),
+    ('\u{1F955}', "carrot"), ('\u{1F956}', "baguette_bread"), ('\u{1F957}', "green_salad"),
+    ('\u{1F958}', "shallow_pan_of_food"), ('\u{1F959}', "stuffed_flatbread"), ('\u{1F95A}', "egg"),
+    ('\u{1F95B}', "glass_of_milk"), ('\u{1F95C}', "peanuts"), ('\u{1F95D}', "kiwifruit"),
+    ('\u{1F95E}', "pancakes"), ('\u{1F95F}', "dumpling"), ('\u{1F960}', "fortune_cookie"),
+    ('\u{1F961}', "takeout_box"), ('\u{1F962}', "chopsticks"), ('\u{1F963}', "bowl_with_spoon"),
+    ('\u{1F964}', "cup_with_straw"), ('\u{1F965}', "coconut"), ('\u{1F966}', "broccoli"),
+    ('\u{1F967}', "pie"), ('\u{1F968}', "pretzel"), ('\u{1F969}', "cut_of_meat"),
+    ('\u{1F96A}', "sandwich"), ('\u{1F96B}', "canned_food"), ('\u{1F96C}', "leafy_green"),
+    ('\u{1F96D}', "mango"), ('\u{1F96E}', "moon_cake"), ('\u{1F96F}', "bagel"),
+    ('\u{1F970}', "smiling_face_with_hearts"), ('\u{1F971}', "yawning_face"),
+    ('\u{1F972}', "smiling_face_with_tear"), ('\u{1F973}', "partying_face"),
+    ('\u{1F974}', "woozy_face"), ('\u{1F975}', "hot_face"), ('\u{1F976}', "cold_face"),
+    ('\u{1F977}', "ninja"), ('\u{1F978}', "disguised_face"),
+    ('\u{1F979}', "face_holding_back_tears"), ('\u{1F97A}', "pleading_face"), ('\u{1F97B}', "sari"),
+    ('\u{1F97C}', "lab_coat"), ('\u{1F97D}', "goggles"), ('\u{1F97E}', "hiking_boot"),
+    ('\u{1F97F}', "flat_shoe"), ('\u{1F980}', "crab"), ('\u{1F981}', "lion"),
+    ('\u{1F982}', "scorpion"), ('\u{1F983}', "turkey"), ('\u{1F984}', "unicorn"),
+    ('\u{1F985}', "eagle"), ('\u{1F986}', "duck"), ('\u{1F987}', "bat"), ('\u{1F988}', "shark"),
+    ('\u{1F989}', "owl"), ('\u{1F98A}', "fox"), ('\u{1F98B}', "butterfly"), ('\u{1F98C}', "deer"),
+    ('\u{1F98D}', "gorilla"), ('\u{1F98E}', "lizard"), ('\u{1F98F}', "rhinoceros"),
+    ('\u{1F990}', "shrimp"), ('\u{1F991}', "squid"), ('\u{1F992}', "giraffe_face"),
+    ('\u{1F993}', "zebra_face"), ('\u{1F994}', "hedgehog"), ('\u{1F995}', "sauropod"),
+    ('\u{1F996}', "t_rex"), ('\u{1F997}', "cricket"), ('\u{1F998}', "kangaroo"),
+    ('\u{1F999}', "llama"), ('\u{1F99A}', "peacock"), ('\u{1F99B}', "hippopotamus"),
+    ('\u{1F99C}', "parrot"), ('\u{1F99D}', "raccoon"), ('\u{1F99E}', "lobster"),
+    ('\u{1F99F}', "mosquito"), ('\u{1F9A0}', "microbe"), ('\u{1F9A1}', "badger"),
+    ('\u{1F9A2}', "swan"), ('\u{1F9A3}', "mammoth"), ('\u{1F9A4}', "dodo"), ('\u{1F9A5}', "sloth"),
+    ('\u{1F9A6}', "otter"), ('\u{1F9A7}', "orangutan"), ('\u{1F9A8}', "skunk"),
+    ('\u{1F9A9}', "flamingo"), ('\u{1F9AA}', "oyster"), ('\u{1F9AB}', "beaver"),
+    ('\u{1F9AC}', "bison"), ('\u{1F9AD}', "seal"), ('\u{1F9AE}', "guide_dog"),
+    ('\u{1F9AF}', "probing_cane"), ('\u{1F9B0}', "red_hair"), ('\u{1F9B1}', "curly_hair"),
+    ('\u{1F9B2}', "bald"), ('\u{1F9B3}', "white_hair"), ('\u{1F9B4}', "bone"), ('\u{1F9B5}', "leg"),
+    ('\u{1F9B6}', "foot"), ('\u{1F9B7}', "tooth"), ('\u{1F9B8}', "superhero"),
+    ('\u{1F9B9}', "supervillain"), ('\u{1F9BA}', "safety_vest"),
+    ('\u{1F9BB}', "ear_with_hearing_aid"), ('\u{1F9BC}', "motorized_wheelchair"),
+    ('\u{1F9BD}', "manual_wheelchair"), ('\u{1F9BE}', "mechanical_arm"),
+    ('\u{1F9BF}', "mechanical_leg"), ('\u{1F9C0}', "cheese_wedge"), ('\u{1F9C1}', "cupcake"),
+    ('\u{1F9C2}', "salt_shaker"), ('\u{1F9C3}', "beverage_box"), ('\u{1F9C4}', "garlic"),
+    ('\u{1F9C5}', "onion"), ('\u{1F9C6}', "falafel"), ('\u{1F9C7}', "waffle"),
+    ('\u{1F9C8}', "butter"), ('\u{1F9C9}', "mate_drink"), ('\u{1F9CA}', "ice_cube"),
+    ('\u{1F9CB}', "bubble_tea"), ('\u{1F9CC}', "troll"), ('\u{1F9CD}', "standing_person"),
+    ('\u{1F9CE}', "kneeling_person"), ('\u{1F9CF}', "deaf_person"),
+    ('\u{1F9D0}', "face_with_monocle"), ('\u{1F9D1}', "person"), ('\u{1F9D2}', "child"),
+    ('\u{1F9D3}', "older_person"), ('\u{1F9D4}', "bearded_person"),
+    ('\u{1F9D5}', "person_with_headscarf"), ('\u{1F9D6}', "person_in_steamy_room"),
+    ('\u{1F9D7}', "person_climbing"), ('\u{1F9D8}', "person_in_lotus_position"),
+    ('\u{1F9D9}', "mage"), ('\u{1F9DA}', "fairy"), ('\u{1F9DB}', "vampire"),
+    ('\u{1F9DC}', "merperson"), ('\u{1F9DD}', "elf"), ('\u{1F9DE}', "genie"),
+    ('\u{1F9DF}', "zombie"), ('\u{1F9E0}', "brain"), ('\u{1F9E1}', "orange_heart"),
+    ('\u{1F9E2}', "billed_cap"), ('\u{1F9E3}', "scarf"), ('\u{1F9E4}', "gloves"),
+    ('\u{1F9E5}', "coat"), ('\u{1F9E6}', "socks"), ('\u{1F9E7}', "red_gift_envelope"),
+    ('\u{1F9E8}', "firecracker"), ('\u{1F9E9}', "jigsaw_puzzle_piece"), ('\u{1F9EA}', "test_tube"),
+    ('\u{1F9EB}', "petri_dish"), ('\u{1F9EC}', "dna_double_helix"), ('\u{1F9ED}', "compass"),
+    ('\u{1F9EE}', "abacus"), ('\u{1F9EF}', "fire_extinguisher"), ('\u{1F9F0}', "toolbox"),
+    ('\u{1F9F1}', "brick"), ('\u{1F9F2}', "magnet"), ('\u{1F9F3}', "luggage"),
+    ('\u{1F9F4}', "lotion_bottle"), ('\u{1F9F5}', "spool_of_thread"), ('\u{1F9F6}', "ball_of_yarn"),
+    ('\u{1F9F7}', "safety_pin"), ('\u{1F9F8}', "teddy_bear"), ('\u{1F9F9}', "broom"),
+    ('\u{1F9FA}', "basket"), ('\u{1F9FB}', "roll_of_paper"), ('\u{1F9FC}', "bar_of_soap"),
+    ('\u{1F9FD}', "sponge"), ('\u{1F9FE}', "receipt"), ('\u{1F9FF}', "nazar_amulet"),
+    ('\u{1FA70}', "ballet_shoes"), ('\u{1FA71}', "one_piece_swimsuit"), ('\u{1FA72}', "briefs"),
+    ('\u{1FA73}', "shorts"), ('\u{1FA74}', "thong_sandal"), ('\u{1FA75}', "light_blue_heart"),
+    ('\u{1FA76}', "grey_heart"), ('\u{1FA77}', "pink_heart"), ('\u{1FA78}', "drop_of_blood"),
+    ('\u{1FA79}', "adhesive_bandage"), ('\u{1FA7A}', "stethoscope"), ('\u{1FA7B}', "x_ray"),
+    ('\u{1FA7C}', "crutch"), ('\u{1FA80}', "yo_yo"), ('\u{1FA81}', "kite"),
+    ('\u{1FA82}', "parachute"), ('\u{1FA83}', "boomerang"), ('\u{1FA84}', "magic_wand"),
+    ('\u{1FA85}', "pinata"), ('\u{1FA86}', "nesting_dolls"), ('\u{1FA87}', "maracas"),
+    ('\u{1FA88}', "flute"), ('\u{1FA90}', "ringed_planet"), ('\u{1FA91}', "chair"),
+    ('\u{1FA92}', "razor"), ('\u{1FA93}', "axe"), ('\u{1FA94}', "diya_lamp"),
+    ('\u{1FA95}', "banjo"), ('\u{1FA96}', "military_helmet"), ('\u{1FA97}', "accordion"),
+    ('\u{1FA98}', "long_drum"), ('\u{1FA99}', "coin"), ('\u{1FA9A}', "carpentry_saw"),
+    ('\u{1FA9B}', "screwdriver"), ('\u{1FA9C}', "ladder"), ('\u{1FA9D}', "hook"),
+    ('\u{1FA9E}', "mirror"), ('\u{1FA9F}', "window"), ('\u{1FAA0}', "plunger"),
+    ('\u{1FAA1}', "sewing_needle"), ('\u{1FAA2}', "knot"), ('\u{1FAA3}', "bucket"),
+    ('\u{1FAA4}', "mouse_trap"), ('\u{1FAA5}', "toothbrush"), ('\u{1FAA6}', "headstone"),
+    ('\u{1FAA7}', "placard"), ('\u{1FAA8}', "rock"), ('\u{1FAA9}', "mirror_ball"),
+    ('\u{1FAAA}', "identification_card"), ('\u{1FAAB}', "low_battery"), ('\u{1FAAC}', "hamsa"),
+    ('\u{1FAAD}', "folding_hand_fan"), ('\u{1FAAE}', "hair_pick"), ('\u{1FAAF}', "khanda"),
+    ('\u{1FAB0}', "fly"), ('\u{1FAB1}', "worm"), ('\u{1FAB2}', "beetle"),
+    ('\u{1FAB3}', "cockroach"), ('\u{1FAB4}', "potted_plant"), ('\u{1FAB5}', "wood"),
+    ('\u{1FAB6}', "feather"), ('\u{1FAB7}', "lotus"), ('\u{1FAB8}', "coral"),
+    ('\u{1FAB9}', "empty_nest"), ('\u{1FABA}', "nest_with_eggs"), ('\u{1FABB}', "hyacinth"),
+    ('\u{1FABC}', "jellyfish"), ('\u{1FABD}', "wing"), ('\u{1FABF}', "goose"),
+    ('\u{1FAC0}', "anatomical_heart"), ('\u{1FAC1}', "lungs"), ('\u{1FAC2}', "people_hugging"),
+    ('\u{1FAC3}', "pregnant_man"), ('\u{1FAC4}', "pregnant_person"),
+    ('\u{1FAC5}', "person_with_crown"), ('\u{1FACE}', "moose"), ('\u{1FACF}', "donkey"),
+    ('\u{1FAD0}', "blueberries"), ('\u{1FAD1}', "bell_pepper"), ('\u{1FAD2}', "olive"),
+    ('\u{1FAD3}', "flatbread"), ('\u{1FAD4}', "tamale"), ('\u{1FAD5}', "fondue"),
+    ('\u{1FAD6}', "teapot"), ('\u{1FAD7}', "pouring_liquid"), ('\u{1FAD8}', "beans"),
+    ('\u{1FAD9}', "jar"), ('\u{1FADA}', "ginger_root"), ('\u{1FADB}', "pea_pod"),
+    ('\u{1FAE0}', "melting_face"), ('\u{1FAE1}', "saluting_face"),
+    ('\u{1FAE2}', "face_with_open_eyes_and_hand_over_mouth"),
+    ('\u{1FAE3}', "face_with_peeking_eye"), ('\u{1FAE4}', "face_with_diagonal_mouth"),
+    ('\u{1FAE5}', "dotted_line_face"), ('\u{1FAE6}', "biting_lip"), ('\u{1FAE7}', "bubbles"),
+    ('\u{1FAE8}', "shaking_face"), ('\u{1FAF0}', "hand_with_index_finger_and_thumb_crossed"),
+    ('\u{1FAF1}', "rightwards_hand"), ('\u{1FAF2}', "leftwards_hand"),
+    ('\u{1FAF3}', "palm_down_hand"), ('\u{1FAF4}', "palm_up_hand"),
+    ('\u{1FAF5}', "index_pointing_at_the_viewer"), ('\u{1FAF6}', "heart_hands"),
+    ('\u{1FAF7}', "leftwards_pushing_hand"), ('\u{1FAF8}', "rightwards_pushing_hand"),
+];
+
+/// Slugified name of an emoji, like `🎉` -> `party_popper`
+pub(crate) fn emoji_name(one_char: char) -> Option<&'static str> {
+    EMOJI_NAMES
+        .binary_search_by_key(&one_char, |(emoji, _)| *emoji)
+        .ok()
+        .map(|index| EMOJI_NAMES[index].1)
+}
@@ -0,0 +1,237 @@
+//! Errors reported in a [`crate::PathChange`]
+
+use core::fmt;
+use std::io;
+
+/// An error of the filesystem, with its kind and raw OS error
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IoError {
+    /// The kind of the error
+    pub kind: io::ErrorKind,
+
+    /// The raw OS error (errno), if the error comes from the system
+    pub errno: Option<i32>,
+
+    /// The error message
+    pub message: String,
+}
+
+impl IoError {
+    /// Create an error not coming from the system
+    #[must_use]
+    pub fn new(kind: io::ErrorKind, message: &str) -> Self {
+        IoError {
+            kind,
+            errno: None,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl From<&io::Error> for IoError {
+    fn from(error: &io::Error) -> Self {
+        IoError {
+            kind: error.kind(),
+            errno: error.raw_os_error(),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Error of a path, see [`NotoxError::code`] for the stable codes
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NotoxError {
+    /// the path, or its directory, cannot be written
+    PermissionDenied(IoError),
+    /// the path does not exist
+    NotFound(IoError),
+    /// the new path is on another device
+    CrossDevice(IoError),
+    /// the new path is already used by another file
+    TargetExists,
+    /// the rename was not done because a conflict aborted the plan
+    Aborted,
+    /// the content of a directory cannot be read
+    ReadDir(IoError),
+    /// the name cannot be used by the system (e.g. a NUL byte)
+    InvalidEncoding(IoError),
+    /// a journal or a JSON output cannot be read or written
+    Journal(String),
+    /// a transliteration table cannot be read
+    Table(String),
+    /// any other error of the filesystem
+    Io(IoError),
+}
+
+impl NotoxError {
+    /// Classify an error of the filesystem
+    #[must_use]
+    pub fn from_io(error: &io::Error) -> Self {
+        let io_error = IoError::from(error);
+        match io_error.kind {
+            io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem => {
+                NotoxError::PermissionDenied(io_error)
+            }
+            io::ErrorKind::NotFound => NotoxError::NotFound(io_error),
+            io::ErrorKind::CrossesDevices => NotoxError::CrossDevice(io_error),
+            io::ErrorKind::AlreadyExists => NotoxError::TargetExists,
+            io::ErrorKind::InvalidInput | io::ErrorKind::InvalidFilename => {
+                NotoxError::InvalidEncoding(io_error)
+            }
+            _ => NotoxError::Io(io_error),
+        }
+    }
+
+    /// Error of a directory that cannot be read
+    #[must_use]
+    pub fn read_dir(error: &io::Error) -> Self {
+        NotoxError::ReadDir(IoError::from(error))
+    }
+
+    /// Stable code of the error, used in the JSON output
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            NotoxError::PermissionDenied(_) => "permission_denied",
+            NotoxError::NotFound(_) => "not_found",
+            NotoxError::CrossDevice(_) => "cross_device",
+            NotoxError::TargetExists => "target_exists",
+            NotoxError::Aborted => "aborted",
+            NotoxError::ReadDir(_) => "read_dir",
+            NotoxError::InvalidEncoding(_) => "invalid_encoding",
+            NotoxError::Journal(_) => "journal",
+            NotoxError::Table(_) => "table",
+            NotoxError::Io(_) => "io",
+        }
+    }
+
+    /// The details of the error of the filesystem, if any
+    #[must_use]
+    pub fn io_error(&self) -> Option<&IoError> {
+        match self {
+            NotoxError::PermissionDenied(io_error)
+            | NotoxError::NotFound(io_error)
+            | NotoxError::CrossDevice(io_error)
+            | NotoxError::ReadDir(io_error)
+            | NotoxError::InvalidEncoding(io_error)
+            | NotoxError::Io(io_error) => Some(io_error),
+            NotoxError::TargetExists
+            | NotoxError::Aborted
+            | NotoxError::Journal(_)
+            | NotoxError::Table(_) => None,
+        }
+    }
+
+    /// The kind of the error of the filesystem, if any
+    #[must_use]
+    pub fn kind(&self) -> Option<io::ErrorKind> {
+        match self {
+            NotoxError::TargetExists => Some(io::ErrorKind::AlreadyExists),
+            _ => self.io_error().map(|io_error| io_error.kind),
+        }
+    }
+
+    /// The raw OS error (errno), if any
+    #[must_use]
+    pub fn raw_os_error(&self) -> Option<i32> {
+        self.io_error().and_then(|io_error| io_error.errno)
+    }
+}
+
+impl fmt::Display for NotoxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotoxError::TargetExists => write!(f, "target already exists"),
+            NotoxError::Aborted => write!(f, "aborted"),
+            NotoxError::ReadDir(io_error) => {
+                write!(f, "Error while reading directory: {}", io_error.message)
+            }
+            NotoxError::Journal(message) | NotoxError::Table(message) => write!(f, "{message}"),
+            NotoxError::PermissionDenied(io_error)
+            | NotoxError::NotFound(io_error)
+            | NotoxError::CrossDevice(io_error)
+            | NotoxError::InvalidEncoding(io_error)
+            | NotoxError::Io(io_error) => write!(f, "{}", io_error.message),
+        }
+    }
+}
+
+impl std::error::Error for NotoxError {}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for NotoxError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("NotoxError", 3)?;
+        state.serialize_field("code", self.code())?;
+        match self.io_error() {
+            Some(io_error) => state.serialize_field("message", &io_error.message)?,
+            None => state.serialize_field("message", &self.to_string())?,
+        }
+        state.serialize_field("errno", &self.raw_os_error())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NotoxError {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Helper {
+            /// Error of the JSON output
+            Detailed {
+                /// Stable code
+                code: String,
+                /// Error message
+                message: String,
+                /// Raw OS error
+                #[serde(default)]
+                errno: Option<i32>,
+            },
+            /// Error message of the outputs before the error codes
+            Message(String),
+        }
+
+        let (code, message, errno) = match Helper::deserialize(deserializer)? {
+            Helper::Detailed {
+                code,
+                message,
+                errno,
+            } => (code, message, errno),
+            Helper::Message(message) => (String::from("io"), message, None),
+        };
+        let default_kind = match code.as_str() {
+            "permission_denied" => io::ErrorKind::PermissionDenied,
+            "not_found" => io::ErrorKind::NotFound,
+            "cross_device" => io::ErrorKind::CrossesDevices,
+            "invalid_encoding" => io::ErrorKind::InvalidInput,
+            _ => io::ErrorKind::Other,
+        };
+        let io_error = IoError {
+            kind: errno.map_or(default_kind, |raw| io::Error::from_raw_os_error(raw).kind()),
+            errno,
+            message,
+        };
+        Ok(match code.as_str() {
+            "permission_denied" => NotoxError::PermissionDenied(io_error),
+            "not_found" => NotoxError::NotFound(io_error),
+            "cross_device" => NotoxError::CrossDevice(io_error),
+            "target_exists" => NotoxError::TargetExists,
+            "aborted" => NotoxError::Aborted,
+            "read_dir" => NotoxError::ReadDir(io_error),
+            "invalid_encoding" => NotoxError::InvalidEncoding(io_error),
+            "journal" => NotoxError::Journal(io_error.message),
+            "table" => NotoxError::Table(io_error.message),
+            _ => NotoxError::Io(io_error),
+        })
+    }
+}
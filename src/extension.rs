@@ -0,0 +1,162 @@
+//! Extension-aware cleaning: the stem and the extensions are cleaned separately
+
+use crate::{clean_name_traced, push_step, NotoxArgs, TraceAction, TraceStep};
+
+/// Extensions made of several parts, kept together
+pub const MULTI_PART_EXTENSIONS: &[&str] = &[
+    "tar.gz", "tar.bz2", "tar.xz", "tar.zst", "tar.lz", "tar.lz4", "tar.lzma", "tar.br", "tar.Z",
+];
+
+/// Extensions replaced by their usual spelling, with [`ExtensionOptions::synonyms`]
+pub const EXTENSION_SYNONYMS: &[(&str, &str)] =
+    &[("jpeg", "jpg"), ("tiff", "tif"), ("htm", "html")];
+
+/// Longest extension, longer parts are considered as part of the stem
+const MAX_EXTENSION_LEN: usize = 10;
+
+/// How the extensions are cleaned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ExtensionOptions {
+    /// lowercase the extensions, `.JPG` becomes `.jpg`
+    pub lowercase: bool,
+
+    /// replace the extensions by their usual spelling, see [`EXTENSION_SYNONYMS`]
+    pub synonyms: bool,
+}
+
+/// Check if a part of a name looks like an extension
+fn is_extension(part: &[u8]) -> bool {
+    !part.is_empty()
+        && part.len() <= MAX_EXTENSION_LEN
+        && part.iter().all(u8::is_ascii_alphanumeric)
+}
+
+/// Split a name in stem and extensions (starting with a dot, or empty).
+/// The leading dots of hidden files belong to the stem
+pub(crate) fn split_extension(name: &[u8]) -> (&[u8], &[u8]) {
+    let stem_start = name.iter().take_while(|byte| **byte == b'.').count();
+    for multi_part in MULTI_PART_EXTENSIONS {
+        let suffix_len = multi_part.len() + 1;
+        if name.len() > stem_start + suffix_len {
+            let (stem, suffix) = name.split_at(name.len() - suffix_len);
+            if suffix[0] == b'.' && suffix[1..].eq_ignore_ascii_case(multi_part.as_bytes()) {
+                return (stem, suffix);
+            }
+        }
+    }
+    match name[stem_start..].iter().rposition(|byte| *byte == b'.') {
+        Some(position) if position > 0 && is_extension(&name[stem_start + position + 1..]) => {
+            name.split_at(stem_start + position)
+        }
+        _ => (name, &[]),
+    }
+}
+
+/// Apply the extension options to one part of an extension
+fn clean_extension_part(part: &str, options: ExtensionOptions) -> String {
+    let mut cleaned = if options.lowercase {
+        part.to_ascii_lowercase()
+    } else {
+        part.to_string()
+    };
+    if options.synonyms {
+        if let Some((_, usual)) = EXTENSION_SYNONYMS
+            .iter()
+            .find(|(synonym, _)| synonym.eq_ignore_ascii_case(&cleaned))
+        {
+            cleaned = if cleaned.bytes().all(|byte| byte.is_ascii_uppercase()) {
+                usual.to_ascii_uppercase()
+            } else {
+                (*usual).to_string()
+            };
+        }
+    }
+    cleaned
+}
+
+/// Clean the extensions of a name, starting with a dot.
+/// The ranges of the recorded steps are relative to the extensions
+pub(crate) fn clean_extensions(
+    extensions: &[u8],
+    options: &NotoxArgs,
+    mut trace: Option<&mut Vec<TraceStep>>,
+) -> String {
+    let cleaned = clean_name_traced(extensions, options, trace.as_deref_mut());
+    if !options.extension.lowercase && !options.extension.synonyms {
+        return cleaned;
+    }
+    let new_extensions = cleaned
+        .split('.')
+        .map(|part| clean_extension_part(part, options.extension))
+        .collect::<Vec<String>>()
+        .join(".");
+    if new_extensions != cleaned {
+        push_step(
+            &mut trace,
+            0..extensions.len(),
+            None,
+            TraceAction::ExtensionCleaned(new_extensions.clone()),
+        );
+    }
+    new_extensions
+}
+
+/// Check if the part of a stem after its last dot is cleaned to nothing, like `日本` in `x.日本`
+fn ends_with_dropped_part(stem: &[u8], options: &NotoxArgs) -> bool {
+    stem.iter()
+        .rposition(|byte| *byte == b'.')
+        .map(|position| &stem[position + 1..])
+        .is_some_and(|part| !part.is_empty() && clean_name_traced(part, options, None).is_empty())
+}
+
+/// Clean a name, the stem and the extensions separately, recording the steps
+pub(crate) fn clean_with_extension(
+    name: &[u8],
+    options: &NotoxArgs,
+    mut trace: Option<&mut Vec<TraceStep>>,
+) -> String {
+    let (stem, extensions) = split_extension(name);
+    let mut cleaned = clean_name_traced(stem, options, trace.as_deref_mut());
+    if !extensions.is_empty() && cleaned.ends_with(['_', '-', '.']) {
+        // the dot of the extension replaces the last separator of the stem
+        cleaned.pop();
+        push_step(
+            &mut trace,
+            0..stem.len(),
+            None,
+            TraceAction::TrimmedBeforeExtension,
+        );
+    } else if extensions.is_empty()
+        && cleaned.ends_with('.')
+        && ends_with_dropped_part(stem, options)
+    {
+        // the dot was followed by characters dropped by the cleaning: `x.日本` -> `x`
+        cleaned.pop();
+        push_step(&mut trace, 0..stem.len(), None, TraceAction::TrimmedDot);
+    }
+    if let Some(case) = options.case {
+        // the stem is transliterated first, so the case mapping stays ASCII-only
+        let cased = case.apply(&cleaned);
+        if cased != cleaned {
+            push_step(
+                &mut trace,
+                0..stem.len(),
+                None,
+                TraceAction::Cased(cased.clone()),
+            );
+        }
+        cleaned = cased;
+    }
+    if extensions.is_empty() {
+        return cleaned;
+    }
+    let first_step = trace.as_ref().map_or(0, |steps| steps.len());
+    cleaned.push_str(&clean_extensions(extensions, options, trace.as_deref_mut()));
+    if let Some(steps) = trace {
+        // the steps of the extensions start after the stem
+        for one_step in &mut steps[first_step..] {
+            one_step.range = one_step.range.start + stem.len()..one_step.range.end + stem.len();
+        }
+    }
+    cleaned
+}
@@ -0,0 +1,93 @@
+//! Fallback names, for the names with nothing left once cleaned
+
+use core::fmt;
+use std::ffi::{OsStr, OsString};
+
+use crate::{clean_name_traced, extension, NotoxArgs};
+
+/// Warning reported when a fallback name is used
+pub(crate) const FALLBACK_WARNING: &str = "nothing left once cleaned, fallback name used";
+
+/// How to name a path whose name has nothing left once cleaned
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum FallbackName {
+    /// a short stable hash of the original name, like `3b2c9e1a`
+    #[default]
+    Hash,
+    /// the code points of the original name, like `u65e5_u672c`
+    CodePoints,
+    /// a fixed name, cleaned
+    Placeholder(String),
+}
+
+impl FallbackName {
+    /// Get the fallback from its name, any other text is a placeholder
+    #[must_use]
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "hash" => FallbackName::Hash,
+            "codepoints" => FallbackName::CodePoints,
+            placeholder => FallbackName::Placeholder(placeholder.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for FallbackName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FallbackName::Hash => write!(f, "hash"),
+            FallbackName::CodePoints => write!(f, "codepoints"),
+            FallbackName::Placeholder(placeholder) => write!(f, "{placeholder}"),
+        }
+    }
+}
+
+/// Check if the name cleaned with the options needs a fallback: the stem has no ASCII letter or digit left
+#[must_use]
+pub fn needs_fallback(name: &OsStr, cleaned: &OsStr, options: &NotoxArgs) -> bool {
+    name != cleaned && stem_needs_fallback(name, options)
+}
+
+/// Check if the stem of a name has no ASCII letter or digit left once cleaned with the options
+fn stem_needs_fallback(name: &OsStr, options: &NotoxArgs) -> bool {
+    let (stem, _) = extension::split_extension(name.as_encoded_bytes());
+    !clean_name_traced(stem, options, None)
+        .bytes()
+        .any(|byte| byte.is_ascii_alphanumeric())
+}
+
+/// Short stable hash of the bytes (FNV-1a)
+fn short_hash(bytes: &[u8]) -> String {
+    let hash = bytes.iter().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(*byte)).wrapping_mul(0x0100_0193)
+    });
+    format!("{hash:08x}")
+}
+
+/// Name a path whose stem has nothing left once cleaned, keeping the extensions
+pub(crate) fn fallback_name(name: &OsStr, options: &NotoxArgs) -> OsString {
+    let bytes = name.as_encoded_bytes();
+    let (stem, extensions) = extension::split_extension(bytes);
+    let leading_dots = stem.iter().take_while(|byte| **byte == b'.').count();
+    let stem = &stem[leading_dots..];
+    let new_stem = match &options.fallback {
+        FallbackName::Hash => short_hash(bytes),
+        FallbackName::CodePoints => String::from_utf8_lossy(stem)
+            .chars()
+            .map(|one_char| format!("u{:04x}", u32::from(one_char)))
+            .collect::<Vec<String>>()
+            .join("_"),
+        FallbackName::Placeholder(placeholder) => {
+            let cleaned = clean_name_traced(placeholder.as_bytes(), options, None);
+            if cleaned.bytes().any(|byte| byte.is_ascii_alphanumeric()) {
+                cleaned
+            } else {
+                short_hash(bytes)
+            }
+        }
+    };
+    let mut new_name = ".".repeat(leading_dots);
+    new_name.push_str(&new_stem);
+    new_name.push_str(&extension::clean_extensions(extensions, options, None));
+    OsString::from(new_name)
+}
@@ -0,0 +1,94 @@
+//! Romanization of the Greek letters (ELOT 743)
+
+use crate::romanization::{apply_case, to_lower};
+
+/// Base letter of a lowercase Greek letter, without its accent or diaeresis
+fn base_letter(letter: char) -> char {
+    match letter {
+        'ά' => 'α',
+        'έ' => 'ε',
+        'ή' => 'η',
+        'ί' | 'ϊ' | 'ΐ' => 'ι',
+        'ό' => 'ο',
+        'ύ' | 'ϋ' | 'ΰ' => 'υ',
+        'ώ' => 'ω',
+        'ς' => 'σ',
+        _ => letter,
+    }
+}
+
+/// Check if the letter is a Greek letter
+fn is_greek(letter: char) -> bool {
+    ('\u{0370}'..='\u{03FF}').contains(&letter)
+}
+
+/// Check if `υ` is read `f` before this letter: a voiceless consonant, or the end of the word
+fn is_voiceless(next: Option<char>) -> bool {
+    next.is_none_or(|next_letter| {
+        !is_greek(next_letter)
+            || matches!(
+                base_letter(next_letter),
+                'θ' | 'κ' | 'ξ' | 'π' | 'σ' | 'τ' | 'φ' | 'χ' | 'ψ'
+            )
+    })
+}
+
+/// Romanization of a lowercase Greek letter
+fn elot_743(letter: char, previous: Option<char>, next: Option<char>) -> Option<&'static str> {
+    let previous_base = previous.map(base_letter);
+    let next_base = next.map(base_letter);
+    Some(match base_letter(letter) {
+        'α' => "a",
+        'β' => "v",
+        // `γγ` -> `ng`, `γξ` -> `nx`, `γχ` -> `nch`
+        'γ' if matches!(next_base, Some('γ' | 'ξ' | 'χ')) => "n",
+        'γ' => "g",
+        'δ' => "d",
+        'ε' => "e",
+        'ζ' => "z",
+        'η' | 'ι' => "i",
+        'θ' => "th",
+        'κ' => "k",
+        'λ' => "l",
+        'μ' => "m",
+        'ν' => "n",
+        'ξ' => "x",
+        'ο' | 'ω' => "o",
+        'π' => "p",
+        'ρ' => "r",
+        'σ' => "s",
+        'τ' => "t",
+        // with a diaeresis, `υ` is not part of a digraph
+        'υ' if matches!(letter, 'ϋ' | 'ΰ') => "y",
+        'υ' if previous_base == Some('ο') => "u",
+        'υ' if matches!(previous_base, Some('α' | 'ε' | 'η')) && is_voiceless(next) => "f",
+        'υ' if matches!(previous_base, Some('α' | 'ε' | 'η')) => "v",
+        'υ' => "y",
+        'φ' => "f",
+        'χ' => "ch",
+        'ψ' => "ps",
+        _ => return None,
+    })
+}
+
+/// Romanization of a Greek letter, keeping its case, with the length of the next letter if it is used too
+pub(crate) fn romanize(
+    one_char: char,
+    previous: Option<char>,
+    next: Option<char>,
+) -> Option<(String, usize)> {
+    let letter = to_lower(one_char);
+    let starts_word = previous.is_none_or(|previous_letter| !is_greek(previous_letter));
+    // `μπ` -> `b` at the start of a word
+    if let Some(next_letter) = next.filter(|next_letter| to_lower(*next_letter) == 'π') {
+        if starts_word && letter == 'μ' {
+            let romanized = apply_case("b", one_char.is_uppercase(), previous, next);
+            return Some((romanized, next_letter.len_utf8()));
+        }
+    }
+    let romanized = elot_743(letter, previous.map(to_lower), next.map(to_lower))?;
+    Some((
+        apply_case(romanized, one_char.is_uppercase(), previous, next),
+        0,
+    ))
+}
@@ -0,0 +1,217 @@
+//! Romanization of the Hangul syllables (Revised Romanization of Korean), by splitting
+//! the syllables into their initial, vowel and final jamo
+
+use crate::romanization::{first_char, last_char};
+
+/// Romanization of the initial consonants, from `ㄱ` to `ㅎ`
+#[rustfmt::skip]
+const INITIALS: &[&str] = &[
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p", "h",
+];
+
+/// Romanization of the vowels, from `ㅏ` to `ㅣ`
+#[rustfmt::skip]
+const VOWELS: &[&str] = &[
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae",
+    "oe", "yo", "u", "wo", "we", "wi", "yu", "eu", "ui", "i",
+];
+
+/// Romanization of the final consonants, from none to `ㅎ`
+#[rustfmt::skip]
+const FINALS: &[&str] = &[
+    "", "k", "k", "k", "n", "n", "n", "t", "l", "k", "m", "l", "l", "l",
+    "p", "l", "m", "p", "p", "t", "t", "ng", "t", "t", "k", "t", "p", "t",
+];
+
+/// Romanization of the final consonants before a vowel, where they are read with the next syllable
+#[rustfmt::skip]
+const LIAISONS: &[&str] = &[
+    "", "g", "kk", "ks", "n", "nj", "n", "d", "r", "lg", "lm", "lb", "ls", "lt",
+    "lp", "r", "m", "b", "ps", "s", "ss", "ng", "j", "ch", "k", "t", "p", "",
+];
+
+/// First precomposed syllable
+const FIRST_SYLLABLE: u32 = 0xAC00;
+
+/// Last precomposed syllable
+const LAST_SYLLABLE: u32 = 0xD7A3;
+
+/// Number of syllables with the same initial consonant
+const INITIAL_COUNT: u32 = 588;
+
+/// Number of final consonants, with none
+const FINAL_COUNT: u32 = 28;
+
+/// First conjoining initial consonant, used in decomposed names
+const FIRST_INITIAL_JAMO: u32 = 0x1100;
+
+/// First conjoining vowel, used in decomposed names
+const FIRST_VOWEL_JAMO: u32 = 0x1161;
+
+/// First conjoining final consonant, used in decomposed names
+const FIRST_FINAL_JAMO: u32 = 0x11A8;
+
+/// Index of the silent initial `ㅇ`
+const SILENT_INITIAL: usize = 11;
+
+/// Index of the initial `ㄱ`
+const INITIAL_G: usize = 0;
+
+/// Index of the initial `ㄴ`
+const INITIAL_N: usize = 2;
+
+/// Index of the initial `ㄷ`
+const INITIAL_D: usize = 3;
+
+/// Index of the initial `ㄹ`
+const INITIAL_R: usize = 5;
+
+/// Index of the initial `ㅁ`
+const INITIAL_M: usize = 6;
+
+/// Index of the initial `ㅈ`
+const INITIAL_J: usize = 12;
+
+/// Index of the final `ㄴ`
+const FINAL_N: usize = 4;
+
+/// Index of the final `ㄶ`
+const FINAL_NH: usize = 6;
+
+/// Index of the final `ㄷ`
+const FINAL_D: usize = 7;
+
+/// Index of the final `ㄾ`
+const FINAL_LT: usize = 13;
+
+/// Index of the final `ㅀ`
+const FINAL_LH: usize = 15;
+
+/// Index of the final `ㅌ`
+const FINAL_T: usize = 25;
+
+/// Index of the final `ㅎ`
+const FINAL_H: usize = 27;
+
+/// Index of the vowel `ㅣ`
+const VOWEL_I: usize = 20;
+
+/// Check if a character starts a syllable: a precomposed one, or a conjoining initial consonant
+pub(crate) fn is_hangul(one_char: char) -> bool {
+    matches!(one_char, '\u{AC00}'..='\u{D7A3}' | '\u{1100}'..='\u{1112}')
+}
+
+/// Index of a conjoining jamo in its block, if the character is one
+fn jamo_index(one_char: char, first: u32, count: usize) -> Option<usize> {
+    u32::from(one_char)
+        .checked_sub(first)
+        .and_then(|index| usize::try_from(index).ok())
+        .filter(|index| *index < count)
+}
+
+/// Initial, vowel and final of the syllable starting with a character,
+/// and the number of bytes of the syllable after the character
+fn syllable(first: char, rest: &[u8]) -> Option<([usize; 3], usize)> {
+    let code = u32::from(first);
+    if (FIRST_SYLLABLE..=LAST_SYLLABLE).contains(&code) {
+        let index = code - FIRST_SYLLABLE;
+        let jamo = [
+            index / INITIAL_COUNT,
+            index % INITIAL_COUNT / FINAL_COUNT,
+            index % FINAL_COUNT,
+        ];
+        return Some((jamo.map(|one_jamo| one_jamo as usize), 0));
+    }
+    // decomposed syllable: an initial, a vowel and maybe a final
+    let initial = jamo_index(first, FIRST_INITIAL_JAMO, INITIALS.len())?;
+    let vowel_char = first_char(rest)?;
+    let vowel = jamo_index(vowel_char, FIRST_VOWEL_JAMO, VOWELS.len())?;
+    let used = vowel_char.len_utf8();
+    let final_jamo = first_char(&rest[used..]).and_then(|final_char| {
+        jamo_index(final_char, FIRST_FINAL_JAMO, FINALS.len() - 1)
+            .map(|index| (index + 1, final_char.len_utf8()))
+    });
+    Some(match final_jamo {
+        Some((final_index, final_len)) => ([initial, vowel, final_index], used + final_len),
+        None => ([initial, vowel, 0], used),
+    })
+}
+
+/// Final consonant of the syllable at the end of the bytes, 0 if it has none
+fn previous_final(bytes: &[u8]) -> Option<usize> {
+    let last = last_char(bytes)?;
+    let code = u32::from(last);
+    if (FIRST_SYLLABLE..=LAST_SYLLABLE).contains(&code) {
+        return Some(((code - FIRST_SYLLABLE) % FINAL_COUNT) as usize);
+    }
+    jamo_index(last, FIRST_FINAL_JAMO, FINALS.len() - 1)
+        .map(|index| index + 1)
+        .or_else(|| jamo_index(last, FIRST_VOWEL_JAMO, VOWELS.len()).map(|_| 0))
+}
+
+/// Romanization of an initial consonant, knowing the final consonant before it
+fn initial_consonant(initial: usize, previous: Option<usize>) -> &'static str {
+    match (initial, previous) {
+        // `ㄹㄹ` and `ㄴㄹ` -> `ll`, `ㄹㄴ` -> `ll`
+        (INITIAL_R, Some(previous)) if FINALS[previous] == "l" || previous == FINAL_N => "l",
+        (INITIAL_N, Some(previous)) if FINALS[previous] == "l" => "l",
+        // `ㄹ` is read `n` after the other consonants: `종로` -> `jongno`
+        (INITIAL_R, Some(previous)) if previous != 0 => "n",
+        // `ㅎ` aspirates `ㄱ`, `ㄷ` and `ㅈ`: `좋다` -> `jota`, `많고` -> `manko`
+        (INITIAL_G, Some(FINAL_H | FINAL_NH | FINAL_LH)) => "k",
+        (INITIAL_D, Some(FINAL_H | FINAL_NH | FINAL_LH)) => "t",
+        (INITIAL_J, Some(FINAL_H | FINAL_NH | FINAL_LH)) => "ch",
+        _ => INITIALS[initial],
+    }
+}
+
+/// Romanization of a final consonant, knowing the initial consonant and the vowel after it
+fn final_consonant(final_index: usize, next: Option<(usize, usize)>) -> &'static str {
+    match (FINALS[final_index], next.map(|(initial, _)| initial)) {
+        // `ㄷ` and `ㅌ` are palatalized before `이`: `굳이` -> `guji`, `같이` -> `gachi`
+        (_, Some(SILENT_INITIAL)) if next.is_some_and(|(_, vowel)| vowel == VOWEL_I) => {
+            match final_index {
+                FINAL_D => "j",
+                FINAL_T => "ch",
+                FINAL_LT => "lch",
+                _ => LIAISONS[final_index],
+            }
+        }
+        (_, Some(SILENT_INITIAL)) => LIAISONS[final_index],
+        // `ㅎ` is read with the aspirated consonant after it
+        (_, Some(INITIAL_G | INITIAL_D | INITIAL_J))
+            if matches!(final_index, FINAL_H | FINAL_NH | FINAL_LH) =>
+        {
+            match final_index {
+                FINAL_NH => "n",
+                FINAL_LH => "l",
+                _ => "",
+            }
+        }
+        ("n", Some(INITIAL_R)) if final_index == FINAL_N => "l",
+        // the consonants are nasalized before `ㄴ`, `ㄹ` and `ㅁ`: `합니다` -> `hamnida`
+        ("k", Some(INITIAL_N | INITIAL_R | INITIAL_M)) => "ng",
+        ("t", Some(INITIAL_N | INITIAL_R | INITIAL_M)) => "n",
+        ("p", Some(INITIAL_N | INITIAL_R | INITIAL_M)) => "m",
+        (romanized, _) => romanized,
+    }
+}
+
+/// Romanization of the syllable starting with this character, knowing the bytes before and after it.
+/// Returns the romanization and the number of bytes after the character also romanized
+pub(crate) fn romanize(one_char: char, before: &[u8], rest: &[u8]) -> Option<(String, usize)> {
+    let ([initial, vowel, final_index], used) = syllable(one_char, rest)?;
+    let after = &rest[used..];
+    let next = first_char(after)
+        .and_then(|next_char| syllable(next_char, &after[next_char.len_utf8()..]))
+        .map(|([next_initial, next_vowel, _], _)| (next_initial, next_vowel));
+    Some((
+        format!(
+            "{}{}{}",
+            initial_consonant(initial, previous_final(before)),
+            VOWELS[vowel],
+            final_consonant(final_index, next)
+        ),
+        used,
+    ))
+}
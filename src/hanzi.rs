@@ -0,0 +1,42 @@
+//! Romanization of the Chinese characters (Hanzi) into toneless pinyin, with the `pinyin` feature
+
+use core::fmt;
+
+/// How the pinyin syllables of consecutive Chinese characters are separated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PinyinSeparator {
+    /// syllables joined: `报告` -> `baogao`
+    Joined,
+    /// syllables separated by a dash: `报告` -> `bao-gao`
+    Dash,
+    /// syllables separated by an underscore: `报告` -> `bao_gao`
+    Underscore,
+}
+
+impl fmt::Display for PinyinSeparator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PinyinSeparator::Joined => write!(f, "joined"),
+            PinyinSeparator::Dash => write!(f, "dash"),
+            PinyinSeparator::Underscore => write!(f, "underscore"),
+        }
+    }
+}
+
+/// Romanization of a Chinese character, separated from the Chinese character before it
+#[cfg(feature = "pinyin")]
+pub(crate) fn romanize(
+    one_char: char,
+    previous: Option<char>,
+    separator: PinyinSeparator,
+) -> Option<String> {
+    let syllable = crate::pinyin::reading(one_char)?;
+    if previous.and_then(crate::pinyin::reading).is_none() {
+        return Some(syllable.to_string());
+    }
+    Some(match separator {
+        PinyinSeparator::Joined => syllable.to_string(),
+        PinyinSeparator::Dash => format!("-{syllable}"),
+        PinyinSeparator::Underscore => format!("_{syllable}"),
+    })
+}
@@ -0,0 +1,103 @@
+//! Romanization of the Hebrew letters (simplified ALA-LC, without diacritics), with the points if written
+
+use crate::romanization::{chars, chars_back};
+
+/// Dagesh, making `ב`, `כ` and `פ` hard
+const DAGESH: char = '\u{05BC}';
+
+/// Hiriq, the `i`
+const HIRIQ: char = '\u{05B4}';
+
+/// Holam, the `o`
+const HOLAM: char = '\u{05B9}';
+
+/// Holam haser for vav, the `o` written on a `ו`
+const HOLAM_HASER: char = '\u{05BA}';
+
+/// Sin dot, making `ש` a `s`
+const SIN_DOT: char = '\u{05C2}';
+
+/// Check if a character is a Hebrew letter
+fn is_letter(one_char: char) -> bool {
+    matches!(one_char, '\u{05D0}'..='\u{05EA}' | '\u{05F0}'..='\u{05F2}')
+}
+
+/// Check if a character is a point or an accent, written over or under a letter
+fn is_point(one_char: char) -> bool {
+    ('\u{0591}'..='\u{05BD}').contains(&one_char)
+        || matches!(
+            one_char,
+            '\u{05BF}' | '\u{05C1}' | '\u{05C2}' | '\u{05C4}' | '\u{05C5}' | '\u{05C7}'
+        )
+}
+
+/// Check if a point is a vowel, or the sheva
+fn is_vowel_point(point: char) -> bool {
+    matches!(point, '\u{05B0}'..='\u{05BB}' | '\u{05C7}')
+}
+
+/// Romanization of a point, the dagesh, the dots and the accents are dropped
+fn point(one_point: char) -> &'static str {
+    match one_point {
+        '\u{05B1}' | '\u{05B5}' | '\u{05B6}' => "e",
+        '\u{05B2}' | '\u{05B7}' | '\u{05B8}' => "a",
+        '\u{05B3}' | HOLAM | HOLAM_HASER | '\u{05C7}' => "o",
+        HIRIQ => "i",
+        '\u{05BB}' => "u",
+        _ => "",
+    }
+}
+
+/// Romanization of a Hebrew letter or point, knowing the bytes before and after it
+pub(crate) fn romanize(one_char: char, before: &[u8], rest: &[u8]) -> Option<String> {
+    if is_point(one_char) {
+        return Some(point(one_char).to_string());
+    }
+    let marks: Vec<char> = chars(rest).take_while(|mark| is_point(*mark)).collect();
+    let has_vowel = marks.iter().any(|mark| is_vowel_point(*mark));
+    let previous = chars_back(before).next();
+    let starts_word = !chars_back(before)
+        .find(|before_char| !is_point(*before_char))
+        .is_some_and(is_letter);
+    let next = chars(rest).find(|next_char| !is_point(*next_char));
+    Some(
+        match one_char {
+            'א' | 'ע' | '׳' | '״' => "",
+            'ב' if marks.contains(&DAGESH) => "b",
+            // `ו` is a vowel with a holam, with a dagesh and no vowel, or alone in a word
+            'ו' if marks.contains(&HOLAM) || marks.contains(&HOLAM_HASER) => "",
+            'ו' if marks.contains(&DAGESH) && !has_vowel => "u",
+            'ו' if !(starts_word || has_vowel || next == Some('ו') || previous == Some('ו')) => {
+                "o"
+            }
+            'ב' | 'ו' | 'װ' => "v",
+            'ג' => "g",
+            'ד' => "d",
+            'ה' | 'ח' => "h",
+            'ז' => "z",
+            'ט' | 'ת' => "t",
+            // `י` after a hiriq is the long `i`, already written
+            'י' if starts_word || has_vowel => "y",
+            'י' if previous == Some(HIRIQ) => "",
+            'י' => "i",
+            'כ' | 'ך' if marks.contains(&DAGESH) => "k",
+            'כ' | 'ך' => "kh",
+            'ל' => "l",
+            'מ' | 'ם' => "m",
+            'נ' | 'ן' => "n",
+            'ס' => "s",
+            'פ' | 'ף' if marks.contains(&DAGESH) => "p",
+            'פ' | 'ף' => "f",
+            'צ' | 'ץ' => "ts",
+            'ק' => "k",
+            'ר' => "r",
+            'ש' if marks.contains(&SIN_DOT) => "s",
+            'ש' => "sh",
+            'ױ' => "oy",
+            'ײ' => "ey",
+            '־' => "-",
+            _ => return None,
+        }
+        .to_string(),
+    )
+}
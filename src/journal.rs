@@ -0,0 +1,47 @@
+//! Journal of the renames, to undo them later, and JSON outputs to apply
+
+use std::path::Path;
+
+use crate::{NotoxError, PathChange, PlannedRename, RenamePlan};
+
+/// Write the done renames to a journal file
+pub(crate) fn write_journal(journal_path: &Path, results: &[PathChange]) -> Result<(), NotoxError> {
+    let done_renames = results
+        .iter()
+        .filter(|one_change| matches!(one_change, PathChange::Changed { .. }))
+        .collect::<Vec<&PathChange>>();
+    let json_string = serde_json::to_string_pretty(&done_renames)
+        .map_err(|e| NotoxError::Journal(format!("Cannot serialize journal: {e}")))?;
+    std::fs::write(journal_path, json_string)
+        .map_err(|e| NotoxError::Journal(format!("Cannot write journal: {e}")))
+}
+
+/// Read the changes of a journal file or of a JSON output
+pub(crate) fn read_changes(json_path: &Path) -> Result<Vec<PathChange>, NotoxError> {
+    let json_string = std::fs::read_to_string(json_path)
+        .map_err(|e| NotoxError::Journal(format!("Cannot read file: {e}")))?;
+    serde_json::from_str(&json_string)
+        .map_err(|e| NotoxError::Journal(format!("Cannot parse file: {e}")))
+}
+
+/// Build the plan reversing the renames of a journal, deepest paths first
+pub(crate) fn undo_plan(journal: Vec<PathChange>) -> RenamePlan {
+    let mut renames = journal
+        .into_iter()
+        .filter_map(|one_change| match one_change {
+            PathChange::Changed { path, modified, .. } => Some(PlannedRename {
+                path: modified,
+                modified: path,
+                selected: true,
+                fallback: false,
+            }),
+            _ => None,
+        })
+        .collect::<Vec<PlannedRename>>();
+    // the content of a directory is restored before the directory itself
+    renames.sort_by_key(|one_rename| std::cmp::Reverse(one_rename.path.components().count()));
+    RenamePlan {
+        renames,
+        ..RenamePlan::default()
+    }
+}
@@ -0,0 +1,171 @@
+//! Romanization of the hiragana and the katakana (modified Hepburn), the long vowels are doubled
+
+use crate::romanization::{first_char, last_char};
+
+/// Romanization of the hiragana, from `ぁ` (U+3041) to `ゖ` (U+3096)
+#[rustfmt::skip]
+const HIRAGANA: &[&str] = &[
+    "a", "a", "i", "i", "u", "u", "e", "e", "o", "o", // ぁあぃいぅうぇえぉお
+    "ka", "ga", "ki", "gi", "ku", "gu", "ke", "ge", "ko", "go", // かがきぎくぐけげこご
+    "sa", "za", "shi", "ji", "su", "zu", "se", "ze", "so", "zo", // さざしじすずせぜそぞ
+    "ta", "da", "chi", "ji", "tsu", "tsu", "zu", "te", "de", "to", // ただちぢっつづてでと
+    "do", "na", "ni", "nu", "ne", "no", "ha", "ba", "pa", "hi", // どなにぬねのはばぱひ
+    "bi", "pi", "fu", "bu", "pu", "he", "be", "pe", "ho", "bo", // びぴふぶぷへべぺほぼ
+    "po", "ma", "mi", "mu", "me", "mo", "ya", "ya", "yu", "yu", // ぽまみむめもゃやゅゆ
+    "yo", "yo", "ra", "ri", "ru", "re", "ro", "wa", "wa", "i", // ょよらりるれろゎわゐ
+    "e", "o", "n", "vu", "ka", "ke", // ゑをんゔゕゖ
+];
+
+/// Small `tsu`, doubling the next consonant
+const SOKUON: char = 'っ';
+
+/// Long vowel mark, doubling the previous vowel
+const LONG_VOWEL: char = 'ー';
+
+/// Combining voiced sound mark (dakuten), used in decomposed names
+const VOICED_MARK: char = '\u{3099}';
+
+/// Combining semi-voiced sound mark (handakuten), used in decomposed names
+const SEMI_VOICED_MARK: char = '\u{309A}';
+
+/// Check if a character is a kana, or a mark of the kana
+pub(crate) fn is_kana(one_char: char) -> bool {
+    matches!(one_char, '\u{3041}'..='\u{309E}' | '\u{30A1}'..='\u{30FA}' | '\u{30FC}'..='\u{30FE}')
+}
+
+/// Hiragana of a kana, the katakana are moved to the hiragana block
+fn to_hiragana(kana: char) -> char {
+    match kana {
+        'ァ'..='ヶ' | 'ヽ' | 'ヾ' => char::from_u32(u32::from(kana) - 0x60).unwrap_or(kana),
+        _ => kana,
+    }
+}
+
+/// Romanization of a hiragana, or of a katakana without hiragana
+fn romaji(kana: char) -> Option<&'static str> {
+    match kana {
+        'ヷ' => Some("va"),
+        'ヸ' => Some("vi"),
+        'ヹ' => Some("ve"),
+        'ヺ' => Some("vo"),
+        _ => {
+            let index = u32::from(kana).checked_sub(0x3041)?;
+            HIRAGANA.get(usize::try_from(index).ok()?).copied()
+        }
+    }
+}
+
+/// Kana shifted in the hiragana block, if its romanization starts with one of the consonants
+fn shifted(kana: char, shift: u32, consonants: &[char]) -> Option<char> {
+    let shifted_kana = char::from_u32(u32::from(kana) + shift)?;
+    romaji(shifted_kana)
+        .filter(|romanized| romanized.starts_with(consonants))
+        .map(|_| shifted_kana)
+}
+
+/// Kana with its voiced or semi-voiced mark, like `か` and the dakuten giving `が`
+fn with_mark(kana: char, mark: char) -> Option<char> {
+    match (kana, mark) {
+        ('う', VOICED_MARK) => Some('ゔ'),
+        (_, VOICED_MARK) if romaji(kana)?.starts_with(['k', 's', 't', 'c', 'h', 'f']) => {
+            shifted(kana, 1, &['g', 'z', 'j', 'd', 'b'])
+        }
+        (_, SEMI_VOICED_MARK) if romaji(kana)?.starts_with(['h', 'f']) => shifted(kana, 2, &['p']),
+        _ => None,
+    }
+}
+
+/// Hiragana of a kana with the mark at the start of the bytes, and the number of bytes of the mark
+fn with_next_mark(kana: char, bytes: &[u8]) -> (char, usize) {
+    let hiragana = to_hiragana(kana);
+    first_char(bytes)
+        .and_then(|mark| with_mark(hiragana, mark).map(|marked| (marked, mark.len_utf8())))
+        .unwrap_or((hiragana, 0))
+}
+
+/// Kana at the start of the bytes, as hiragana with its mark, and the number of bytes used
+fn next_kana(bytes: &[u8]) -> Option<(char, usize)> {
+    let kana = first_char(bytes).filter(|one_char| is_kana(*one_char))?;
+    let (hiragana, mark_len) = with_next_mark(kana, &bytes[kana.len_utf8()..]);
+    Some((hiragana, kana.len_utf8() + mark_len))
+}
+
+/// Last kana of the bytes, skipping the marks, as hiragana
+fn previous_kana(bytes: &[u8]) -> Option<char> {
+    let mut before = bytes;
+    while let Some(one_char) = last_char(before) {
+        before = &before[..before.len() - one_char.len_utf8()];
+        if !matches!(one_char, LONG_VOWEL | VOICED_MARK | SEMI_VOICED_MARK) {
+            return Some(to_hiragana(one_char)).filter(|kana| is_kana(*kana));
+        }
+    }
+    None
+}
+
+/// Romanization of a kana followed by a small kana, like `きゃ` -> `kya` or `ファ` -> `fa`
+fn combine(romanized: &str, small: char) -> Option<String> {
+    let (stem, last_vowel) = romanized.split_at(romanized.len() - 1);
+    let vowel = match small {
+        'ゃ' | 'ゅ' | 'ょ' if last_vowel == "i" && !stem.is_empty() => {
+            let vowel = &romaji(small)?[1..];
+            return Some(match stem {
+                "sh" | "ch" | "j" => format!("{stem}{vowel}"),
+                _ => format!("{stem}y{vowel}"),
+            });
+        }
+        'ぁ' | 'ぃ' | 'ぅ' | 'ぇ' | 'ぉ' => romaji(small)?,
+        _ => return None,
+    };
+    match (romanized, vowel) {
+        ("fu" | "vu" | "tsu", _)
+        | ("shi" | "chi" | "ji", "e")
+        | ("te" | "de", "i")
+        | ("to" | "do", "u") => Some(format!("{stem}{vowel}")),
+        ("u", "i" | "e" | "o") => Some(format!("w{vowel}")),
+        ("i", "e") => Some(String::from("ye")),
+        _ => None,
+    }
+}
+
+/// Romanization of a kana, knowing the bytes before and after it.
+/// Returns the romanization and the number of bytes after the kana also romanized
+pub(crate) fn romanize(kana: char, before: &[u8], rest: &[u8]) -> Option<(String, usize)> {
+    let (hiragana, used) = with_next_mark(kana, rest);
+    let after = &rest[used..];
+    match hiragana {
+        SOKUON => {
+            // the consonant of the next kana, `t` before `ch`
+            let consonant = next_kana(after)
+                .and_then(|(next, _)| romaji(next))
+                .and_then(|romanized| match romanized.chars().next() {
+                    Some('c') => Some('t'),
+                    Some(first) if !"aeioun".contains(first) => Some(first),
+                    _ => None,
+                });
+            Some((consonant.map(String::from).unwrap_or_default(), used))
+        }
+        LONG_VOWEL => {
+            let vowel = previous_kana(before)
+                .and_then(romaji)
+                .and_then(|romanized| romanized.chars().last())
+                .filter(|last| "aeiou".contains(*last));
+            Some((vowel.map(String::from).unwrap_or_default(), used))
+        }
+        'ゝ' | 'ゞ' => {
+            let previous = previous_kana(before)?;
+            let repeated = if hiragana == 'ゞ' {
+                with_mark(previous, VOICED_MARK).unwrap_or(previous)
+            } else {
+                previous
+            };
+            Some((romaji(repeated)?.to_string(), used))
+        }
+        _ => {
+            let romanized = romaji(hiragana)?;
+            let combined = next_kana(after).and_then(|(small, small_len)| {
+                combine(romanized, small).map(|combined| (combined, used + small_len))
+            });
+            Some(combined.unwrap_or_else(|| (romanized.to_string(), used)))
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Language profiles: the conventional spellings of a language, used before the built-in table
+
+use core::fmt;
+
+use crate::romanization::{apply_case, to_lower};
+
+/// A language whose conventional spellings are used, like `ä` -> `ae` in German
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// German (`de`): `ä` -> `ae`, `ö` -> `oe`, `ü` -> `ue`, `ß` -> `ss`
+    German,
+    /// Danish (`da`): `æ` -> `ae`, `ø` -> `oe`, `å` -> `aa`
+    Danish,
+    /// Norwegian (`no`): `æ` -> `ae`, `ø` -> `oe`, `å` -> `aa`
+    Norwegian,
+    /// Swedish (`sv`): `å` -> `aa`, `ä` -> `ae`, `ö` -> `oe`
+    Swedish,
+    /// French (`fr`): `œ` -> `oe`, `æ` -> `ae`, `Œuvre` -> `Oeuvre`
+    French,
+    /// Spanish (`es`): the accents are removed, `ñ` -> `n`
+    Spanish,
+    /// Turkish (`tr`): the dotted `İ` and the dotless `ı` keep their case, `İzmir` -> `Izmir`,
+    /// `ılık` -> `ilik`, `ğ` -> `g`, `ş` -> `s`
+    Turkish,
+    /// Polish (`pl`): the accents are removed, `ł` -> `l`
+    Polish,
+}
+
+/// Every language, in the order of the help message
+pub const LANGUAGES: &[Language] = &[
+    Language::German,
+    Language::Danish,
+    Language::Norwegian,
+    Language::Swedish,
+    Language::French,
+    Language::Spanish,
+    Language::Turkish,
+    Language::Polish,
+];
+
+/// Mappings of German
+const GERMAN: &[(char, &str)] = &[('ß', "ss"), ('ä', "ae"), ('ö', "oe"), ('ü', "ue")];
+
+/// Mappings of Danish and Norwegian
+const DANISH: &[(char, &str)] = &[('å', "aa"), ('æ', "ae"), ('ø', "oe")];
+
+/// Mappings of Swedish
+const SWEDISH: &[(char, &str)] = &[('ä', "ae"), ('å', "aa"), ('ö', "oe")];
+
+/// Mappings of French
+const FRENCH: &[(char, &str)] = &[('æ', "ae"), ('œ', "oe")];
+
+/// Mappings of Spanish
+const SPANISH: &[(char, &str)] = &[
+    ('á', "a"),
+    ('é', "e"),
+    ('í', "i"),
+    ('ñ', "n"),
+    ('ó', "o"),
+    ('ú', "u"),
+    ('ü', "u"),
+];
+
+/// Mappings of Turkish, with `i` for the lowercase of the dotted `İ`
+const TURKISH: &[(char, &str)] = &[
+    ('i', "i"),
+    ('ç', "c"),
+    ('ö', "o"),
+    ('ü', "u"),
+    ('ğ', "g"),
+    ('ı', "i"),
+    ('ş', "s"),
+];
+
+/// Mappings of Polish
+const POLISH: &[(char, &str)] = &[
+    ('ó', "o"),
+    ('ą', "a"),
+    ('ć', "c"),
+    ('ę', "e"),
+    ('ł', "l"),
+    ('ń', "n"),
+    ('ś', "s"),
+    ('ź', "z"),
+    ('ż', "z"),
+];
+
+impl Language {
+    /// Get a language from its ISO 639-1 code, like `de`
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Language> {
+        LANGUAGES
+            .iter()
+            .find(|language| language.code().eq_ignore_ascii_case(code))
+            .copied()
+    }
+
+    /// ISO 639-1 code of the language
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::German => "de",
+            Language::Danish => "da",
+            Language::Norwegian => "no",
+            Language::Swedish => "sv",
+            Language::French => "fr",
+            Language::Spanish => "es",
+            Language::Turkish => "tr",
+            Language::Polish => "pl",
+        }
+    }
+
+    /// Mappings of the lowercase letters of the language, sorted by character
+    #[must_use]
+    pub fn mappings(&self) -> &'static [(char, &'static str)] {
+        match self {
+            Language::German => GERMAN,
+            Language::Danish | Language::Norwegian => DANISH,
+            Language::Swedish => SWEDISH,
+            Language::French => FRENCH,
+            Language::Spanish => SPANISH,
+            Language::Turkish => TURKISH,
+            Language::Polish => POLISH,
+        }
+    }
+
+    /// Lowercase of a letter in the language: the dotted `İ` is `i` in Turkish, not `i̇`
+    fn lowercase(self, one_char: char) -> char {
+        match (self, one_char) {
+            (Language::Turkish, 'İ') => 'i',
+            _ => to_lower(one_char),
+        }
+    }
+
+    /// Mapping of a character in the language, if any, knowing the characters around it
+    /// to keep the case of the word: `Ü` -> `Ue`, but `MÜLLER` -> `MUELLER`
+    #[must_use]
+    pub fn mapping(
+        &self,
+        one_char: char,
+        previous: Option<char>,
+        next: Option<char>,
+    ) -> Option<String> {
+        let mappings = self.mappings();
+        let mapped = mappings
+            .binary_search_by_key(&self.lowercase(one_char), |(mapped_char, _)| *mapped_char)
+            .ok()
+            .map(|index| mappings[index].1)?;
+        // `ß` has no common uppercase, it is written `SS` in a word in uppercase: `STRAßE`
+        let is_upper = one_char.is_uppercase()
+            || (one_char == 'ß'
+                && previous.is_some_and(char::is_uppercase)
+                && !next.is_some_and(char::is_lowercase));
+        Some(apply_case(mapped, is_upper, previous, next))
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
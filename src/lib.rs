@@ -26,7 +26,8 @@
 //!     //    json: JsonOutput::JsonDefault,
 //!     //    pretty: false,
 //!     // },
-//!     output: NotoxOutput::Quiet
+//!     output: NotoxOutput::Quiet,
+//!     ..NotoxArgs::default()
 //! };
 //! let res = Notox::new(notox_args).run(&paths);
 //! ```
@@ -46,13 +47,31 @@
 //!     //    json: JsonOutput::JsonDefault,
 //!     //    pretty: false,
 //!     // },
-//!     output: NotoxOutput::Quiet
+//!     output: NotoxOutput::Quiet,
+//!     ..NotoxArgs::default()
 //! };
 //! let notox_inst = Notox::new(notox_args);
 //! let res = notox_inst.run(&paths);
 //! notox_inst.print_output(res);
 //! ```
 //!
+//! ## Planning the renames before applying them
+//!
+//! ```rust
+//! use std::collections::HashSet;
+//! use std::path::PathBuf;
+//! use notox::{Notox, NotoxArgs};
+//!
+//! let paths: HashSet<PathBuf> = HashSet::from(["README.md".into(), "Cargo.toml".into()]);
+//! let notox_inst = Notox::new(NotoxArgs::default());
+//! let mut plan = notox_inst.plan(&paths);
+//! for one_rename in &mut plan.renames {
+//!     // choose the renames to do
+//!     one_rename.selected = one_rename.path != PathBuf::from("README.md");
+//! }
+//! let res = plan.apply();
+//! ```
+//!
 //! ## Coverage
 //!
 //! Coverage is available at [https://n4n5.dev/notox/coverage/](https://n4n5.dev/notox/coverage/)
@@ -75,11 +94,55 @@ use core::fmt;
 use std::{
     collections::HashSet,
     ffi::{OsStr, OsString},
-    path::{Path, PathBuf},
+    ops::Range,
+    path::PathBuf,
 };
 
-#[cfg(feature = "rayon")]
-use rayon::{iter::Either, prelude::*};
+#[cfg(feature = "serde")]
+use std::path::Path;
+
+mod arabic;
+mod case;
+mod cyrillic;
+mod devanagari;
+mod emoji;
+mod emoji_names;
+mod error;
+mod extension;
+mod fallback;
+mod greek;
+mod hangul;
+mod hanzi;
+mod hebrew;
+#[cfg(feature = "serde")]
+mod journal;
+mod kana;
+mod language;
+mod lint;
+#[cfg(feature = "pinyin")]
+mod pinyin;
+mod plan;
+mod romanization;
+mod symbol_names;
+mod table;
+mod words;
+
+pub use arabic::ArabicScheme;
+pub use case::{CaseMode, CASE_MODES};
+pub use cyrillic::CyrillicScheme;
+pub use error::{IoError, NotoxError};
+pub use extension::{ExtensionOptions, EXTENSION_SYNONYMS, MULTI_PART_EXTENSIONS};
+pub use fallback::{needs_fallback, FallbackName};
+pub use hanzi::PinyinSeparator;
+pub use language::{Language, LANGUAGES};
+pub use lint::{lint_name, LintCategory, LintFinding, LintReport};
+pub use plan::{PlannedRename, RenamePlan};
+pub use romanization::{Romanization, ROMANIZATION_NAMES};
+pub use table::{
+    ascii_equivalent, builtin_mapping, TransliterationTable, BIDI_CONTROLS, BUILTIN_TABLE,
+    COMBINING_MARKS, DROPPED_LATIN_LETTERS, SPACE_SEPARATORS,
+};
+pub use words::{SymbolWords, SYMBOL_WORDS};
 
 /// Type of JSON output
 #[cfg(feature = "serde")]
@@ -119,6 +182,70 @@ impl NotoxOutput {
     }
 }
 
+/// What to do when the cleaned name is already used by another file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConflictStrategy {
+    /// do not rename the file and report the conflict
+    #[default]
+    Skip,
+
+    /// add a numeric suffix before the extension (`a_b-1.txt`)
+    Suffix,
+
+    /// stop renaming anything once a conflict is found
+    Abort,
+}
+
+impl ConflictStrategy {
+    /// Parse a strategy from its name
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<ConflictStrategy> {
+        match name {
+            "skip" => Some(ConflictStrategy::Skip),
+            "suffix" => Some(ConflictStrategy::Suffix),
+            "abort" => Some(ConflictStrategy::Abort),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ConflictStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConflictStrategy::Skip => write!(f, "skip"),
+            ConflictStrategy::Suffix => write!(f, "suffix"),
+            ConflictStrategy::Abort => write!(f, "abort"),
+        }
+    }
+}
+
+/// What the program does
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum NotoxMode {
+    /// clean the names of the paths
+    #[default]
+    Clean,
+
+    /// only report the toxic names, see [`Notox::lint`]
+    Check,
+
+    /// explain how a name is cleaned, see [`trace_name`]
+    Explain(String),
+
+    /// reverse the renames written in a journal file
+    #[cfg(feature = "serde")]
+    Undo(PathBuf),
+
+    /// do the renames of a JSON output, possibly edited by hand
+    #[cfg(feature = "serde")]
+    Apply {
+        /// the JSON file to apply
+        json_path: PathBuf,
+        /// only retry the renames that failed
+        failed_only: bool,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq)]
 /// Options for the program
 pub struct NotoxArgs {
@@ -127,6 +254,43 @@ pub struct NotoxArgs {
 
     /// which kind of json output to use
     pub output: NotoxOutput,
+
+    /// what to do when the cleaned name is already taken
+    pub conflict: ConflictStrategy,
+
+    /// how to name the paths with nothing left once cleaned
+    pub fallback: FallbackName,
+
+    /// how the extensions are cleaned
+    pub extension: ExtensionOptions,
+
+    /// mappings used before the built-in table, see [`BUILTIN_TABLE`]
+    pub table: TransliterationTable,
+
+    /// conventional spellings used before the built-in table, after the table
+    pub language: Option<Language>,
+
+    /// scripts romanized before the built-in table, after the language
+    pub romanization: Romanization,
+
+    /// replace the symbols without mapping by their Unicode name, like `☃` -> `snowman`
+    pub unicode_names: bool,
+
+    /// replace the emoji by their short name, like `🎉` -> `party_popper`
+    pub emoji: bool,
+
+    /// spell out the symbols, like `&` -> `and`, `None` to clean them as the other characters
+    pub words: Option<SymbolWords>,
+
+    /// case of the stem once cleaned, like `snake_case`, `None` to keep the case of the name
+    pub case: Option<CaseMode>,
+
+    /// what the program does
+    pub mode: NotoxMode,
+
+    /// file where the done renames are written, to undo them later
+    #[cfg(feature = "serde")]
+    pub journal: Option<PathBuf>,
 }
 
 impl NotoxArgs {
@@ -137,18 +301,73 @@ impl NotoxArgs {
     }
 }
 
+impl Default for NotoxArgs {
+    fn default() -> Self {
+        NotoxArgs {
+            dry_run: true,
+            output: NotoxOutput::Default,
+            conflict: ConflictStrategy::default(),
+            fallback: FallbackName::default(),
+            extension: ExtensionOptions::default(),
+            table: TransliterationTable::new(),
+            language: None,
+            romanization: Romanization::default(),
+            unicode_names: false,
+            emoji: false,
+            words: None,
+            case: None,
+            mode: NotoxMode::default(),
+            #[cfg(feature = "serde")]
+            journal: None,
+        }
+    }
+}
+
 impl fmt::Display for NotoxArgs {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // We don't need to print output when
         // - it's Output::Quiet because it's quiet
         // - it's Output::JsonOutput because it's json
         // so, only one case is default
-        write!(f, "NotoxArgs {{ dry_run: {} }}", self.dry_run)
+        let or_none = |option: Option<String>| option.unwrap_or_else(|| "none".to_string());
+        write!(
+            f,
+            "NotoxArgs {{ dry_run: {}, conflict: {}, fallback: {}, lower_ext: {}, ext_synonyms: {}, \
+             table: {} mappings, lang: {}, romanize: {}, unicode_names: {}, emoji: {}, words: {}, \
+             case: {}, mode: {:?}",
+            self.dry_run,
+            self.conflict,
+            self.fallback,
+            self.extension.lowercase,
+            self.extension.synonyms,
+            self.table.iter().count(),
+            or_none(self.language.map(|language| language.to_string())),
+            self.romanization,
+            self.unicode_names,
+            self.emoji,
+            or_none(self.words.as_ref().map(|words| {
+                words
+                    .iter()
+                    .map(|(symbol, word)| format!("{symbol}={word}"))
+                    .collect::<Vec<String>>()
+                    .join(",")
+            })),
+            or_none(self.case.map(|case| case.to_string())),
+            self.mode,
+        )?;
+        #[cfg(feature = "serde")]
+        write!(
+            f,
+            ", journal: {}",
+            or_none(self.journal.as_ref().map(|path| path.display().to_string()))
+        )?;
+        write!(f, " }}")
     }
 }
 
 /// Contains information about a result of a single file
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum PathChange {
     /// The path has not been changed
     Unchanged {
@@ -161,6 +380,26 @@ pub enum PathChange {
         path: PathBuf,
         /// The modified path
         modified: PathBuf,
+        /// Whether the modified name is a fallback name, see [`FallbackName`]
+        fallback: bool,
+    },
+    /// The path would be changed, without the dry-run
+    WouldRename {
+        /// The original path
+        path: PathBuf,
+        /// The modified path
+        modified: PathBuf,
+        /// Whether the modified name is a fallback name, see [`FallbackName`]
+        fallback: bool,
+    },
+    /// The cleaned path is already used by another file
+    Conflict {
+        /// The original path
+        path: PathBuf,
+        /// The modified path, already taken
+        modified: PathBuf,
+        /// Whether the modified name is a fallback name, see [`FallbackName`]
+        fallback: bool,
     },
     /// The path could not be changed
     ErrorRename {
@@ -168,15 +407,17 @@ pub enum PathChange {
         path: PathBuf,
         /// The modified path
         modified: PathBuf,
-        /// The error message
-        error: String,
+        /// The error
+        error: NotoxError,
+        /// Whether the modified name is a fallback name, see [`FallbackName`]
+        fallback: bool,
     },
     /// There was an error while processing the path
     Error {
         /// The original path
         path: PathBuf,
-        /// The error message
-        error: String,
+        /// The error
+        error: NotoxError,
     },
 }
 
@@ -192,23 +433,41 @@ impl<'de> serde::Deserialize<'de> for PathChange {
             path: String,
             /// Modified string
             modified: Option<String>,
-            /// Error string
-            error: Option<String>,
+            /// Error
+            error: Option<NotoxError>,
+            /// Dry-run flag
+            #[serde(default)]
+            dry_run: bool,
+            /// Warning, only reported for the fallback names
+            warning: Option<String>,
         }
 
         let helper = Helper::deserialize(deserializer)?;
 
         let path = PathBuf::from(helper.path);
+        let fallback = helper.warning.is_some();
         match (helper.modified, helper.error) {
+            (Some(modified), Some(NotoxError::TargetExists)) => Ok(PathChange::Conflict {
+                path,
+                modified: PathBuf::from(modified),
+                fallback,
+            }),
+            (Some(modified), _) if helper.dry_run => Ok(PathChange::WouldRename {
+                path,
+                modified: PathBuf::from(modified),
+                fallback,
+            }),
             (None, None) => Ok(PathChange::Unchanged { path }),
             (Some(modified), None) => Ok(PathChange::Changed {
                 path,
                 modified: PathBuf::from(modified),
+                fallback,
             }),
             (Some(modified), Some(error)) => Ok(PathChange::ErrorRename {
                 path,
                 modified: PathBuf::from(modified),
                 error,
+                fallback,
             }),
             (None, Some(error)) => Ok(PathChange::Error { path, error }),
         }
@@ -223,22 +482,39 @@ impl serde::Serialize for PathChange {
     {
         use serde::ser::SerializeStruct;
 
-        let mut state = serializer.serialize_struct("PathChange", 3)?;
+        let warning = self.warning();
+        let len = match self {
+            PathChange::WouldRename { .. } => 4,
+            _ => 3,
+        } + usize::from(warning.is_some());
+        let mut state = serializer.serialize_struct("PathChange", len)?;
         match self {
             PathChange::Unchanged { path } => {
                 state.serialize_field("path", path)?;
                 state.serialize_field("modified", &Option::<PathBuf>::None)?;
-                state.serialize_field("error", &Option::<String>::None)?;
+                state.serialize_field("error", &Option::<NotoxError>::None)?;
             }
-            PathChange::Changed { path, modified } => {
+            PathChange::Changed { path, modified, .. } => {
                 state.serialize_field("path", path)?;
                 state.serialize_field("modified", &Some(modified))?;
-                state.serialize_field("error", &Option::<String>::None)?;
+                state.serialize_field("error", &Option::<NotoxError>::None)?;
+            }
+            PathChange::WouldRename { path, modified, .. } => {
+                state.serialize_field("path", path)?;
+                state.serialize_field("modified", &Some(modified))?;
+                state.serialize_field("error", &Option::<NotoxError>::None)?;
+                state.serialize_field("dry_run", &true)?;
+            }
+            PathChange::Conflict { path, modified, .. } => {
+                state.serialize_field("path", path)?;
+                state.serialize_field("modified", &Some(modified))?;
+                state.serialize_field("error", &Some(NotoxError::TargetExists))?;
             }
             PathChange::ErrorRename {
                 path,
                 modified,
                 error,
+                ..
             } => {
                 state.serialize_field("path", path)?;
                 state.serialize_field("modified", &Some(modified))?;
@@ -250,188 +526,45 @@ impl serde::Serialize for PathChange {
                 state.serialize_field("error", &Some(error))?;
             }
         }
+        if let Some(warning) = warning {
+            state.serialize_field("warning", warning)?;
+        }
         state.end()
     }
 }
 
-/// Check if a vector of bytes is similar to a char
+impl PathChange {
+    /// Warning about the new name, when a fallback name is used
+    #[must_use]
+    pub fn warning(&self) -> Option<&'static str> {
+        match self {
+            PathChange::Changed { fallback, .. }
+            | PathChange::WouldRename { fallback, .. }
+            | PathChange::Conflict { fallback, .. }
+            | PathChange::ErrorRename { fallback, .. } => {
+                fallback.then_some(fallback::FALLBACK_WARNING)
+            }
+            PathChange::Unchanged { .. } | PathChange::Error { .. } => None,
+        }
+    }
+}
+
+/// Check if a vector of bytes is similar to a char, see [`BUILTIN_TABLE`]
 #[inline]
-#[allow(clippy::too_many_lines)]
 pub fn check_similar(curr_char: Option<char>, name_acc: &mut String, last_was_ascii: bool) -> bool {
     if let Some(one_char) = curr_char {
-        match one_char {
-            'A' | 'Ⓐ' | 'Ａ' | 'À' | 'Á' | 'Â' | 'Ầ' | 'Ấ' | 'Ẫ' | 'Ẩ' | 'Ã' | 'Ā' | 'Ă' | 'Ằ'
-            | 'Ắ' | 'Ẵ' | 'Ẳ' | 'Ȧ' | 'Ǡ' | 'Ä' | 'Ǟ' | 'Ả' | 'Å' | 'Ǻ' | 'Ǎ' | 'Ȁ' | 'Ȃ' | 'Ạ'
-            | 'Ậ' | 'Ặ' | 'Ḁ' | 'Ą' | 'Ⱥ' | 'Ɐ' => name_acc.push('A'),
-            'Ꜳ' => name_acc.push_str("AA"),
-            'Æ' | 'Ǽ' | 'Ǣ' => {
-                name_acc.push_str("AE");
-            }
-            'Ꜵ' => name_acc.push_str("AO"),
-            'Ꜷ' => name_acc.push_str("AU"),
-            'Ꜹ' | 'Ꜻ' => name_acc.push_str("AV"),
-            'Ꜽ' => name_acc.push_str("AY"),
-            'B' | 'Ⓑ' | 'Ｂ' | 'Ḃ' | 'Ḅ' | 'Ḇ' | 'Ƀ' | 'Ƃ' | 'Ɓ' => name_acc.push('B'),
-            'C' | 'Ⓒ' | 'Ｃ' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' | 'Ç' | 'Ḉ' | 'Ƈ' | 'Ȼ' | 'Ꜿ' => {
-                name_acc.push('C');
-            }
-            'D' | 'Ⓓ' | 'Ｄ' | 'Ḋ' | 'Ď' | 'Ḍ' | 'Ḑ' | 'Ḓ' | 'Ḏ' | 'Đ' | 'Ƌ' | 'Ɗ' | 'Ɖ' | 'Ꝺ' =>
-            {
-                name_acc.push('D');
-            }
-            'Ǳ' | 'Ǆ' => name_acc.push_str("DZ"),
-            'ǲ' | 'ǅ' => name_acc.push_str("Dz"),
-            'E' | 'Ⓔ' | 'Ｅ' | 'È' | 'É' | 'Ê' | 'Ề' | 'Ế' | 'Ễ' | 'Ể' | 'Ẽ' | 'Ē' | 'Ḕ' | 'Ḗ'
-            | 'Ĕ' | 'Ė' | 'Ë' | 'Ẻ' | 'Ě' | 'Ȅ' | 'Ȇ' | 'Ẹ' | 'Ệ' | 'Ȩ' | 'Ḝ' | 'Ę' | 'Ḙ' | 'Ḛ'
-            | 'Ɛ' | 'Ǝ' => name_acc.push('E'),
-            'F' | 'Ⓕ' | 'Ｆ' | 'Ḟ' | 'Ƒ' | 'Ꝼ' => name_acc.push('F'),
-            'G' | 'Ⓖ' | 'Ｇ' | 'Ǵ' | 'Ĝ' | 'Ḡ' | 'Ğ' | 'Ġ' | 'Ǧ' | 'Ģ' | 'Ǥ' | 'Ɠ' | 'Ꞡ' | 'Ᵹ'
-            | 'Ꝿ' => name_acc.push('G'),
-            'H' | 'Ⓗ' | 'Ｈ' | 'Ĥ' | 'Ḣ' | 'Ḧ' | 'Ȟ' | 'Ḥ' | 'Ḩ' | 'Ḫ' | 'Ħ' | 'Ⱨ' | 'Ⱶ' | 'Ɥ' =>
-            {
-                name_acc.push('H');
-            }
-            'I' | 'Ⓘ' | 'Ｉ' | 'Ì' | 'Í' | 'Î' | 'Ĩ' | 'Ī' | 'Ĭ' | 'İ' | 'Ï' | 'Ḯ' | 'Ỉ' | 'Ǐ'
-            | 'Ȉ' | 'Ȋ' | 'Ị' | 'Į' | 'Ḭ' | 'Ɨ' => name_acc.push('I'),
-            'J' | 'Ⓙ' | 'Ｊ' | 'Ĵ' | 'Ɉ' => name_acc.push('J'),
-            'K' | 'Ⓚ' | 'Ｋ' | 'Ḱ' | 'Ǩ' | 'Ḳ' | 'Ķ' | 'Ḵ' | 'Ƙ' | 'Ⱪ' | 'Ꝁ' | 'Ꝃ' | 'Ꝅ' | 'Ꞣ' =>
-            {
-                name_acc.push('K');
-            }
-            'L' | 'Ⓛ' | 'Ｌ' | 'Ŀ' | 'Ĺ' | 'Ľ' | 'Ḷ' | 'Ḹ' | 'Ļ' | 'Ḽ' | 'Ḻ' | 'Ł' | 'Ƚ' | 'Ɫ'
-            | 'Ⱡ' | 'Ꝉ' | 'Ꝇ' | 'Ꞁ' => name_acc.push('L'),
-            'Ǉ' => name_acc.push_str("LJ"),
-            'ǈ' => name_acc.push_str("Lj"),
-            'M' | 'Ⓜ' | 'Ｍ' | 'Ḿ' | 'Ṁ' | 'Ṃ' | 'Ɱ' | 'Ɯ' => name_acc.push('M'),
-            'N' | 'Ⓝ' | 'Ｎ' | 'Ǹ' | 'Ń' | 'Ñ' | 'Ṅ' | 'Ň' | 'Ṇ' | 'Ņ' | 'Ṋ' | 'Ṉ' | 'Ƞ' | 'Ɲ'
-            | 'Ꞑ' | 'Ꞥ' => name_acc.push('N'),
-            'Ǌ' => name_acc.push_str("NJ"),
-            'ǋ' => name_acc.push_str("Nj"),
-            'O' | 'Ⓞ' | 'Ｏ' | 'Ò' | 'Ó' | 'Ô' | 'Ồ' | 'Ố' | 'Ỗ' | 'Ổ' | 'Õ' | 'Ṍ' | 'Ȭ' | 'Ṏ'
-            | 'Ō' | 'Ṑ' | 'Ṓ' | 'Ŏ' | 'Ȯ' | 'Ȱ' | 'Ö' | 'Ȫ' | 'Ỏ' | 'Ő' | 'Ǒ' | 'Ȍ' | 'Ȏ' | 'Ơ'
-            | 'Ờ' | 'Ớ' | 'Ỡ' | 'Ở' | 'Ợ' | 'Ọ' | 'Ộ' | 'Ǫ' | 'Ǭ' | 'Ø' | 'Ǿ' | 'Ɔ' | 'Ɵ' | 'Ꝋ'
-            | 'Ꝍ' => name_acc.push('O'),
-            'Ƣ' => name_acc.push_str("OI"),
-            'Ꝏ' => name_acc.push_str("OO"),
-            'Ȣ' => name_acc.push_str("OU"),
-            '\u{008C}' | 'Œ' => name_acc.push_str("OE"),
-            '\u{009C}' | 'œ' => name_acc.push_str("oe"),
-            'P' | 'Ⓟ' | 'Ｐ' | 'Ṕ' | 'Ṗ' | 'Ƥ' | 'Ᵽ' | 'Ꝑ' | 'Ꝓ' | 'Ꝕ' => {
-                name_acc.push('P');
-            }
-            'Q' | 'Ⓠ' | 'Ｑ' | 'Ꝗ' | 'Ꝙ' | 'Ɋ' => name_acc.push('Q'),
-            'R' | 'Ⓡ' | 'Ｒ' | 'Ŕ' | 'Ṙ' | 'Ř' | 'Ȑ' | 'Ȓ' | 'Ṛ' | 'Ṝ' | 'Ŗ' | 'Ṟ' | 'Ɍ' | 'Ɽ'
-            | 'Ꝛ' | 'Ꞧ' | 'Ꞃ' => name_acc.push('R'),
-            'S' | 'Ⓢ' | 'Ｓ' | 'ẞ' | 'Ś' | 'Ṥ' | 'Ŝ' | 'Ṡ' | 'Š' | 'Ṧ' | 'Ṣ' | 'Ṩ' | 'Ș' | 'Ş'
-            | 'Ȿ' | 'Ꞩ' | 'Ꞅ' => name_acc.push('S'),
-            'T' | 'Ⓣ' | 'Ｔ' | 'Ṫ' | 'Ť' | 'Ṭ' | 'Ț' | 'Ţ' | 'Ṱ' | 'Ṯ' | 'Ŧ' | 'Ƭ' | 'Ʈ' | 'Ⱦ'
-            | 'Ꞇ' => name_acc.push('T'),
-            'Ꜩ' => name_acc.push_str("TZ"),
-            'U' | 'Ⓤ' | 'Ｕ' | 'Ù' | 'Ú' | 'Û' | 'Ũ' | 'Ṹ' | 'Ū' | 'Ṻ' | 'Ŭ' | 'Ü' | 'Ǜ' | 'Ǘ'
-            | 'Ǖ' | 'Ǚ' | 'Ủ' | 'Ů' | 'Ű' | 'Ǔ' | 'Ȕ' | 'Ȗ' | 'Ư' | 'Ừ' | 'Ứ' | 'Ữ' | 'Ử' | 'Ự'
-            | 'Ụ' | 'Ṳ' | 'Ų' | 'Ṷ' | 'Ṵ' | 'Ʉ' => name_acc.push('U'),
-            'V' | 'Ⓥ' | 'Ｖ' | 'Ṽ' | 'Ṿ' | 'Ʋ' | 'Ꝟ' | 'Ʌ' => name_acc.push('V'),
-            'Ꝡ' => name_acc.push_str("VY"),
-            'W' | 'Ⓦ' | 'Ｗ' | 'Ẁ' | 'Ẃ' | 'Ŵ' | 'Ẇ' | 'Ẅ' | 'Ẉ' | 'Ⱳ' => {
-                name_acc.push('W');
-            }
-            'X' | 'Ⓧ' | 'Ｘ' | 'Ẋ' | 'Ẍ' => name_acc.push('X'),
-            'Y' | 'Ⓨ' | 'Ｙ' | 'Ỳ' | 'Ý' | 'Ŷ' | 'Ỹ' | 'Ȳ' | 'Ẏ' | 'Ÿ' | 'Ỷ' | 'Ỵ' | 'Ƴ' | 'Ɏ'
-            | 'Ỿ' => name_acc.push('Y'),
-            'Z' | 'Ⓩ' | 'Ｚ' | 'Ź' | 'Ẑ' | 'Ż' | 'Ž' | 'Ẓ' | 'Ẕ' | 'Ƶ' | 'Ȥ' | 'Ɀ' | 'Ⱬ' | 'Ꝣ' =>
-            {
-                name_acc.push('Z');
-            }
-            'a' | 'ⓐ' | 'ａ' | 'ẚ' | 'à' | 'á' | 'â' | 'ầ' | 'ấ' | 'ẫ' | 'ẩ' | 'ã' | 'ā' | 'ă'
-            | 'ằ' | 'ắ' | 'ẵ' | 'ẳ' | 'ȧ' | 'ǡ' | 'ä' | 'ǟ' | 'ả' | 'å' | 'ǻ' | 'ǎ' | 'ȁ' | 'ȃ'
-            | 'ạ' | 'ậ' | 'ặ' | 'ḁ' | 'ą' | 'ⱥ' | 'ɐ' => name_acc.push('a'),
-            'ꜳ' => name_acc.push_str("aa"),
-            'æ' | 'ǽ' | 'ǣ' => name_acc.push_str("ae"),
-            'ꜵ' => name_acc.push_str("ao"),
-            'ꜷ' => name_acc.push_str("au"),
-            'ꜹ' | 'ꜻ' => name_acc.push_str("av"),
-            'ꜽ' => name_acc.push_str("ay"),
-            'b' | 'ⓑ' | 'ｂ' | 'ḃ' | 'ḅ' | 'ḇ' | 'ƀ' | 'ƃ' | 'ɓ' | 'þ' => {
-                name_acc.push('b');
-            }
-            'c' | 'ⓒ' | 'ｃ' | 'ć' | 'ĉ' | 'ċ' | 'č' | 'ç' | 'ḉ' | 'ƈ' | 'ȼ' | 'ꜿ' | 'ↄ' =>
-            {
-                name_acc.push('c');
-            }
-            'd' | 'ⓓ' | 'ｄ' | 'ḋ' | 'ď' | 'ḍ' | 'ḑ' | 'ḓ' | 'ḏ' | 'đ' | 'ƌ' | 'ɖ' | 'ɗ' | 'ꝺ' =>
-            {
-                name_acc.push('d');
-            }
-            'ǳ' | 'ǆ' => name_acc.push_str("dz"),
-            'e' | 'ⓔ' | 'ｅ' | 'è' | 'é' | 'ê' | 'ề' | 'ế' | 'ễ' | 'ể' | 'ẽ' | 'ē' | 'ḕ' | 'ḗ'
-            | 'ĕ' | 'ė' | 'ë' | 'ẻ' | 'ě' | 'ȅ' | 'ȇ' | 'ẹ' | 'ệ' | 'ȩ' | 'ḝ' | 'ę' | 'ḙ' | 'ḛ'
-            | 'ɇ' | 'ɛ' | 'ǝ' => name_acc.push('e'),
-            'f' | 'ⓕ' | 'ｆ' | 'ḟ' | 'ƒ' | 'ꝼ' => name_acc.push('f'),
-            'g' | 'ⓖ' | 'ｇ' | 'ǵ' | 'ĝ' | 'ḡ' | 'ğ' | 'ġ' | 'ǧ' | 'ģ' | 'ǥ' | 'ɠ' | 'ꞡ' | 'ᵹ'
-            | 'ꝿ' => name_acc.push('g'),
-            'h' | 'ⓗ' | 'ｈ' | 'ĥ' | 'ḣ' | 'ḧ' | 'ȟ' | 'ḥ' | 'ḩ' | 'ḫ' | 'ẖ' | 'ħ' | 'ⱨ' | 'ⱶ'
-            | 'ɥ' => name_acc.push('h'),
-            'ƕ' => name_acc.push_str("hv"),
-            'i' | 'ⓘ' | 'ｉ' | 'ì' | 'í' | 'î' | 'ĩ' | 'ī' | 'ĭ' | 'ï' | 'ḯ' | 'ỉ' | 'ǐ' | 'ȉ'
-            | 'ȋ' | 'ị' | 'į' | 'ḭ' | 'ɨ' | 'ı' => name_acc.push('i'),
-            'j' | 'ⓙ' | 'ｊ' | 'ĵ' | 'ǰ' | 'ɉ' => name_acc.push('j'),
-            'k' | 'ⓚ' | 'ｋ' | 'ḱ' | 'ǩ' | 'ḳ' | 'ķ' | 'ḵ' | 'ƙ' | 'ⱪ' | 'ꝁ' | 'ꝃ' | 'ꝅ' | 'ꞣ' =>
-            {
-                name_acc.push('k');
-            }
-            'l' | 'ⓛ' | 'ｌ' | 'ŀ' | 'ĺ' | 'ľ' | 'ḷ' | 'ḹ' | 'ļ' | 'ḽ' | 'ḻ' | 'ſ' | 'ł' | 'ƚ'
-            | 'ɫ' | 'ⱡ' | 'ꝉ' | 'ꞁ' | 'ꝇ' => name_acc.push('l'),
-            'ǉ' => name_acc.push_str("lj"),
-            'm' | 'ⓜ' | 'ｍ' | 'ḿ' | 'ṁ' | 'ṃ' | 'ɱ' | 'ɯ' => name_acc.push('m'),
-            'n' | 'ⓝ' | 'ｎ' | 'ǹ' | 'ń' | 'ñ' | 'ṅ' | 'ň' | 'ṇ' | 'ņ' | 'ṋ' | 'ṉ' | 'ƞ' | 'ɲ'
-            | 'ŉ' | 'ꞑ' | 'ꞥ' => name_acc.push('n'),
-            'ǌ' => name_acc.push_str("nj"),
-            'o' | 'ⓞ' | 'ｏ' | 'ò' | 'ó' | 'ô' | 'ồ' | 'ố' | 'ỗ' | 'ổ' | 'õ' | 'ṍ' | 'ȭ' | 'ṏ'
-            | 'ō' | 'ṑ' | 'ṓ' | 'ŏ' | 'ȯ' | 'ȱ' | 'ö' | 'ȫ' | 'ỏ' | 'ő' | 'ǒ' | 'ȍ' | 'ȏ' | 'ơ'
-            | 'ờ' | 'ớ' | 'ỡ' | 'ở' | 'ợ' | 'ọ' | 'ộ' | 'ǫ' | 'ǭ' | 'ø' | 'ǿ' | 'ɔ' | 'ꝋ' | 'ꝍ'
-            | 'ɵ' => name_acc.push('o'),
-            'ƣ' => name_acc.push_str("oi"),
-            'ȣ' => name_acc.push_str("ou"),
-            'ꝏ' => name_acc.push_str("oo"),
-            'p' | 'ⓟ' | 'ｐ' | 'ṕ' | 'ṗ' | 'ƥ' | 'ᵽ' | 'ꝑ' | 'ꝓ' | 'ꝕ' => {
-                name_acc.push('p');
-            }
-            'q' | 'ⓠ' | 'ｑ' | 'ɋ' | 'ꝗ' | 'ꝙ' => name_acc.push('q'),
-            'r' | 'ⓡ' | 'ｒ' | 'ŕ' | 'ṙ' | 'ř' | 'ȑ' | 'ȓ' | 'ṛ' | 'ṝ' | 'ŗ' | 'ṟ' | 'ɍ' | 'ɽ'
-            | 'ꝛ' | 'ꞧ' | 'ꞃ' => name_acc.push('r'),
-            's' | 'ⓢ' | 'ｓ' | 'ß' | 'ś' | 'ṥ' | 'ŝ' | 'ṡ' | 'š' | 'ṧ' | 'ṣ' | 'ṩ' | 'ș' | 'ş'
-            | 'ȿ' | 'ꞩ' | 'ꞅ' | 'ẛ' => name_acc.push('s'),
-            't' | 'ⓣ' | 'ｔ' | 'ṫ' | 'ẗ' | 'ť' | 'ṭ' | 'ț' | 'ţ' | 'ṱ' | 'ṯ' | 'ŧ' | 'ƭ' | 'ʈ'
-            | 'ⱦ' | 'ꞇ' => name_acc.push('t'),
-            'ꜩ' => name_acc.push_str("tz"),
-            'u' | 'ⓤ' | 'ｕ' | 'ù' | 'ú' | 'û' | 'ũ' | 'ṹ' | 'ū' | 'ṻ' | 'ŭ' | 'ü' | 'ǜ' | 'ǘ'
-            | 'ǖ' | 'ǚ' | 'ủ' | 'ů' | 'ű' | 'ǔ' | 'ȕ' | 'ȗ' | 'ư' | 'ừ' | 'ứ' | 'ữ' | 'ử' | 'ự'
-            | 'ụ' | 'ṳ' | 'ų' | 'ṷ' | 'ṵ' | 'ʉ' => name_acc.push('u'),
-            'v' | 'ⓥ' | 'ｖ' | 'ṽ' | 'ṿ' | 'ʋ' | 'ꝟ' | 'ʌ' => name_acc.push('v'),
-            'ꝡ' => name_acc.push_str("vy"),
-            'w' | 'ⓦ' | 'ｗ' | 'ẁ' | 'ẃ' | 'ŵ' | 'ẇ' | 'ẅ' | 'ẘ' | 'ẉ' | 'ⱳ' => {
-                name_acc.push('w');
-            }
-            'x' | 'ⓧ' | 'ｘ' | 'ẋ' | 'ẍ' => name_acc.push('x'),
-            'y' | 'ⓨ' | 'ｙ' | 'ỳ' | 'ý' | 'ŷ' | 'ỹ' | 'ȳ' | 'ẏ' | 'ÿ' | 'ỷ' | 'ẙ' | 'ỵ' | 'ƴ'
-            | 'ɏ' | 'ỿ' => name_acc.push('y'),
-            'z' | 'ⓩ' | 'ｚ' | 'ź' | 'ẑ' | 'ż' | 'ž' | 'ẓ' | 'ẕ' | 'ƶ' | 'ȥ' | 'ɀ' | 'ⱬ' | 'ꝣ' =>
-            {
-                name_acc.push('z');
-            }
-            '–' => {
-                name_acc.push('-');
-                return false;
-            }
-            '\u{0300}'..='\u{036F}' | '\u{1AB0}'..='\u{1AFF}' | '\u{1DC0}'..='\u{1DFF}' => {}
-            _ => {
-                if last_was_ascii {
-                    name_acc.push('_');
-                }
-                return false;
+        if let Some(mapped) = builtin_mapping(one_char) {
+            if mapped.is_empty() {
+                // an invisible character does not end a word
+                return last_was_ascii;
             }
+            name_acc.push_str(mapped);
+            return true;
         }
-        return true;
+        if last_was_ascii {
+            name_acc.push('_');
+        }
+        return false;
     }
     last_was_ascii
 }
@@ -467,214 +600,406 @@ pub fn convert_two_to_u32(first_byte: u8, second_byte: u8) -> u32 {
     ((u32::from(first_byte) & 0b0001_1111) << 6) | (u32::from(second_byte) & 0b0011_1111)
 }
 
-/// Clean a name
+/// What happened to a character of a name, see [`trace_name`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum TraceAction {
+    /// the character is kept as is
+    Kept,
+    /// the character is replaced by an ASCII text
+    Mapped(String),
+    /// the character has no mapping and is removed
+    Dropped,
+    /// the character is replaced by the separator `_`
+    Separator,
+    /// the character is merged into the previous separator, or removed at the start
+    Collapsed,
+    /// the character (`-` or `.`) replaces the previous separator
+    SeparatorRule,
+    /// the last separator of the stem is removed, the dot of the extension replaces it
+    TrimmedBeforeExtension,
+    /// the last dot of the name is removed, nothing is left after it: `x.日本` -> `x`
+    TrimmedDot,
+    /// the stem is cased, see [`CaseMode`]
+    Cased(String),
+    /// the extensions are lowercased or replaced by their usual spelling, see [`ExtensionOptions`]
+    ExtensionCleaned(String),
+    /// nothing is left of the stem, the fallback name is used, see [`FallbackName`]
+    Fallback(String),
+}
+
+impl fmt::Display for TraceAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceAction::Kept => write!(f, "kept"),
+            TraceAction::Mapped(mapped) => write!(f, "mapped to {mapped:?}"),
+            TraceAction::Dropped => write!(f, "dropped, no mapping"),
+            TraceAction::Separator => write!(f, "replaced by the separator '_'"),
+            TraceAction::Collapsed => write!(f, "collapsed into the previous separator"),
+            TraceAction::SeparatorRule => write!(f, "replaces the previous separator"),
+            TraceAction::TrimmedBeforeExtension => {
+                write!(f, "last separator removed before the extension")
+            }
+            TraceAction::TrimmedDot => write!(f, "last dot removed, nothing left after it"),
+            TraceAction::Cased(cased) => write!(f, "cased to {cased:?}"),
+            TraceAction::ExtensionCleaned(cleaned) => {
+                write!(f, "extensions cleaned to {cleaned:?}")
+            }
+            TraceAction::Fallback(fallback) => {
+                write!(f, "nothing left, fallback name {fallback:?} used")
+            }
+        }
+    }
+}
+
+/// A character of a name and what it became, see [`trace_name`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TraceStep {
+    /// Byte range of the character in the name, or of the stem, the extensions or the whole name
+    pub range: Range<usize>,
+
+    /// The character, `None` if the bytes are not valid UTF-8 or if the step is about
+    /// the stem, the extensions or the whole name
+    pub original: Option<char>,
+
+    /// What the character became
+    pub action: TraceAction,
+}
+
+/// The cleaned name with the options, and what happened to each character of the name,
+/// then to its stem, its extensions and the whole name
+#[must_use]
+pub fn trace_name(name: &OsStr, options: &NotoxArgs) -> (OsString, Vec<TraceStep>) {
+    let mut steps = Vec::new();
+    let (cleaned, _) = clean_name_steps(name, options, Some(&mut steps));
+    (cleaned, steps)
+}
+
+/// Clean a name with the options, as notox renames it, see [`trace_name`] for the details
+#[must_use]
+pub fn clean_name_with(name: &str, options: &NotoxArgs) -> String {
+    clean_name(OsStr::new(name), options)
+        .0
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Clean a name, and tell if a fallback name is used
 #[inline]
-fn clean_name(path: &OsStr, _options: &NotoxArgs) -> OsString {
+fn clean_name(path: &OsStr, options: &NotoxArgs) -> (OsString, bool) {
+    clean_name_steps(path, options, None)
+}
+
+/// Clean a name, recording the steps, and tell if a fallback name is used
+fn clean_name_steps(
+    path: &OsStr,
+    options: &NotoxArgs,
+    mut trace: Option<&mut Vec<TraceStep>>,
+) -> (OsString, bool) {
+    let bytes = path.as_encoded_bytes();
+    if bytes.iter().all(|byte| *byte == b'.') {
+        // `.`, `..` and the other names made only of dots have no stem to clean or to replace
+        return (path.to_os_string(), false);
+    }
+    let cleaned = OsString::from(extension::clean_with_extension(
+        bytes,
+        options,
+        trace.as_deref_mut(),
+    ));
+    if fallback::needs_fallback(path, &cleaned, options) {
+        let fallback_name = fallback::fallback_name(path, options);
+        push_step(
+            &mut trace,
+            0..bytes.len(),
+            None,
+            TraceAction::Fallback(fallback_name.to_string_lossy().into_owned()),
+        );
+        return (fallback_name, true);
+    }
+    (cleaned, false)
+}
+
+/// Record a step of the trace, if any
+#[inline]
+pub(crate) fn push_step(
+    trace: &mut Option<&mut Vec<TraceStep>>,
+    range: Range<usize>,
+    original: Option<char>,
+    action: TraceAction,
+) {
+    if let Some(steps) = trace {
+        steps.push(TraceStep {
+            range,
+            original,
+            action,
+        });
+    }
+}
+
+/// Push the name of a symbol or an emoji, a word of its own separated by `_` from the rest,
+/// and return if it ends with a letter, only at the end of the name
+#[inline]
+fn push_name(new_name: &mut String, name: &str, last_was_ascii: bool, is_last: bool) -> bool {
+    if last_was_ascii {
+        new_name.push('_');
+    }
+    new_name.push_str(name);
+    if !is_last {
+        new_name.push('_');
+    }
+    is_last
+}
+
+/// Clean an ASCII character, the separators collapsing with the previous one, and tell what happened to it
+#[inline]
+fn clean_ascii(byte: u8, new_name: &mut String, last_was_ascii: &mut bool) -> TraceAction {
     const MINUS: char = '-'; // 45
     const DOT: char = '.'; // 46
 
+    match byte {
+        45 | 46 => {
+            // - Hyphen-minus and . (dot)
+            let separator = char::from(byte);
+            if *last_was_ascii {
+                new_name.push(separator);
+                *last_was_ascii = false;
+                TraceAction::Kept
+            } else {
+                match new_name.pop() {
+                    Some(last_separator @ (MINUS | '_' | DOT)) => {
+                        // re-add the separator
+                        new_name.push(separator);
+                        if last_separator == separator {
+                            TraceAction::Collapsed
+                        } else {
+                            TraceAction::SeparatorRule
+                        }
+                    }
+                    None => {
+                        new_name.push(separator);
+                        TraceAction::Kept
+                    }
+                    Some(last_char) => {
+                        new_name.push(last_char);
+                        new_name.push(separator);
+                        TraceAction::Kept
+                    }
+                }
+            }
+        }
+        0..=44 | 47 | 58..=64 | 91..=96 | 123..=127 => {
+            // 47 is a slash
+            if *last_was_ascii {
+                new_name.push('_');
+                *last_was_ascii = false;
+                if byte == b'_' {
+                    TraceAction::Kept
+                } else {
+                    TraceAction::Separator
+                }
+            } else {
+                TraceAction::Collapsed
+            }
+        }
+        _ => {
+            new_name.push(char::from(byte));
+            *last_was_ascii = true;
+            TraceAction::Kept
+        }
+    }
+}
+
+/// Clean a name with the table, the language, the romanization then the built-in table, recording what happened to each character in `trace`
+#[allow(clippy::too_many_lines)]
+fn clean_name_traced(
+    bytes: &[u8],
+    options: &NotoxArgs,
+    mut trace: Option<&mut Vec<TraceStep>>,
+) -> String {
     // for each byte of the path if it's not ascii, replace it with _
     let mut new_name = String::new();
     let mut vec_grapheme: [u8; 4] = [0; 4];
     let mut last_was_ascii = false;
     let mut idx_grapheme = 0;
-    for byte in path.as_encoded_bytes() {
-        // eprintln!("{} {} {}", byte, *byte as char, last_was_ascii);
-        if idx_grapheme == 0 && *byte < 128 {
-            match byte {
-                45 => {
-                    // - Hyphen-minus
-                    if last_was_ascii {
-                        new_name.push(MINUS);
-                        last_was_ascii = false;
-                    } else {
-                        match new_name.pop() {
-                            Some(MINUS | '_' | DOT) | None => {
-                                // add or re-add the minus
-                                new_name.push(MINUS);
-                            }
-                            Some(last_char) => {
-                                new_name.push(last_char);
-                                new_name.push(MINUS);
-                            }
-                        }
-                    }
-                }
-                46 => {
-                    // . (dot)
-                    if last_was_ascii {
-                        new_name.push(DOT);
-                        last_was_ascii = false;
-                    } else {
-                        match new_name.pop() {
-                            Some(DOT | '_' | MINUS) | None => {
-                                // add or re-add the dot
-                                new_name.push(DOT);
-                            }
-                            Some(last_char) => {
-                                new_name.push(last_char);
-                                new_name.push(DOT);
-                            }
-                        }
-                    }
-                }
-                0..=44 | 47 | 58..=64 | 91..=96 | 123..=127 => {
-                    // 47 is a slash
-                    if last_was_ascii {
-                        new_name.push('_');
-                        last_was_ascii = false;
-                    }
+    // bytes of a sequence of the table, already replaced
+    let mut to_skip = 0;
+    for (idx_byte, byte) in bytes.iter().enumerate() {
+        if to_skip > 0 {
+            to_skip -= 1;
+            continue;
+        }
+        if idx_grapheme == 0 {
+            if let Some((len, replacement)) = options.table.longest_match(&bytes[idx_byte..]) {
+                new_name.push_str(replacement);
+                if let Some(last_byte) = replacement.bytes().last() {
+                    last_was_ascii = last_byte.is_ascii_alphanumeric();
                 }
-                _ => {
-                    new_name.push(*byte as char);
-                    last_was_ascii = true;
+                let original = String::from_utf8_lossy(&bytes[idx_byte..idx_byte + len])
+                    .chars()
+                    .next();
+                let action = match replacement {
+                    "" => TraceAction::Dropped,
+                    mapped => TraceAction::Mapped(mapped.to_string()),
+                };
+                push_step(&mut trace, idx_byte..idx_byte + len, original, action);
+                to_skip = len - 1;
+                continue;
+            }
+        }
+        if idx_grapheme == 0 && options.emoji {
+            if let Some((len, name)) = emoji::emoji_sequence(&bytes[idx_byte..]) {
+                let is_last = idx_byte + len == bytes.len();
+                last_was_ascii = push_name(&mut new_name, &name, last_was_ascii, is_last);
+                let original = String::from_utf8_lossy(&bytes[idx_byte..idx_byte + len])
+                    .chars()
+                    .next();
+                push_step(
+                    &mut trace,
+                    idx_byte..idx_byte + len,
+                    original,
+                    TraceAction::Mapped(name),
+                );
+                to_skip = len - 1;
+                continue;
+            }
+        }
+        if idx_grapheme == 0 {
+            let word = options.words.as_ref().and_then(|words| {
+                romanization::first_char(&bytes[idx_byte..])
+                    .and_then(|symbol| Some((symbol, words.get(symbol)?)))
+            });
+            if let Some((symbol, word)) = word {
+                // the word is glued to the rest of the name: `50%` -> `50pct`
+                new_name.push_str(word);
+                if let Some(last_byte) = word.bytes().last() {
+                    last_was_ascii = last_byte.is_ascii_alphanumeric();
                 }
+                let action = match word {
+                    "" => TraceAction::Dropped,
+                    mapped => TraceAction::Mapped(mapped.to_string()),
+                };
+                let len = symbol.len_utf8();
+                push_step(&mut trace, idx_byte..idx_byte + len, Some(symbol), action);
+                to_skip = len - 1;
+                continue;
             }
+        }
+        if idx_grapheme == 0 && *byte < 128 {
+            let action = clean_ascii(*byte, &mut new_name, &mut last_was_ascii);
+            push_step(
+                &mut trace,
+                idx_byte..idx_byte + 1,
+                Some(char::from(*byte)),
+                action,
+            );
             idx_grapheme = 0;
         } else {
             vec_grapheme[idx_grapheme] = *byte;
             idx_grapheme += 1;
             let first_byte = vec_grapheme[0];
-            if first_byte >= 240 && idx_grapheme == 4 {
+            let curr_char = if first_byte >= 240 && idx_grapheme == 4 {
                 // four bytes grapheme
-                let curr_char = std::char::from_u32(convert_four_to_u32(
+                std::char::from_u32(convert_four_to_u32(
                     vec_grapheme[0],
                     vec_grapheme[1],
                     vec_grapheme[2],
                     vec_grapheme[3],
-                ));
-                last_was_ascii = check_similar(curr_char, &mut new_name, last_was_ascii);
-                vec_grapheme = [0; 4];
-                idx_grapheme = 0;
+                ))
             } else if (224..240).contains(&first_byte) && idx_grapheme == 3 {
                 // three bytes grapheme
-                let curr_char = std::char::from_u32(convert_three_to_u32(
+                std::char::from_u32(convert_three_to_u32(
                     vec_grapheme[0],
                     vec_grapheme[1],
                     vec_grapheme[2],
-                ));
-                last_was_ascii = check_similar(curr_char, &mut new_name, last_was_ascii);
-                vec_grapheme = [0; 4];
-                idx_grapheme = 0;
+                ))
             } else if (128..224).contains(&first_byte) && idx_grapheme == 2 {
                 // two bytes grapheme
-                let curr_char =
-                    std::char::from_u32(convert_two_to_u32(vec_grapheme[0], vec_grapheme[1]));
-                last_was_ascii = check_similar(curr_char, &mut new_name, last_was_ascii);
+                std::char::from_u32(convert_two_to_u32(vec_grapheme[0], vec_grapheme[1]))
+            } else {
+                // the grapheme is not complete
+                continue;
+            };
+            let len_before = new_name.len();
+            let char_start = idx_byte + 1 - idx_grapheme;
+            if let Some(ascii) = curr_char.and_then(ascii_equivalent) {
+                // a typographic dash, ellipsis or space is cleaned as its ASCII equivalent
+                let action = match clean_ascii(ascii, &mut new_name, &mut last_was_ascii) {
+                    TraceAction::Kept => TraceAction::Mapped(char::from(ascii).to_string()),
+                    action => action,
+                };
+                push_step(&mut trace, char_start..idx_byte + 1, curr_char, action);
                 vec_grapheme = [0; 4];
                 idx_grapheme = 0;
+                continue;
             }
-        }
-    }
-    OsString::from(new_name)
-}
-
-/// Clean a path
-fn clean_path(file_path: &Path, options: &NotoxArgs) -> PathChange {
-    let Some(file_name) = file_path.file_name() else {
-        return PathChange::Unchanged {
-            path: file_path.to_path_buf(),
-        };
-    };
-    let cleaned_name = clean_name(file_name, options);
-    if cleaned_name == file_name {
-        return PathChange::Unchanged {
-            path: file_path.to_path_buf(),
-        };
-    }
-    let cleaned_path = file_path.with_file_name(cleaned_name);
-    if options.dry_run {
-        return PathChange::ErrorRename {
-            path: file_path.to_path_buf(),
-            modified: cleaned_path,
-            error: "dry-run".to_string(),
-        };
-    }
-    match std::fs::rename(file_path, &cleaned_path) {
-        Ok(()) => PathChange::Changed {
-            path: file_path.to_path_buf(),
-            modified: cleaned_path,
-        },
-        Err(rename_error) => PathChange::ErrorRename {
-            path: file_path.to_path_buf(),
-            modified: cleaned_path,
-            error: rename_error.to_string(),
-        },
-    }
-}
-
-/// Clean a directory
-fn clean_directory(dir_path: &Path, options: &NotoxArgs) -> Vec<PathChange> {
-    let mut dir_path = dir_path.to_path_buf();
-    let mut result_vec = Vec::new();
-    let res_dir = clean_path(&dir_path, options);
-    if let PathChange::Changed { modified, .. } = &res_dir {
-        dir_path.clone_from(modified);
-    }
-    result_vec.push(res_dir);
-    if let Ok(entries) = std::fs::read_dir(&dir_path) {
-        let ok_entries = {
-            #[cfg(feature = "rayon")]
-            {
-                use std::fs::DirEntry;
-                let (ok_entries, error_entries): (Vec<_>, Vec<_>) = entries
-                    .collect::<Vec<Result<DirEntry, std::io::Error>>>()
-                    .into_par_iter()
-                    .partition_map(|x| match x {
-                        Ok(entry) => Either::Left(entry),
-                        Err(e) => Either::Right(e),
+            let language_mapping =
+                options
+                    .language
+                    .zip(curr_char)
+                    .and_then(|(language, one_char)| {
+                        language.mapping(
+                            one_char,
+                            romanization::last_char(&bytes[..char_start]),
+                            romanization::first_char(&bytes[idx_byte + 1..]),
+                        )
                     });
-                for e in error_entries {
-                    result_vec.push(PathChange::Error {
-                        path: dir_path.clone(),
-                        error: format!("Error reading dir entry of directory {e}"),
-                    });
-                }
-                ok_entries
-            }
-            #[cfg(not(feature = "rayon"))]
+            let romanized = curr_char.and_then(|one_char| {
+                options.romanization.romanize(
+                    one_char,
+                    &bytes[..char_start],
+                    &bytes[idx_byte + 1..],
+                )
+            });
+            last_was_ascii = if let Some(mapped) = language_mapping {
+                new_name.push_str(&mapped);
+                true
+            } else if let Some((romanized, also_romanized)) = romanized {
+                new_name.push_str(&romanized);
+                to_skip = also_romanized;
+                // a letter without romanization, like `ъ`, does not end the word
+                last_was_ascii || !romanized.is_empty()
+            } else if let Some(name) = curr_char
+                .filter(|one_char| options.unicode_names && builtin_mapping(*one_char).is_none())
+                .and_then(symbol_names::symbol_name)
             {
-                let mut ok_entries = Vec::new();
-                for entry in entries {
-                    match entry {
-                        Ok(e) => ok_entries.push(e),
-                        Err(e) => result_vec.push(PathChange::Error {
-                            path: dir_path.clone(),
-                            error: format!("Error reading dir entry of directory {}", e),
-                        }),
-                    }
-                }
-                ok_entries
-            }
-        };
-        #[cfg(feature = "rayon")]
-        let iter = ok_entries.par_iter();
-        #[cfg(not(feature = "rayon"))]
-        let iter = ok_entries.iter();
-        let mapped = iter
-            .map(|entry| {
-                let file_path = entry.path();
-                let is_entry_directory = match entry.file_type() {
-                    Ok(file_type) => file_type.is_dir(),
-                    Err(_) => false,
+                push_name(
+                    &mut new_name,
+                    name,
+                    last_was_ascii,
+                    idx_byte + 1 == bytes.len(),
+                )
+            } else {
+                check_similar(curr_char, &mut new_name, last_was_ascii)
+            };
+            if trace.is_some() {
+                let action = match &new_name[len_before..] {
+                    "" => TraceAction::Dropped,
+                    "_" => TraceAction::Separator,
+                    pushed => TraceAction::Mapped(pushed.to_string()),
                 };
-                if is_entry_directory {
-                    clean_directory(&file_path, options)
-                } else {
-                    let res = clean_path(&file_path, options);
-                    vec![res]
-                }
-            })
-            .flatten()
-            .collect::<Vec<PathChange>>();
-        result_vec.extend(mapped);
-    } else {
-        result_vec.push(PathChange::Error {
-            path: dir_path,
-            error: "Error while reading directory".to_string(),
-        });
+                push_step(
+                    &mut trace,
+                    char_start..idx_byte + 1 + to_skip,
+                    curr_char,
+                    action,
+                );
+            }
+            vec_grapheme = [0; 4];
+            idx_grapheme = 0;
+        }
+    }
+    if idx_grapheme > 0 {
+        // the last grapheme is not complete
+        push_step(
+            &mut trace,
+            bytes.len() - idx_grapheme..bytes.len(),
+            None,
+            TraceAction::Dropped,
+        );
     }
-    result_vec
+    new_name
 }
 
 /// Get the path of a directory
@@ -707,9 +1032,31 @@ fn show_version() {
 pub fn parse_args(args: &[String]) -> Result<(NotoxArgs, HashSet<PathBuf>), i32> {
     let mut dry_run = true;
     let mut output = NotoxOutput::Default;
+    let mut conflict = ConflictStrategy::default();
+    let mut fallback = FallbackName::default();
+    let mut extension = ExtensionOptions::default();
+    #[cfg_attr(not(feature = "serde"), allow(unused_mut))]
+    let mut table = TransliterationTable::new();
+    let mut language = None;
+    let mut romanization = Romanization::default();
+    let mut unicode_names = false;
+    let mut emoji = false;
+    let mut words = None;
+    let mut case = None;
+    let mut check = false;
+    let mut explain = None;
+    #[cfg(feature = "serde")]
+    let mut journal = None;
+    #[cfg(feature = "serde")]
+    let mut journal_to_undo = None;
+    #[cfg(feature = "serde")]
+    let mut json_to_apply = None;
+    #[cfg(feature = "serde")]
+    let mut failed_only = false;
     let mut path_to_check: HashSet<PathBuf> = HashSet::new();
     let mut count_path_args = 0;
-    for one_arg in &args[1..] {
+    let mut args_iter = args[1..].iter();
+    while let Some(one_arg) = args_iter.next() {
         if one_arg == "-d" || one_arg == "--do" {
             dry_run = false;
         } else if one_arg == "-h" || one_arg == "--help" {
@@ -717,12 +1064,40 @@ pub fn parse_args(args: &[String]) -> Result<(NotoxArgs, HashSet<PathBuf>), i32>
             show_version();
             println!("Options:");
             println!("  -d, --do          Do the renaming");
+            println!(
+                "  -c, --conflict    When the new name is taken: skip (default), suffix or abort"
+            );
             println!("  -h, --help        Show this help message");
             println!("  -v, --version     Show the version");
             println!("  -p, --json-pretty Print the result in JSON format (pretty)");
             println!("  -e, --json-error  Print only the errors in JSON format");
             println!("  -j, --json        Print the result in JSON format");
             println!("  -q, --quiet       Do not print anything");
+            println!(
+                "  --fallback NAME   Name used when nothing is left: hash (default), codepoints or a placeholder"
+            );
+            println!("  --lower-ext       Lowercase the extensions");
+            println!("  --ext-synonyms    Use the usual extensions: jpeg -> jpg, tiff -> tif, htm -> html");
+            println!("  --lang CODE       Use the spellings of a language: de, da, no, sv, fr, es, tr or pl");
+            println!("  --romanize LIST   Romanize the scripts of the list: cyrillic, cyrillic-gost, greek, kana, hangul,");
+            println!("                    hanzi, hanzi-dash, hanzi-underscore (pinyin feature),");
+            println!("                    arabic, persian, hebrew, devanagari");
+            println!("  --unicode-names   Replace the symbols without mapping by their name: ☃ -> snowman");
+            println!(
+                "  --emoji           Replace the emoji by their short name: 🎉 -> party_popper"
+            );
+            println!("  --words           Spell out the symbols: & -> and, % -> pct, € -> EUR, + -> plus");
+            println!(
+                "  --word S=WORD     Spell out the symbol S as WORD, like &=und (implies --words)"
+            );
+            println!("  --case MODE       Case of the stem once cleaned: lower, upper, snake, kebab, camel, pascal or title");
+            println!("  --table FILE      Use the mappings of FILE (JSON or TOML) before the built-in ones");
+            println!("  --check           Explain the toxic names, fail if there is any");
+            println!("  --explain NAME    Explain how NAME is cleaned, character by character");
+            println!("  --journal FILE    Write the done renames to FILE");
+            println!("  -u, --undo FILE   Reverse the renames written in the journal FILE");
+            println!("  --apply FILE      Do the renames of the JSON output FILE");
+            println!("  --failed-only     With --apply, only retry the renames that failed");
             return Err(1);
         } else if one_arg == "-v" || one_arg == "--version" {
             show_version();
@@ -787,6 +1162,160 @@ pub fn parse_args(args: &[String]) -> Result<(NotoxArgs, HashSet<PathBuf>), i32>
                 println!("JSON output is not available, please use a notox version with the 'serde' feature.");
                 return Err(2);
             }
+        } else if one_arg == "-c" || one_arg == "--conflict" {
+            let strategy_name = args_iter.next().map_or("", String::as_str);
+            let Some(strategy) = ConflictStrategy::from_name(strategy_name) else {
+                println!("Invalid conflict strategy: '{strategy_name}', use skip, suffix or abort");
+                return Err(2);
+            };
+            conflict = strategy;
+        } else if one_arg == "--fallback" {
+            let Some(fallback_name) = args_iter.next() else {
+                println!(
+                    "Missing fallback after '{one_arg}', use hash, codepoints or a placeholder"
+                );
+                return Err(2);
+            };
+            fallback = FallbackName::from_name(fallback_name);
+        } else if one_arg == "--lower-ext" {
+            extension.lowercase = true;
+        } else if one_arg == "--ext-synonyms" {
+            extension.synonyms = true;
+        } else if one_arg == "--unicode-names" {
+            unicode_names = true;
+        } else if one_arg == "--emoji" {
+            emoji = true;
+        } else if one_arg == "--words" {
+            words.get_or_insert_with(SymbolWords::default);
+        } else if one_arg == "--word" {
+            let spec = args_iter.next().map_or("", String::as_str);
+            if let Err(e) = words
+                .get_or_insert_with(SymbolWords::default)
+                .insert_spec(spec)
+            {
+                println!("{e}");
+                return Err(2);
+            }
+        } else if one_arg == "--case" {
+            let name = args_iter.next().map_or("", String::as_str);
+            let Some(one_case) = CaseMode::from_name(name) else {
+                let names = CASE_MODES
+                    .iter()
+                    .map(CaseMode::name)
+                    .collect::<Vec<&str>>()
+                    .join(", ");
+                println!("Invalid case: '{name}', use {names}");
+                return Err(2);
+            };
+            case = Some(one_case);
+        } else if one_arg == "--lang" {
+            let code = args_iter.next().map_or("", String::as_str);
+            let Some(one_language) = Language::from_code(code) else {
+                let codes = LANGUAGES
+                    .iter()
+                    .map(Language::code)
+                    .collect::<Vec<&str>>()
+                    .join(", ");
+                println!("Invalid language: '{code}', use {codes}");
+                return Err(2);
+            };
+            language = Some(one_language);
+        } else if one_arg == "--romanize" {
+            let scripts = args_iter.next().map_or("", String::as_str);
+            for script in scripts.split(',') {
+                if !romanization.enable(script) {
+                    println!(
+                        "Invalid script: '{script}', use {}",
+                        ROMANIZATION_NAMES.join(", ")
+                    );
+                    return Err(2);
+                }
+            }
+            #[cfg(not(feature = "pinyin"))]
+            if romanization.hanzi.is_some() {
+                println!("Hanzi romanization is not available, please use a notox version with the 'pinyin' feature.");
+                return Err(2);
+            }
+        } else if one_arg == "--table" {
+            #[cfg(feature = "serde")]
+            {
+                let Some(table_path) = args_iter.next() else {
+                    println!("Missing table file after '{one_arg}'");
+                    return Err(2);
+                };
+                table = match TransliterationTable::from_file(Path::new(table_path)) {
+                    Ok(file_table) => file_table,
+                    Err(e) => {
+                        println!("Invalid table '{table_path}': {e}");
+                        return Err(2);
+                    }
+                };
+            }
+            #[cfg(not(feature = "serde"))]
+            {
+                println!("Tables are not available, please use a notox version with the 'serde' feature.");
+                return Err(2);
+            }
+        } else if one_arg == "--check" {
+            check = true;
+        } else if one_arg == "--explain" {
+            let Some(name) = args_iter.next() else {
+                println!("Missing name after '{one_arg}'");
+                return Err(2);
+            };
+            explain = Some(name.clone());
+        } else if one_arg == "--journal" {
+            #[cfg(feature = "serde")]
+            {
+                let Some(journal_path) = args_iter.next() else {
+                    println!("Missing journal file after '{one_arg}'");
+                    return Err(2);
+                };
+                journal = Some(PathBuf::from(journal_path));
+            }
+            #[cfg(not(feature = "serde"))]
+            {
+                println!("Journal is not available, please use a notox version with the 'serde' feature.");
+                return Err(2);
+            }
+        } else if one_arg == "-u" || one_arg == "--undo" {
+            #[cfg(feature = "serde")]
+            {
+                let Some(journal_path) = args_iter.next() else {
+                    println!("Missing journal file after '{one_arg}'");
+                    return Err(2);
+                };
+                journal_to_undo = Some(PathBuf::from(journal_path));
+            }
+            #[cfg(not(feature = "serde"))]
+            {
+                println!("Journal is not available, please use a notox version with the 'serde' feature.");
+                return Err(2);
+            }
+        } else if one_arg == "--apply" {
+            #[cfg(feature = "serde")]
+            {
+                let Some(json_path) = args_iter.next() else {
+                    println!("Missing JSON file after '{one_arg}'");
+                    return Err(2);
+                };
+                json_to_apply = Some(PathBuf::from(json_path));
+            }
+            #[cfg(not(feature = "serde"))]
+            {
+                println!("JSON input is not available, please use a notox version with the 'serde' feature.");
+                return Err(2);
+            }
+        } else if one_arg == "--failed-only" {
+            #[cfg(feature = "serde")]
+            {
+                failed_only = true;
+            }
+            #[cfg(not(feature = "serde"))]
+            {
+                println!("JSON input is not available, please use a notox version with the 'serde' feature.");
+                return Err(2);
+            }
         } else if one_arg == "-q" || one_arg == "--quiet" {
             output = NotoxOutput::Quiet;
         } else if one_arg == "*" {
@@ -802,6 +1331,32 @@ pub fn parse_args(args: &[String]) -> Result<(NotoxArgs, HashSet<PathBuf>), i32>
             println!("Cannot find path: {one_arg}");
         }
     }
+    #[cfg(feature = "serde")]
+    let mode = if let Some(json_path) = json_to_apply {
+        NotoxMode::Apply {
+            json_path,
+            failed_only,
+        }
+    } else if failed_only {
+        println!("--failed-only can only be used with --apply");
+        return Err(2);
+    } else if let Some(journal_path) = journal_to_undo {
+        NotoxMode::Undo(journal_path)
+    } else if let Some(name) = explain {
+        NotoxMode::Explain(name)
+    } else if check {
+        NotoxMode::Check
+    } else {
+        NotoxMode::Clean
+    };
+    #[cfg(not(feature = "serde"))]
+    let mode = if let Some(name) = explain {
+        NotoxMode::Explain(name)
+    } else if check {
+        NotoxMode::Check
+    } else {
+        NotoxMode::Clean
+    };
     if count_path_args == 1 && path_to_check.is_empty() {
         return Err(1);
     }
@@ -809,7 +1364,26 @@ pub fn parse_args(args: &[String]) -> Result<(NotoxArgs, HashSet<PathBuf>), i32>
         let paths = get_path_of_dir(".");
         path_to_check.extend(paths);
     }
-    Ok((NotoxArgs { dry_run, output }, path_to_check))
+    Ok((
+        NotoxArgs {
+            dry_run,
+            output,
+            conflict,
+            fallback,
+            extension,
+            table,
+            language,
+            romanization,
+            unicode_names,
+            emoji,
+            words,
+            case,
+            mode,
+            #[cfg(feature = "serde")]
+            journal,
+        },
+        path_to_check,
+    ))
 }
 
 /// Notox struct
@@ -841,6 +1415,20 @@ impl Notox {
         match parse_args(args) {
             Ok((notox_args, paths)) => {
                 let notox_inst = Self::new(notox_args);
+                if let NotoxMode::Explain(name) = &notox_inst.notox_args.mode {
+                    return match notox_inst.print_trace(OsStr::new(name)) {
+                        Ok(()) => 0,
+                        Err(code) => code,
+                    };
+                }
+                if notox_inst.notox_args.mode == NotoxMode::Check {
+                    let reports = notox_inst.lint(&paths);
+                    return match notox_inst.print_lint(&reports) {
+                        Ok(()) if reports.is_empty() => 0,
+                        Ok(()) => 1,
+                        Err(code) => code,
+                    };
+                }
                 let final_res = notox_inst.run(&paths);
                 match notox_inst.print_output(final_res) {
                     Ok(()) => 0,
@@ -860,25 +1448,207 @@ impl Notox {
         if self.notox_args.is_verbose() {
             println!("Running with options: {}", &self.notox_args);
         }
-        #[cfg(feature = "rayon")]
-        let iter = paths_to_check.par_iter();
-        #[cfg(not(feature = "rayon"))]
-        let iter = paths_to_check.iter();
-
-        let results = iter
-            .map(|one_path| {
-                if self.notox_args.is_verbose() {
-                    println!("Checking: {}", one_path.display());
+        match &self.notox_args.mode {
+            NotoxMode::Clean => self.finish_plan(self.plan(paths_to_check)),
+            NotoxMode::Check => self.plan(paths_to_check).dry_run(),
+            NotoxMode::Explain(name) => {
+                let path = PathBuf::from(name);
+                let (modified, fallback) = clean_name(OsStr::new(name), &self.notox_args);
+                let modified = PathBuf::from(modified);
+                if path == modified {
+                    vec![PathChange::Unchanged { path }]
+                } else {
+                    vec![PathChange::WouldRename {
+                        path,
+                        modified,
+                        fallback,
+                    }]
                 }
-                if one_path.is_dir() {
-                    clean_directory(one_path, &self.notox_args)
+            }
+            #[cfg(feature = "serde")]
+            NotoxMode::Undo(journal_path) => self.undo(journal_path),
+            #[cfg(feature = "serde")]
+            NotoxMode::Apply {
+                json_path,
+                failed_only,
+            } => match journal::read_changes(json_path) {
+                Ok(changes) => self.finish_plan(self.plan_changes(changes, *failed_only)),
+                Err(error) => vec![PathChange::Error {
+                    path: json_path.clone(),
+                    error,
+                }],
+            },
+        }
+    }
+
+    /// Reverse the renames written in a journal file, deepest paths first.
+    /// The renames that cannot be reversed are reported as errors or conflicts
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn undo(&self, journal_path: &Path) -> Vec<PathChange> {
+        match journal::read_changes(journal_path) {
+            Ok(journal) => self.finish_plan(journal::undo_plan(journal)),
+            Err(error) => vec![PathChange::Error {
+                path: journal_path.to_path_buf(),
+                error,
+            }],
+        }
+    }
+
+    /// Apply a plan, or only report it for a dry-run
+    fn finish_plan(&self, rename_plan: RenamePlan) -> Vec<PathChange> {
+        if self.notox_args.dry_run {
+            return rename_plan.dry_run();
+        }
+        #[cfg(feature = "serde")]
+        if let Some(journal_path) = &self.notox_args.journal {
+            let mut results = rename_plan.apply();
+            if let Err(error) = journal::write_journal(journal_path, &results) {
+                results.push(PathChange::Error {
+                    path: journal_path.clone(),
+                    error,
+                });
+            }
+            return results;
+        }
+        rename_plan.apply()
+    }
+
+    /// Compute every rename of the paths, without renaming anything.
+    /// Paths contained in another path are only checked once
+    #[must_use]
+    pub fn plan<S: ::std::hash::BuildHasher>(
+        &self,
+        paths_to_check: &HashSet<PathBuf, S>,
+    ) -> RenamePlan {
+        plan::plan_paths(paths_to_check, &self.notox_args)
+    }
+
+    /// Compute the renames listed in a previous output, without renaming anything.
+    /// The paths must still exist and the targets must be free.
+    /// With `failed_only`, only the renames that failed are kept
+    #[must_use]
+    pub fn plan_changes(&self, changes: Vec<PathChange>, failed_only: bool) -> RenamePlan {
+        plan::plan_changes(changes, &self.notox_args, failed_only)
+    }
+
+    /// Explain why the names of the paths are toxic, without renaming anything.
+    /// Only the toxic names are reported
+    #[must_use]
+    pub fn lint<S: ::std::hash::BuildHasher>(
+        &self,
+        paths_to_check: &HashSet<PathBuf, S>,
+    ) -> Vec<LintReport> {
+        lint::lint_plan(self.plan(paths_to_check), &self.notox_args)
+    }
+
+    /// Print the reports of [`Notox::lint`] conforming to the options
+    /// # Errors
+    /// Return an error if the output cannot be serialized
+    pub fn print_lint(&self, reports: &[LintReport]) -> Result<(), i32> {
+        match &self.notox_args.output {
+            NotoxOutput::Default => {
+                for one_report in reports {
+                    println!(
+                        "{} -> {}",
+                        one_report.path.display(),
+                        one_report.modified.display()
+                    );
+                    for one_finding in &one_report.findings {
+                        println!(
+                            "  {} {:?} at {}",
+                            one_finding.category, one_finding.text, one_finding.index
+                        );
+                    }
+                }
+                match reports.len() {
+                    0 => println!("no toxic name found"),
+                    1 => println!("1 toxic name found"),
+                    len => println!("{len} toxic names found"),
+                }
+            }
+            #[cfg(feature = "serde")]
+            NotoxOutput::JsonOutput { json: _, pretty } => {
+                let json_string = match pretty {
+                    true => serde_json::to_string_pretty(reports),
+                    false => serde_json::to_string(reports),
+                };
+                if let Ok(stringed) = json_string {
+                    println!("{stringed}");
                 } else {
-                    let one_cleaned = clean_path(one_path, &self.notox_args);
-                    vec![one_cleaned]
+                    println!(r#"{{"error": "Cannot serialize result"}}"#);
+                    return Err(2);
                 }
-            })
-            .flatten();
-        results.collect::<Vec<PathChange>>()
+            }
+            NotoxOutput::Quiet => {}
+        }
+        Ok(())
+    }
+
+    /// Print how a name is cleaned, see [`trace_name`]
+    /// # Errors
+    /// Return an error if the output cannot be serialized
+    pub fn print_trace(&self, name: &OsStr) -> Result<(), i32> {
+        let (cleaned, steps) = trace_name(name, &self.notox_args);
+        match &self.notox_args.output {
+            NotoxOutput::Default => {
+                println!(
+                    "{} -> {}",
+                    name.to_string_lossy(),
+                    cleaned.to_string_lossy()
+                );
+                if steps
+                    .iter()
+                    .any(|one_step| matches!(one_step.action, TraceAction::Fallback(_)))
+                {
+                    println!("  warning: {}", fallback::FALLBACK_WARNING);
+                }
+                for one_step in steps {
+                    let original = match (&one_step.action, one_step.original) {
+                        (TraceAction::Kept, _) => continue,
+                        (_, Some(one_char)) => format!("{one_char:?}"),
+                        (
+                            TraceAction::TrimmedBeforeExtension
+                            | TraceAction::TrimmedDot
+                            | TraceAction::Cased(_)
+                            | TraceAction::ExtensionCleaned(_)
+                            | TraceAction::Fallback(_),
+                            None,
+                        ) => format!(
+                            "{:?}",
+                            String::from_utf8_lossy(
+                                &name.as_encoded_bytes()[one_step.range.clone()]
+                            )
+                        ),
+                        (_, None) => "invalid UTF-8".to_string(),
+                    };
+                    println!(
+                        "  {}..{} {} {}",
+                        one_step.range.start, one_step.range.end, original, one_step.action
+                    );
+                }
+            }
+            #[cfg(feature = "serde")]
+            NotoxOutput::JsonOutput { json: _, pretty } => {
+                let json_value = serde_json::json!({
+                    "name": name.to_string_lossy(),
+                    "cleaned": cleaned.to_string_lossy(),
+                    "steps": steps,
+                });
+                let json_string = match pretty {
+                    true => serde_json::to_string_pretty(&json_value),
+                    false => serde_json::to_string(&json_value),
+                };
+                if let Ok(stringed) = json_string {
+                    println!("{stringed}");
+                } else {
+                    println!(r#"{{"error": "Cannot serialize result"}}"#);
+                    return Err(2);
+                }
+            }
+            NotoxOutput::Quiet => {}
+        }
+        Ok(())
     }
 
     /// Print the output of the program conforming to the options
@@ -889,18 +1659,35 @@ impl Notox {
             NotoxOutput::Default => {
                 let len = final_res.len();
                 for one_change in final_res {
+                    let warning = one_change.warning();
                     match one_change {
                         PathChange::Unchanged { .. } => {}
-                        PathChange::Changed { path, modified } => {
-                            println!("{} -> {}", path.display(), modified.display());
-                        }
+                        PathChange::Changed { path, modified, .. }
+                        | PathChange::WouldRename { path, modified, .. } => match warning {
+                            Some(warning) => println!(
+                                "{} -> {} : warning: {}",
+                                path.display(),
+                                modified.display(),
+                                warning
+                            ),
+                            None => println!("{} -> {}", path.display(), modified.display()),
+                        },
                         PathChange::Error { path, error } => {
                             println!("{} : {}", path.display(), error);
                         }
+                        PathChange::Conflict { path, modified, .. } => {
+                            println!(
+                                "{} -> {} : {}",
+                                path.display(),
+                                modified.display(),
+                                NotoxError::TargetExists
+                            );
+                        }
                         PathChange::ErrorRename {
                             path,
                             modified,
                             error,
+                            ..
                         } => {
                             println!("{} -> {} : {}", path.display(), modified.display(), error);
                         }
@@ -923,8 +1710,11 @@ impl Notox {
                         let mut vec_to_json: Vec<PathChange> = Vec::new();
                         for one_change in final_res {
                             match one_change {
-                                PathChange::Unchanged { .. } | PathChange::Changed { .. } => {}
+                                PathChange::Unchanged { .. }
+                                | PathChange::Changed { .. }
+                                | PathChange::WouldRename { .. } => {}
                                 one_res @ (PathChange::Error { .. }
+                                | PathChange::Conflict { .. }
                                 | PathChange::ErrorRename { .. }) => {
                                     vec_to_json.push(one_res);
                                 }
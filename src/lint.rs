@@ -0,0 +1,200 @@
+//! Explain why names are toxic, without renaming anything
+
+use core::fmt;
+use std::{ffi::OsStr, path::PathBuf};
+
+use crate::{trace_name, NotoxArgs, PathChange, RenamePlan, TraceAction, TraceStep};
+
+/// Why a character of a name is toxic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum LintCategory {
+    /// a space, a tab or another whitespace
+    Whitespace,
+    /// a non-ASCII letter, replaced by an ASCII one
+    NonAsciiLetter,
+    /// a character with a meaning for the shell, like `$` or `*`
+    ShellMetacharacter,
+    /// a control character, like a newline
+    ControlCharacter,
+    /// other punctuation, replaced by a separator
+    Punctuation,
+    /// separators collapsed into one, or removed at the start of the name
+    RepeatedSeparators,
+    /// a character dropped because it has no mapping
+    Dropped,
+}
+
+impl LintCategory {
+    /// Name of the category
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            LintCategory::Whitespace => "whitespace",
+            LintCategory::NonAsciiLetter => "non_ascii_letter",
+            LintCategory::ShellMetacharacter => "shell_metacharacter",
+            LintCategory::ControlCharacter => "control_character",
+            LintCategory::Punctuation => "punctuation",
+            LintCategory::RepeatedSeparators => "repeated_separators",
+            LintCategory::Dropped => "dropped",
+        }
+    }
+}
+
+impl fmt::Display for LintCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A toxic part of a name
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LintFinding {
+    /// Why the text is toxic
+    pub category: LintCategory,
+
+    /// Byte offset of the text in the name
+    pub index: usize,
+
+    /// The toxic text
+    pub text: String,
+}
+
+/// The findings of a toxic name
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LintReport {
+    /// The toxic path
+    pub path: PathBuf,
+
+    /// The path with the cleaned name
+    pub modified: PathBuf,
+
+    /// Why the name is toxic
+    pub findings: Vec<LintFinding>,
+}
+
+/// Characters with a meaning for the shell
+const SHELL_METACHARACTERS: &str = "!\"#$&'()*;<>?[\\]`{|}~";
+
+/// Categorize a character of a name, knowing what the cleaning did to it, `None` if it is kept as is
+fn categorize(one_char: char, action: Option<&TraceAction>) -> Option<LintCategory> {
+    if one_char.is_ascii_alphanumeric() || matches!(one_char, '-' | '.' | '_') {
+        None
+    } else if one_char.is_control() {
+        Some(LintCategory::ControlCharacter)
+    } else if one_char.is_whitespace() {
+        Some(LintCategory::Whitespace)
+    } else if SHELL_METACHARACTERS.contains(one_char) {
+        Some(LintCategory::ShellMetacharacter)
+    } else if one_char.is_ascii() {
+        Some(LintCategory::Punctuation)
+    } else {
+        match action {
+            // without mapping, the character is removed or replaced by a separator
+            None | Some(TraceAction::Dropped | TraceAction::Separator) => {
+                Some(LintCategory::Dropped)
+            }
+            Some(TraceAction::Mapped(mapped))
+                if mapped.bytes().any(|byte| byte.is_ascii_alphanumeric()) =>
+            {
+                Some(LintCategory::NonAsciiLetter)
+            }
+            Some(_) => Some(LintCategory::Punctuation),
+        }
+    }
+}
+
+/// Check if a character of a name ends up as a separator, or is removed
+fn is_separator(one_char: char, category: Option<LintCategory>) -> bool {
+    match category {
+        None => matches!(one_char, '-' | '.' | '_'),
+        Some(LintCategory::NonAsciiLetter | LintCategory::Dropped) => false,
+        Some(_) => true,
+    }
+}
+
+/// Report a run of separators if it is collapsed, or removed at the start of the name
+fn push_separators(separators: Option<(usize, String)>, findings: &mut Vec<LintFinding>) {
+    if let Some((index, text)) = separators {
+        if text.chars().count() > 1 || (index == 0 && text.starts_with('_')) {
+            findings.push(LintFinding {
+                category: LintCategory::RepeatedSeparators,
+                index,
+                text,
+            });
+        }
+    }
+}
+
+/// Explain why a name is toxic when cleaned with the options, the findings are sorted by index
+#[must_use]
+pub fn lint_name(name: &OsStr, options: &NotoxArgs) -> Vec<LintFinding> {
+    let name = String::from_utf8_lossy(name.as_encoded_bytes());
+    // the steps of the characters, a step covers several characters mapped together
+    let steps = trace_name(OsStr::new(name.as_ref()), options)
+        .1
+        .into_iter()
+        .filter(|one_step| one_step.original.is_some())
+        .collect::<Vec<TraceStep>>();
+    let mut findings = Vec::new();
+    // start and text of the current run of separators
+    let mut separators: Option<(usize, String)> = None;
+    for (index, one_char) in name.char_indices() {
+        let action = steps
+            .iter()
+            .find(|one_step| one_step.range.contains(&index))
+            .map(|one_step| &one_step.action);
+        let category = categorize(one_char, action);
+        if is_separator(one_char, category) {
+            separators
+                .get_or_insert_with(|| (index, String::new()))
+                .1
+                .push(one_char);
+        } else {
+            push_separators(separators.take(), &mut findings);
+        }
+        if let Some(category) = category {
+            findings.push(LintFinding {
+                category,
+                index,
+                text: one_char.to_string(),
+            });
+        }
+    }
+    push_separators(separators, &mut findings);
+    findings.sort_by_key(|one_finding| one_finding.index);
+    findings
+}
+
+/// Report the toxic names of a plan, sorted by path
+pub(crate) fn lint_plan(rename_plan: RenamePlan, options: &NotoxArgs) -> Vec<LintReport> {
+    let toxic_paths = rename_plan
+        .renames
+        .into_iter()
+        .map(|one_rename| (one_rename.path, one_rename.modified))
+        .chain(
+            rename_plan
+                .results
+                .into_iter()
+                .filter_map(|one_change| match one_change {
+                    PathChange::Conflict { path, modified, .. }
+                    | PathChange::ErrorRename { path, modified, .. } => Some((path, modified)),
+                    _ => None,
+                }),
+        );
+    let mut reports = toxic_paths
+        .map(|(path, modified)| LintReport {
+            findings: path
+                .file_name()
+                .map(|name| lint_name(name, options))
+                .unwrap_or_default(),
+            path,
+            modified,
+        })
+        .collect::<Vec<LintReport>>();
+    reports.sort_by(|first, second| first.path.cmp(&second.path));
+    reports
+}
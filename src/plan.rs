@@ -0,0 +1,510 @@
+//! Two-phase renaming: compute every rename first, then apply them
+
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "rayon")]
+use rayon::{iter::Either, prelude::*};
+
+use crate::{
+    clean_name, extension::split_extension, ConflictStrategy, IoError, NotoxArgs, NotoxError,
+    PathChange,
+};
+
+/// Message used when the path to rename does not exist anymore
+const SOURCE_ERROR: &str = "source does not exist";
+
+/// A rename computed by [`crate::Notox::plan`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedRename {
+    /// The path, before any rename of the plan
+    pub path: PathBuf,
+
+    /// The new path, in the same directory as `path`
+    pub modified: PathBuf,
+
+    /// Only selected renames are done by [`RenamePlan::apply`]
+    pub selected: bool,
+
+    /// Whether the new name is a fallback name, see [`crate::FallbackName`]
+    pub fallback: bool,
+}
+
+/// Every rename of a run, computed before touching the filesystem
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenamePlan {
+    /// Renames to do, parent directories before their content
+    pub renames: Vec<PlannedRename>,
+
+    /// Paths that will not be renamed: unchanged, conflicts and errors
+    pub results: Vec<PathChange>,
+
+    /// Set when a conflict aborted the plan, nothing will be renamed
+    pub aborted: bool,
+}
+
+impl RenamePlan {
+    /// Rename the selected paths, parent directories first.
+    /// Paths inside a renamed directory are reported with their new parent
+    #[must_use]
+    pub fn apply(self) -> Vec<PathChange> {
+        if self.aborted {
+            return self.skip_all(&NotoxError::Aborted);
+        }
+        let mut results = self.results;
+        let mut moved_dirs: Vec<(PathBuf, PathBuf)> = Vec::new();
+        for one_rename in self.renames {
+            let path = current_location(&one_rename.path, &moved_dirs);
+            let modified = current_location(&one_rename.modified, &moved_dirs);
+            if !one_rename.selected {
+                results.push(PathChange::Unchanged { path });
+                continue;
+            }
+            // the target may have been created since the plan
+            if modified.symlink_metadata().is_ok() && !is_same_file(&path, &modified) {
+                results.push(PathChange::Conflict {
+                    path,
+                    modified,
+                    fallback: one_rename.fallback,
+                });
+                continue;
+            }
+            match std::fs::rename(&path, &modified) {
+                Ok(()) => {
+                    if modified.is_dir() {
+                        moved_dirs.push((one_rename.path, modified.clone()));
+                    }
+                    results.push(PathChange::Changed {
+                        path,
+                        modified,
+                        fallback: one_rename.fallback,
+                    });
+                }
+                Err(rename_error) => results.push(PathChange::ErrorRename {
+                    path,
+                    modified,
+                    error: NotoxError::from_io(&rename_error),
+                    fallback: one_rename.fallback,
+                }),
+            }
+        }
+        results
+    }
+
+    /// Report the selected renames without doing them
+    #[must_use]
+    pub fn dry_run(self) -> Vec<PathChange> {
+        if self.aborted {
+            return self.skip_all(&NotoxError::Aborted);
+        }
+        let mut results = self.results;
+        results.extend(self.renames.into_iter().map(|one_rename| {
+            if one_rename.selected {
+                PathChange::WouldRename {
+                    path: one_rename.path,
+                    modified: one_rename.modified,
+                    fallback: one_rename.fallback,
+                }
+            } else {
+                PathChange::Unchanged {
+                    path: one_rename.path,
+                }
+            }
+        }));
+        results
+    }
+
+    /// Add a rename to the plan if the target is free, following the conflict strategy
+    fn push_rename(
+        &mut self,
+        path: PathBuf,
+        modified: PathBuf,
+        fallback: bool,
+        conflict: ConflictStrategy,
+        claimed: &mut HashSet<PathBuf>,
+    ) {
+        if let Err(error) = check_write_parent(&path) {
+            self.results.push(PathChange::ErrorRename {
+                path,
+                modified,
+                error: NotoxError::from_io(&error),
+                fallback,
+            });
+            return;
+        }
+        match claim_target(&path, modified, conflict, claimed) {
+            Ok(free_path) => self.renames.push(PlannedRename {
+                path,
+                modified: free_path,
+                selected: true,
+                fallback,
+            }),
+            Err(taken_path) => {
+                if conflict == ConflictStrategy::Abort {
+                    self.aborted = true;
+                }
+                self.results.push(PathChange::Conflict {
+                    path,
+                    modified: taken_path,
+                    fallback,
+                });
+            }
+        }
+    }
+
+    /// Report every selected rename as not done because of `error`
+    fn skip_all(self, error: &NotoxError) -> Vec<PathChange> {
+        let mut results = self.results;
+        results.extend(self.renames.into_iter().map(|one_rename| {
+            if one_rename.selected {
+                PathChange::ErrorRename {
+                    path: one_rename.path,
+                    modified: one_rename.modified,
+                    error: error.clone(),
+                    fallback: one_rename.fallback,
+                }
+            } else {
+                PathChange::Unchanged {
+                    path: one_rename.path,
+                }
+            }
+        }));
+        results
+    }
+}
+
+/// A path found while walking the inputs, before conflicts are checked
+enum WalkedPath {
+    /// The path will not be renamed
+    Done(PathChange),
+
+    /// The path can be renamed to the given path, a fallback name or not
+    Rename(PathBuf, PathBuf, bool),
+}
+
+/// Find the location of a path once the given directories are moved
+fn current_location(path: &Path, moved_dirs: &[(PathBuf, PathBuf)]) -> PathBuf {
+    let Some(parent) = path.parent() else {
+        return path.to_path_buf();
+    };
+    // deepest directories are moved last
+    for (old_dir, new_dir) in moved_dirs.iter().rev() {
+        if let Ok(relative) = parent.strip_prefix(old_dir) {
+            let mut location = new_dir.join(relative);
+            if let Some(file_name) = path.file_name() {
+                location.push(file_name);
+            }
+            return location;
+        }
+    }
+    path.to_path_buf()
+}
+
+/// Check if two paths point to the same file
+fn is_same_file(first: &Path, second: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        match (first.symlink_metadata(), second.symlink_metadata()) {
+            (Ok(first_meta), Ok(second_meta)) => {
+                first_meta.dev() == second_meta.dev() && first_meta.ino() == second_meta.ino()
+            }
+            _ => false,
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        match (first.canonicalize(), second.canonicalize()) {
+            (Ok(first_canon), Ok(second_canon)) => first_canon == second_canon,
+            _ => false,
+        }
+    }
+}
+
+/// Check if a target is already used by another file or by another rename of the plan
+fn is_taken(file_path: &Path, target: &Path, claimed: &HashSet<PathBuf>) -> bool {
+    claimed.contains(target)
+        || (target.symlink_metadata().is_ok() && !is_same_file(file_path, target))
+}
+
+/// Add a numeric suffix before the extensions: `archive.tar.gz` -> `archive-1.tar.gz`
+fn with_numeric_suffix(path: &Path, number: usize) -> PathBuf {
+    // the targets are cleaned names, always valid UTF-8
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let (stem, extensions) = split_extension(name.as_bytes());
+    path.with_file_name(format!(
+        "{}-{number}{}",
+        String::from_utf8_lossy(stem),
+        String::from_utf8_lossy(extensions)
+    ))
+}
+
+/// Find a free target for a path, following the conflict strategy.
+/// Returns the taken target if there is no free one
+fn claim_target(
+    file_path: &Path,
+    target: PathBuf,
+    conflict: ConflictStrategy,
+    claimed: &mut HashSet<PathBuf>,
+) -> Result<PathBuf, PathBuf> {
+    if !is_taken(file_path, &target, claimed) {
+        claimed.insert(target.clone());
+        return Ok(target);
+    }
+    match conflict {
+        ConflictStrategy::Suffix => {
+            let mut number = 1;
+            loop {
+                let candidate = with_numeric_suffix(&target, number);
+                if !is_taken(file_path, &candidate, claimed) {
+                    claimed.insert(candidate.clone());
+                    return Ok(candidate);
+                }
+                number += 1;
+            }
+        }
+        ConflictStrategy::Skip | ConflictStrategy::Abort => Err(target),
+    }
+}
+
+/// Check if the process can write the directory containing a path, with access(2).
+/// Only the permission errors are returned, the rename reports the other ones
+fn check_write_parent(file_path: &Path) -> Result<(), std::io::Error> {
+    #[cfg(unix)]
+    {
+        use std::{ffi::CString, os::unix::ffi::OsStrExt};
+        let parent = match file_path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        let Ok(parent) = CString::new(parent.as_os_str().as_bytes()) else {
+            return Ok(());
+        };
+        // SAFETY: `parent` is a valid C string that lives during the call
+        if unsafe { libc::access(parent.as_ptr(), libc::W_OK) } == 0 {
+            return Ok(());
+        }
+        let error = std::io::Error::last_os_error();
+        match error.raw_os_error() {
+            Some(libc::EACCES | libc::EPERM | libc::EROFS) => Err(error),
+            _ => Ok(()),
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        // the read-only attribute of a directory does not prevent renames
+        let _ = file_path;
+        Ok(())
+    }
+}
+
+/// Remove the inputs already contained in another input
+fn remove_overlapping<'a, I>(paths: I) -> Vec<PathBuf>
+where
+    I: IntoIterator<Item = &'a PathBuf>,
+{
+    let mut canonical_paths = paths
+        .into_iter()
+        .map(|one_path| {
+            let canonical = one_path.canonicalize().unwrap_or_else(|_| one_path.clone());
+            (canonical, one_path.clone())
+        })
+        .collect::<Vec<(PathBuf, PathBuf)>>();
+    // a parent is always sorted before its content
+    canonical_paths.sort();
+    let mut kept: Vec<(PathBuf, PathBuf)> = Vec::new();
+    for (canonical, one_path) in canonical_paths {
+        if !kept
+            .iter()
+            .any(|(kept_canonical, _)| canonical.starts_with(kept_canonical))
+        {
+            kept.push((canonical, one_path));
+        }
+    }
+    kept.into_iter().map(|(_, one_path)| one_path).collect()
+}
+
+/// Compute the new name of a path
+fn plan_path(file_path: &Path, options: &NotoxArgs) -> WalkedPath {
+    let Some(file_name) = file_path.file_name() else {
+        return WalkedPath::Done(PathChange::Unchanged {
+            path: file_path.to_path_buf(),
+        });
+    };
+    let (cleaned_name, fallback) = clean_name(file_name, options);
+    if cleaned_name == file_name {
+        return WalkedPath::Done(PathChange::Unchanged {
+            path: file_path.to_path_buf(),
+        });
+    }
+    WalkedPath::Rename(
+        file_path.to_path_buf(),
+        file_path.with_file_name(cleaned_name),
+        fallback,
+    )
+}
+
+/// Compute the new names of a directory and of its content
+fn plan_directory(dir_path: &Path, options: &NotoxArgs) -> Vec<WalkedPath> {
+    let mut result_vec = vec![plan_path(dir_path, options)];
+    match std::fs::read_dir(dir_path) {
+        Ok(entries) => {
+            let mut ok_entries = {
+                #[cfg(feature = "rayon")]
+                {
+                    use std::fs::DirEntry;
+                    let (ok_entries, error_entries): (Vec<_>, Vec<_>) = entries
+                        .collect::<Vec<Result<DirEntry, std::io::Error>>>()
+                        .into_par_iter()
+                        .partition_map(|x| match x {
+                            Ok(entry) => Either::Left(entry),
+                            Err(e) => Either::Right(e),
+                        });
+                    for e in error_entries {
+                        result_vec.push(WalkedPath::Done(PathChange::Error {
+                            path: dir_path.to_path_buf(),
+                            error: NotoxError::read_dir(&e),
+                        }));
+                    }
+                    ok_entries
+                }
+                #[cfg(not(feature = "rayon"))]
+                {
+                    let mut ok_entries = Vec::new();
+                    for entry in entries {
+                        match entry {
+                            Ok(e) => ok_entries.push(e),
+                            Err(e) => result_vec.push(WalkedPath::Done(PathChange::Error {
+                                path: dir_path.to_path_buf(),
+                                error: NotoxError::read_dir(&e),
+                            })),
+                        }
+                    }
+                    ok_entries
+                }
+            };
+            // keep the plan stable between runs
+            ok_entries.sort_by_key(std::fs::DirEntry::path);
+            #[cfg(feature = "rayon")]
+            let iter = ok_entries.par_iter();
+            #[cfg(not(feature = "rayon"))]
+            let iter = ok_entries.iter();
+            let mapped = iter
+                .flat_map(|entry| {
+                    let file_path = entry.path();
+                    let is_entry_directory = match entry.file_type() {
+                        Ok(file_type) => file_type.is_dir(),
+                        Err(_) => false,
+                    };
+                    if is_entry_directory {
+                        plan_directory(&file_path, options)
+                    } else {
+                        vec![plan_path(&file_path, options)]
+                    }
+                })
+                .collect::<Vec<WalkedPath>>();
+            result_vec.extend(mapped);
+        }
+        Err(read_error) => {
+            result_vec.push(WalkedPath::Done(PathChange::Error {
+                path: dir_path.to_path_buf(),
+                error: NotoxError::read_dir(&read_error),
+            }));
+        }
+    }
+    result_vec
+}
+
+/// Walk the paths and compute every rename
+pub(crate) fn plan_paths<'a, I>(paths_to_check: I, options: &NotoxArgs) -> RenamePlan
+where
+    I: IntoIterator<Item = &'a PathBuf>,
+{
+    let inputs = remove_overlapping(paths_to_check);
+    #[cfg(feature = "rayon")]
+    let iter = inputs.par_iter();
+    #[cfg(not(feature = "rayon"))]
+    let iter = inputs.iter();
+    let walked = iter
+        .flat_map(|one_path| {
+            if options.is_verbose() {
+                println!("Checking: {}", one_path.display());
+            }
+            if one_path.is_dir() {
+                plan_directory(one_path, options)
+            } else {
+                vec![plan_path(one_path, options)]
+            }
+        })
+        .collect::<Vec<WalkedPath>>();
+
+    let mut plan = RenamePlan::default();
+    let mut claimed: HashSet<PathBuf> = HashSet::new();
+    for one_walked in walked {
+        match one_walked {
+            WalkedPath::Done(one_change) => plan.results.push(one_change),
+            WalkedPath::Rename(path, modified, fallback) => {
+                plan.push_rename(path, modified, fallback, options.conflict, &mut claimed);
+            }
+        }
+    }
+    plan
+}
+
+/// Build a plan from the renames of a JSON output, parent directories first.
+/// With `failed_only`, only the renames that failed are kept
+pub(crate) fn plan_changes(
+    changes: Vec<PathChange>,
+    options: &NotoxArgs,
+    failed_only: bool,
+) -> RenamePlan {
+    let mut requested = changes
+        .into_iter()
+        .filter_map(|one_change| match one_change {
+            PathChange::Changed {
+                path,
+                modified,
+                fallback,
+            }
+            | PathChange::WouldRename {
+                path,
+                modified,
+                fallback,
+            } if !failed_only => Some((path, modified, fallback)),
+            PathChange::ErrorRename {
+                path,
+                modified,
+                fallback,
+                ..
+            }
+            | PathChange::Conflict {
+                path,
+                modified,
+                fallback,
+            } => Some((path, modified, fallback)),
+            _ => None,
+        })
+        .collect::<Vec<(PathBuf, PathBuf, bool)>>();
+    requested.sort_by_key(|(path, _, _)| path.components().count());
+
+    let mut plan = RenamePlan::default();
+    let mut claimed: HashSet<PathBuf> = HashSet::new();
+    for (path, modified, fallback) in requested {
+        if path == modified {
+            plan.results.push(PathChange::Unchanged { path });
+        } else if path.symlink_metadata().is_err() {
+            plan.results.push(PathChange::Error {
+                path,
+                error: NotoxError::NotFound(IoError::new(
+                    std::io::ErrorKind::NotFound,
+                    SOURCE_ERROR,
+                )),
+            });
+        } else {
+            plan.push_rename(path, modified, fallback, options.conflict, &mut claimed);
+        }
+    }
+    plan
+}
@@ -0,0 +1,228 @@
+//! Romanization of the non-Latin scripts, used before the built-in table
+
+use core::fmt;
+
+#[cfg(feature = "pinyin")]
+use crate::hanzi;
+use crate::{
+    arabic, cyrillic, devanagari, greek, hangul, hebrew, kana, ArabicScheme, CyrillicScheme,
+    PinyinSeparator,
+};
+
+/// Scripts romanized, none by default
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Romanization {
+    /// scheme of the Cyrillic letters, `None` to leave them to the built-in table
+    pub cyrillic: Option<CyrillicScheme>,
+
+    /// romanize the Greek letters (ELOT 743)
+    pub greek: bool,
+
+    /// romanize the hiragana and the katakana (Hepburn)
+    pub kana: bool,
+
+    /// romanize the Hangul syllables (Revised Romanization of Korean)
+    pub hangul: bool,
+
+    /// separator of the pinyin of the Chinese characters, `None` to leave them to the built-in table.
+    /// Only used with the `pinyin` feature
+    pub hanzi: Option<PinyinSeparator>,
+
+    /// scheme of the Arabic letters, `None` to leave them to the built-in table
+    pub arabic: Option<ArabicScheme>,
+
+    /// romanize the Hebrew letters (ALA-LC)
+    pub hebrew: bool,
+
+    /// romanize the Devanagari letters (ISO 15919)
+    pub devanagari: bool,
+}
+
+/// Names of the scripts, for [`Romanization::enable`]
+pub const ROMANIZATION_NAMES: &[&str] = &[
+    "cyrillic",
+    "cyrillic-gost",
+    "greek",
+    "kana",
+    "hangul",
+    "hanzi",
+    "hanzi-dash",
+    "hanzi-underscore",
+    "arabic",
+    "persian",
+    "hebrew",
+    "devanagari",
+];
+
+impl Romanization {
+    /// Every script, with the default schemes
+    #[must_use]
+    pub fn all() -> Self {
+        Romanization {
+            cyrillic: Some(CyrillicScheme::BgnPcgn),
+            greek: true,
+            kana: true,
+            hangul: true,
+            hanzi: Some(PinyinSeparator::Joined),
+            arabic: Some(ArabicScheme::Arabic),
+            hebrew: true,
+            devanagari: true,
+        }
+    }
+
+    /// Enable a script from its name, see [`ROMANIZATION_NAMES`].
+    /// Returns false if the name is unknown
+    pub fn enable(&mut self, name: &str) -> bool {
+        match name {
+            "cyrillic" => self.cyrillic = Some(CyrillicScheme::BgnPcgn),
+            "cyrillic-gost" => self.cyrillic = Some(CyrillicScheme::Gost779B),
+            "greek" => self.greek = true,
+            "kana" => self.kana = true,
+            "hangul" => self.hangul = true,
+            "hanzi" => self.hanzi = Some(PinyinSeparator::Joined),
+            "hanzi-dash" => self.hanzi = Some(PinyinSeparator::Dash),
+            "hanzi-underscore" => self.hanzi = Some(PinyinSeparator::Underscore),
+            "arabic" => self.arabic = Some(ArabicScheme::Arabic),
+            "persian" => self.arabic = Some(ArabicScheme::Persian),
+            "hebrew" => self.hebrew = true,
+            "devanagari" => self.devanagari = true,
+            _ => return false,
+        }
+        true
+    }
+
+    /// Names of the scripts romanized, see [`ROMANIZATION_NAMES`]
+    #[must_use]
+    pub fn names(&self) -> Vec<&'static str> {
+        let mut names = Vec::new();
+        names.extend(self.cyrillic.map(|scheme| match scheme {
+            CyrillicScheme::BgnPcgn => "cyrillic",
+            CyrillicScheme::Gost779B => "cyrillic-gost",
+        }));
+        names.extend(self.greek.then_some("greek"));
+        names.extend(self.kana.then_some("kana"));
+        names.extend(self.hangul.then_some("hangul"));
+        names.extend(self.hanzi.map(|separator| match separator {
+            PinyinSeparator::Joined => "hanzi",
+            PinyinSeparator::Dash => "hanzi-dash",
+            PinyinSeparator::Underscore => "hanzi-underscore",
+        }));
+        names.extend(self.arabic.map(|scheme| match scheme {
+            ArabicScheme::Arabic => "arabic",
+            ArabicScheme::Persian => "persian",
+        }));
+        names.extend(self.hebrew.then_some("hebrew"));
+        names.extend(self.devanagari.then_some("devanagari"));
+        names
+    }
+
+    /// Romanization of a character, knowing the bytes before and after it.
+    /// Returns the romanization and the number of bytes after the character also romanized
+    pub(crate) fn romanize(
+        self,
+        one_char: char,
+        before: &[u8],
+        rest: &[u8],
+    ) -> Option<(String, usize)> {
+        let previous = last_char(before);
+        match one_char {
+            '\u{0400}'..='\u{04FF}' => cyrillic::romanize(one_char, before, rest, self.cyrillic?)
+                .map(|romanized| (romanized, 0)),
+            '\u{0370}'..='\u{03FF}' if self.greek => {
+                greek::romanize(one_char, previous, first_char(rest))
+            }
+            '\u{0590}'..='\u{05FF}' if self.hebrew => {
+                hebrew::romanize(one_char, before, rest).map(|romanized| (romanized, 0))
+            }
+            '\u{0600}'..='\u{06FF}' => arabic::romanize(one_char, before, rest, self.arabic?)
+                .map(|romanized| (romanized, 0)),
+            '\u{0900}'..='\u{097F}' if self.devanagari => {
+                devanagari::romanize(one_char, rest).map(|romanized| (romanized, 0))
+            }
+            _ if self.kana && kana::is_kana(one_char) => kana::romanize(one_char, before, rest),
+            _ if self.hangul && hangul::is_hangul(one_char) => {
+                hangul::romanize(one_char, before, rest)
+            }
+            #[cfg(feature = "pinyin")]
+            '\u{4E00}'..='\u{9FFF}' => {
+                hanzi::romanize(one_char, previous, self.hanzi?).map(|romanized| (romanized, 0))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// First character of the bytes, if they start with a valid one
+pub(crate) fn first_char(bytes: &[u8]) -> Option<char> {
+    (1..=bytes.len().min(4))
+        .find_map(|len| std::str::from_utf8(&bytes[..len]).ok())
+        .and_then(|text| text.chars().next())
+}
+
+/// Last character of the bytes, if they end with a valid one
+pub(crate) fn last_char(bytes: &[u8]) -> Option<char> {
+    (1..=bytes.len().min(4))
+        .find_map(|len| std::str::from_utf8(&bytes[bytes.len() - len..]).ok())
+        .and_then(|text| text.chars().next_back())
+}
+
+/// Characters at the start of the bytes, until an invalid one
+pub(crate) fn chars(bytes: &[u8]) -> impl Iterator<Item = char> + '_ {
+    let mut rest = bytes;
+    std::iter::from_fn(move || {
+        let one_char = first_char(rest)?;
+        rest = &rest[one_char.len_utf8()..];
+        Some(one_char)
+    })
+}
+
+/// Characters at the end of the bytes, from the last one, until an invalid one
+pub(crate) fn chars_back(bytes: &[u8]) -> impl Iterator<Item = char> + '_ {
+    let mut before = bytes;
+    std::iter::from_fn(move || {
+        let one_char = last_char(before)?;
+        before = &before[..before.len() - one_char.len_utf8()];
+        Some(one_char)
+    })
+}
+
+impl fmt::Display for Romanization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.names().join(",").as_str() {
+            "" => write!(f, "none"),
+            names => write!(f, "{names}"),
+        }
+    }
+}
+
+/// Lowercase form of a character, if it has a single one
+pub(crate) fn to_lower(one_char: char) -> char {
+    let mut lowercase = one_char.to_lowercase();
+    match (lowercase.next(), lowercase.next()) {
+        (Some(lower), None) => lower,
+        _ => one_char,
+    }
+}
+
+/// Give the case of the letter to its lowercase romanization:
+/// `Sh` at the start of a word, `SH` in a word in uppercase
+pub(crate) fn apply_case(
+    romanized: &str,
+    is_upper: bool,
+    previous: Option<char>,
+    next: Option<char>,
+) -> String {
+    if !is_upper {
+        return romanized.to_string();
+    }
+    let all_upper = next.is_some_and(char::is_uppercase)
+        || (!next.is_some_and(char::is_lowercase) && previous.is_some_and(char::is_uppercase));
+    if all_upper {
+        return romanized.to_ascii_uppercase();
+    }
+    let mut chars = romanized.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_ascii_uppercase().to_string() + chars.as_str()
+    })
+}
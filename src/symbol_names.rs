@@ -0,0 +1,1610 @@
+//! Names of the symbols, from the Unicode character names, used when a symbol has no mapping
+
+/// Slugified Unicode names of the symbols, sorted by character:
+/// arrows, mathematical operators, technical symbols, shapes, dingbats and pictographs
+#[rustfmt::skip]
+const SYMBOL_NAMES: &[(char, &str)] = &[
+    ('\u{2190}', "leftwards_arrow"), ('\u{2191}', "upwards_arrow"),
+    ('\u{2192}', "rightwards_arrow"), ('\u{2193}', "downwards_arrow"),
+    ('\u{2194}', "left_right_arrow"), ('\u{2195}', "up_down_arrow"),
+    ('\u{2196}', "north_west_arrow"), ('\u{2197}', "north_east_arrow"),
+    ('\u{2198}', "south_east_arrow"), ('\u{2199}', "south_west_arrow"),
+    ('\u{219A}', "leftwards_arrow_with_stroke"), ('\u{219B}', "rightwards_arrow_with_stroke"),
+    ('\u{219C}', "leftwards_wave_arrow"), ('\u{219D}', "rightwards_wave_arrow"),
+    ('\u{219E}', "leftwards_two_headed_arrow"), ('\u{219F}', "upwards_two_headed_arrow"),
+    ('\u{21A0}', "rightwards_two_headed_arrow"), ('\u{21A1}', "downwards_two_headed_arrow"),
+    ('\u{21A2}', "leftwards_arrow_with_tail"), ('\u{21A3}', "rightwards_arrow_with_tail"),
+    ('\u{21A4}', "leftwards_arrow_from_bar"), ('\u{21A5}', "upwards_arrow_from_bar"),
+    ('\u{21A6}', "rightwards_arrow_from_bar"), ('\u{21A7}', "downwards_arrow_from_bar"),
+    ('\u{21A8}', "up_down_arrow_with_base"), ('\u{21A9}', "leftwards_arrow_with_hook"),
+    ('\u{21AA}', "rightwards_arrow_with_hook"), ('\u{21AB}', "leftwards_arrow_with_loop"),
+    ('\u{21AC}', "rightwards_arrow_with_loop"), ('\u{21AD}', "left_right_wave_arrow"),
+    ('\u{21AE}', "left_right_arrow_with_stroke"), ('\u{21AF}', "downwards_zigzag_arrow"),
+    ('\u{21B0}', "upwards_arrow_with_tip_leftwards"),
+    ('\u{21B1}', "upwards_arrow_with_tip_rightwards"),
+    ('\u{21B2}', "downwards_arrow_with_tip_leftwards"),
+    ('\u{21B3}', "downwards_arrow_with_tip_rightwards"),
+    ('\u{21B4}', "rightwards_arrow_with_corner_downwards"),
+    ('\u{21B5}', "downwards_arrow_with_corner_leftwards"),
+    ('\u{21B6}', "anticlockwise_top_semicircle_arrow"),
+    ('\u{21B7}', "clockwise_top_semicircle_arrow"), ('\u{21B8}', "north_west_arrow_to_long_bar"),
+    ('\u{21B9}', "leftwards_arrow_to_bar_over_rightwards_arrow_to_bar"),
+    ('\u{21BA}', "anticlockwise_open_circle_arrow"), ('\u{21BB}', "clockwise_open_circle_arrow"),
+    ('\u{21BC}', "leftwards_harpoon_with_barb_upwards"),
+    ('\u{21BD}', "leftwards_harpoon_with_barb_downwards"),
+    ('\u{21BE}', "upwards_harpoon_with_barb_rightwards"),
+    ('\u{21BF}', "upwards_harpoon_with_barb_leftwards"),
+    ('\u{21C0}', "rightwards_harpoon_with_barb_upwards"),
+    ('\u{21C1}', "rightwards_harpoon_with_barb_downwards"),
+    ('\u{21C2}', "downwards_harpoon_with_barb_rightwards"),
+    ('\u{21C3}', "downwards_harpoon_with_barb_leftwards"),
+    ('\u{21C4}', "rightwards_arrow_over_leftwards_arrow"),
+    ('\u{21C5}', "upwards_arrow_leftwards_of_downwards_arrow"),
+    ('\u{21C6}', "leftwards_arrow_over_rightwards_arrow"), ('\u{21C7}', "leftwards_paired_arrows"),
+    ('\u{21C8}', "upwards_paired_arrows"), ('\u{21C9}', "rightwards_paired_arrows"),
+    ('\u{21CA}', "downwards_paired_arrows"),
+    ('\u{21CB}', "leftwards_harpoon_over_rightwards_harpoon"),
+    ('\u{21CC}', "rightwards_harpoon_over_leftwards_harpoon"),
+    ('\u{21CD}', "leftwards_double_arrow_with_stroke"),
+    ('\u{21CE}', "left_right_double_arrow_with_stroke"),
+    ('\u{21CF}', "rightwards_double_arrow_with_stroke"), ('\u{21D0}', "leftwards_double_arrow"),
+    ('\u{21D1}', "upwards_double_arrow"), ('\u{21D2}', "rightwards_double_arrow"),
+    ('\u{21D3}', "downwards_double_arrow"), ('\u{21D4}', "left_right_double_arrow"),
+    ('\u{21D5}', "up_down_double_arrow"), ('\u{21D6}', "north_west_double_arrow"),
+    ('\u{21D7}', "north_east_double_arrow"), ('\u{21D8}', "south_east_double_arrow"),
+    ('\u{21D9}', "south_west_double_arrow"), ('\u{21DA}', "leftwards_triple_arrow"),
+    ('\u{21DB}', "rightwards_triple_arrow"), ('\u{21DC}', "leftwards_squiggle_arrow"),
+    ('\u{21DD}', "rightwards_squiggle_arrow"), ('\u{21DE}', "upwards_arrow_with_double_stroke"),
+    ('\u{21DF}', "downwards_arrow_with_double_stroke"), ('\u{21E0}', "leftwards_dashed_arrow"),
+    ('\u{21E1}', "upwards_dashed_arrow"), ('\u{21E2}', "rightwards_dashed_arrow"),
+    ('\u{21E3}', "downwards_dashed_arrow"), ('\u{21E4}', "leftwards_arrow_to_bar"),
+    ('\u{21E5}', "rightwards_arrow_to_bar"), ('\u{21E6}', "leftwards_white_arrow"),
+    ('\u{21E7}', "upwards_white_arrow"), ('\u{21E8}', "rightwards_white_arrow"),
+    ('\u{21E9}', "downwards_white_arrow"), ('\u{21EA}', "upwards_white_arrow_from_bar"),
+    ('\u{21EB}', "upwards_white_arrow_on_pedestal"),
+    ('\u{21EC}', "upwards_white_arrow_on_pedestal_with_horizontal_bar"),
+    ('\u{21ED}', "upwards_white_arrow_on_pedestal_with_vertical_bar"),
+    ('\u{21EE}', "upwards_white_double_arrow"),
+    ('\u{21EF}', "upwards_white_double_arrow_on_pedestal"),
+    ('\u{21F0}', "rightwards_white_arrow_from_wall"), ('\u{21F1}', "north_west_arrow_to_corner"),
+    ('\u{21F2}', "south_east_arrow_to_corner"), ('\u{21F3}', "up_down_white_arrow"),
+    ('\u{21F4}', "right_arrow_with_small_circle"),
+    ('\u{21F5}', "downwards_arrow_leftwards_of_upwards_arrow"),
+    ('\u{21F6}', "three_rightwards_arrows"), ('\u{21F7}', "leftwards_arrow_with_vertical_stroke"),
+    ('\u{21F8}', "rightwards_arrow_with_vertical_stroke"),
+    ('\u{21F9}', "left_right_arrow_with_vertical_stroke"),
+    ('\u{21FA}', "leftwards_arrow_with_double_vertical_stroke"),
+    ('\u{21FB}', "rightwards_arrow_with_double_vertical_stroke"),
+    ('\u{21FC}', "left_right_arrow_with_double_vertical_stroke"),
+    ('\u{21FD}', "leftwards_open_headed_arrow"), ('\u{21FE}', "rightwards_open_headed_arrow"),
+    ('\u{21FF}', "left_right_open_headed_arrow"), ('\u{2200}', "for_all"),
+    ('\u{2201}', "complement"), ('\u{2202}', "partial_differential"), ('\u{2203}', "there_exists"),
+    ('\u{2204}', "there_does_not_exist"), ('\u{2205}', "empty_set"), ('\u{2206}', "increment"),
+    ('\u{2207}', "nabla"), ('\u{2208}', "element_of"), ('\u{2209}', "not_an_element_of"),
+    ('\u{220A}', "small_element_of"), ('\u{220B}', "contains_as_member"),
+    ('\u{220C}', "does_not_contain_as_member"), ('\u{220D}', "small_contains_as_member"),
+    ('\u{220E}', "end_of_proof"), ('\u{220F}', "n_ary_product"), ('\u{2210}', "n_ary_coproduct"),
+    ('\u{2211}', "n_ary_summation"), ('\u{2212}', "minus_sign"), ('\u{2213}', "minus_or_plus_sign"),
+    ('\u{2214}', "dot_plus"), ('\u{2215}', "division_slash"), ('\u{2216}', "set_minus"),
+    ('\u{2217}', "asterisk_operator"), ('\u{2218}', "ring_operator"),
+    ('\u{2219}', "bullet_operator"), ('\u{221A}', "square_root"), ('\u{221B}', "cube_root"),
+    ('\u{221C}', "fourth_root"), ('\u{221D}', "proportional_to"), ('\u{221E}', "infinity"),
+    ('\u{221F}', "right_angle"), ('\u{2220}', "angle"), ('\u{2221}', "measured_angle"),
+    ('\u{2222}', "spherical_angle"), ('\u{2223}', "divides"), ('\u{2224}', "does_not_divide"),
+    ('\u{2225}', "parallel_to"), ('\u{2226}', "not_parallel_to"), ('\u{2227}', "logical_and"),
+    ('\u{2228}', "logical_or"), ('\u{2229}', "intersection"), ('\u{222A}', "union"),
+    ('\u{222B}', "integral"), ('\u{222C}', "double_integral"), ('\u{222D}', "triple_integral"),
+    ('\u{222E}', "contour_integral"), ('\u{222F}', "surface_integral"),
+    ('\u{2230}', "volume_integral"), ('\u{2231}', "clockwise_integral"),
+    ('\u{2232}', "clockwise_contour_integral"), ('\u{2233}', "anticlockwise_contour_integral"),
+    ('\u{2234}', "therefore"), ('\u{2235}', "because"), ('\u{2236}', "ratio"),
+    ('\u{2237}', "proportion"), ('\u{2238}', "dot_minus"), ('\u{2239}', "excess"),
+    ('\u{223A}', "geometric_proportion"), ('\u{223B}', "homothetic"),
+    ('\u{223C}', "tilde_operator"), ('\u{223D}', "reversed_tilde"), ('\u{223E}', "inverted_lazy_s"),
+    ('\u{223F}', "sine_wave"), ('\u{2240}', "wreath_product"), ('\u{2241}', "not_tilde"),
+    ('\u{2242}', "minus_tilde"), ('\u{2243}', "asymptotically_equal_to"),
+    ('\u{2244}', "not_asymptotically_equal_to"), ('\u{2245}', "approximately_equal_to"),
+    ('\u{2246}', "approximately_but_not_actually_equal_to"),
+    ('\u{2247}', "neither_approximately_nor_actually_equal_to"), ('\u{2248}', "almost_equal_to"),
+    ('\u{2249}', "not_almost_equal_to"), ('\u{224A}', "almost_equal_or_equal_to"),
+    ('\u{224B}', "triple_tilde"), ('\u{224C}', "all_equal_to"), ('\u{224D}', "equivalent_to"),
+    ('\u{224E}', "geometrically_equivalent_to"), ('\u{224F}', "difference_between"),
+    ('\u{2250}', "approaches_the_limit"), ('\u{2251}', "geometrically_equal_to"),
+    ('\u{2252}', "approximately_equal_to_or_the_image_of"),
+    ('\u{2253}', "image_of_or_approximately_equal_to"), ('\u{2254}', "colon_equals"),
+    ('\u{2255}', "equals_colon"), ('\u{2256}', "ring_in_equal_to"), ('\u{2257}', "ring_equal_to"),
+    ('\u{2258}', "corresponds_to"), ('\u{2259}', "estimates"), ('\u{225A}', "equiangular_to"),
+    ('\u{225B}', "star_equals"), ('\u{225C}', "delta_equal_to"),
+    ('\u{225D}', "equal_to_by_definition"), ('\u{225E}', "measured_by"),
+    ('\u{225F}', "questioned_equal_to"), ('\u{2260}', "not_equal_to"), ('\u{2261}', "identical_to"),
+    ('\u{2262}', "not_identical_to"), ('\u{2263}', "strictly_equivalent_to"),
+    ('\u{2264}', "less_than_or_equal_to"), ('\u{2265}', "greater_than_or_equal_to"),
+    ('\u{2266}', "less_than_over_equal_to"), ('\u{2267}', "greater_than_over_equal_to"),
+    ('\u{2268}', "less_than_but_not_equal_to"), ('\u{2269}', "greater_than_but_not_equal_to"),
+    ('\u{226A}', "much_less_than"), ('\u{226B}', "much_greater_than"), ('\u{226C}', "between"),
+    ('\u{226D}', "not_equivalent_to"), ('\u{226E}', "not_less_than"),
+    ('\u{226F}', "not_greater_than"), ('\u{2270}', "neither_less_than_nor_equal_to"),
+    ('\u{2271}', "neither_greater_than_nor_equal_to"), ('\u{2272}', "less_than_or_equivalent_to"),
+    ('\u{2273}', "greater_than_or_equivalent_to"),
+    ('\u{2274}', "neither_less_than_nor_equivalent_to"),
+    ('\u{2275}', "neither_greater_than_nor_equivalent_to"),
+    ('\u{2276}', "less_than_or_greater_than"), ('\u{2277}', "greater_than_or_less_than"),
+    ('\u{2278}', "neither_less_than_nor_greater_than"),
+    ('\u{2279}', "neither_greater_than_nor_less_than"), ('\u{227A}', "precedes"),
+    ('\u{227B}', "succeeds"), ('\u{227C}', "precedes_or_equal_to"),
+    ('\u{227D}', "succeeds_or_equal_to"), ('\u{227E}', "precedes_or_equivalent_to"),
+    ('\u{227F}', "succeeds_or_equivalent_to"), ('\u{2280}', "does_not_precede"),
+    ('\u{2281}', "does_not_succeed"), ('\u{2282}', "subset_of"), ('\u{2283}', "superset_of"),
+    ('\u{2284}', "not_a_subset_of"), ('\u{2285}', "not_a_superset_of"),
+    ('\u{2286}', "subset_of_or_equal_to"), ('\u{2287}', "superset_of_or_equal_to"),
+    ('\u{2288}', "neither_a_subset_of_nor_equal_to"),
+    ('\u{2289}', "neither_a_superset_of_nor_equal_to"), ('\u{228A}', "subset_of_with_not_equal_to"),
+    ('\u{228B}', "superset_of_with_not_equal_to"), ('\u{228C}', "multiset"),
+    ('\u{228D}', "multiset_multiplication"), ('\u{228E}', "multiset_union"),
+    ('\u{228F}', "square_image_of"), ('\u{2290}', "square_original_of"),
+    ('\u{2291}', "square_image_of_or_equal_to"), ('\u{2292}', "square_original_of_or_equal_to"),
+    ('\u{2293}', "square_cap"), ('\u{2294}', "square_cup"), ('\u{2295}', "circled_plus"),
+    ('\u{2296}', "circled_minus"), ('\u{2297}', "circled_times"),
+    ('\u{2298}', "circled_division_slash"), ('\u{2299}', "circled_dot_operator"),
+    ('\u{229A}', "circled_ring_operator"), ('\u{229B}', "circled_asterisk_operator"),
+    ('\u{229C}', "circled_equals"), ('\u{229D}', "circled_dash"), ('\u{229E}', "squared_plus"),
+    ('\u{229F}', "squared_minus"), ('\u{22A0}', "squared_times"),
+    ('\u{22A1}', "squared_dot_operator"), ('\u{22A2}', "right_tack"), ('\u{22A3}', "left_tack"),
+    ('\u{22A4}', "down_tack"), ('\u{22A5}', "up_tack"), ('\u{22A6}', "assertion"),
+    ('\u{22A7}', "models"), ('\u{22A8}', "true"), ('\u{22A9}', "forces"),
+    ('\u{22AA}', "triple_vertical_bar_right_turnstile"),
+    ('\u{22AB}', "double_vertical_bar_double_right_turnstile"), ('\u{22AC}', "does_not_prove"),
+    ('\u{22AD}', "not_true"), ('\u{22AE}', "does_not_force"),
+    ('\u{22AF}', "negated_double_vertical_bar_double_right_turnstile"),
+    ('\u{22B0}', "precedes_under_relation"), ('\u{22B1}', "succeeds_under_relation"),
+    ('\u{22B2}', "normal_subgroup_of"), ('\u{22B3}', "contains_as_normal_subgroup"),
+    ('\u{22B4}', "normal_subgroup_of_or_equal_to"),
+    ('\u{22B5}', "contains_as_normal_subgroup_or_equal_to"), ('\u{22B6}', "original_of"),
+    ('\u{22B7}', "image_of"), ('\u{22B8}', "multimap"), ('\u{22B9}', "hermitian_conjugate_matrix"),
+    ('\u{22BA}', "intercalate"), ('\u{22BB}', "xor"), ('\u{22BC}', "nand"), ('\u{22BD}', "nor"),
+    ('\u{22BE}', "right_angle_with_arc"), ('\u{22BF}', "right_triangle"),
+    ('\u{22C0}', "n_ary_logical_and"), ('\u{22C1}', "n_ary_logical_or"),
+    ('\u{22C2}', "n_ary_intersection"), ('\u{22C3}', "n_ary_union"),
+    ('\u{22C4}', "diamond_operator"), ('\u{22C5}', "dot_operator"), ('\u{22C6}', "star_operator"),
+    ('\u{22C7}', "division_times"), ('\u{22C8}', "bowtie"),
+    ('\u{22C9}', "left_normal_factor_semidirect_product"),
+    ('\u{22CA}', "right_normal_factor_semidirect_product"), ('\u{22CB}', "left_semidirect_product"),
+    ('\u{22CC}', "right_semidirect_product"), ('\u{22CD}', "reversed_tilde_equals"),
+    ('\u{22CE}', "curly_logical_or"), ('\u{22CF}', "curly_logical_and"),
+    ('\u{22D0}', "double_subset"), ('\u{22D1}', "double_superset"),
+    ('\u{22D2}', "double_intersection"), ('\u{22D3}', "double_union"), ('\u{22D4}', "pitchfork"),
+    ('\u{22D5}', "equal_and_parallel_to"), ('\u{22D6}', "less_than_with_dot"),
+    ('\u{22D7}', "greater_than_with_dot"), ('\u{22D8}', "very_much_less_than"),
+    ('\u{22D9}', "very_much_greater_than"), ('\u{22DA}', "less_than_equal_to_or_greater_than"),
+    ('\u{22DB}', "greater_than_equal_to_or_less_than"), ('\u{22DC}', "equal_to_or_less_than"),
+    ('\u{22DD}', "equal_to_or_greater_than"), ('\u{22DE}', "equal_to_or_precedes"),
+    ('\u{22DF}', "equal_to_or_succeeds"), ('\u{22E0}', "does_not_precede_or_equal"),
+    ('\u{22E1}', "does_not_succeed_or_equal"), ('\u{22E2}', "not_square_image_of_or_equal_to"),
+    ('\u{22E3}', "not_square_original_of_or_equal_to"),
+    ('\u{22E4}', "square_image_of_or_not_equal_to"),
+    ('\u{22E5}', "square_original_of_or_not_equal_to"),
+    ('\u{22E6}', "less_than_but_not_equivalent_to"),
+    ('\u{22E7}', "greater_than_but_not_equivalent_to"),
+    ('\u{22E8}', "precedes_but_not_equivalent_to"), ('\u{22E9}', "succeeds_but_not_equivalent_to"),
+    ('\u{22EA}', "not_normal_subgroup_of"), ('\u{22EB}', "does_not_contain_as_normal_subgroup"),
+    ('\u{22EC}', "not_normal_subgroup_of_or_equal_to"),
+    ('\u{22ED}', "does_not_contain_as_normal_subgroup_or_equal"), ('\u{22EE}', "vertical_ellipsis"),
+    ('\u{22EF}', "midline_horizontal_ellipsis"), ('\u{22F0}', "up_right_diagonal_ellipsis"),
+    ('\u{22F1}', "down_right_diagonal_ellipsis"),
+    ('\u{22F2}', "element_of_with_long_horizontal_stroke"),
+    ('\u{22F3}', "element_of_with_vertical_bar_at_end_of_horizontal_stroke"),
+    ('\u{22F4}', "small_element_of_with_vertical_bar_at_end_of_horizontal_stroke"),
+    ('\u{22F5}', "element_of_with_dot_above"), ('\u{22F6}', "element_of_with_overbar"),
+    ('\u{22F7}', "small_element_of_with_overbar"), ('\u{22F8}', "element_of_with_underbar"),
+    ('\u{22F9}', "element_of_with_two_horizontal_strokes"),
+    ('\u{22FA}', "contains_with_long_horizontal_stroke"),
+    ('\u{22FB}', "contains_with_vertical_bar_at_end_of_horizontal_stroke"),
+    ('\u{22FC}', "small_contains_with_vertical_bar_at_end_of_horizontal_stroke"),
+    ('\u{22FD}', "contains_with_overbar"), ('\u{22FE}', "small_contains_with_overbar"),
+    ('\u{22FF}', "z_notation_bag_membership"), ('\u{2300}', "diameter_sign"),
+    ('\u{2301}', "electric_arrow"), ('\u{2302}', "house"), ('\u{2303}', "up_arrowhead"),
+    ('\u{2304}', "down_arrowhead"), ('\u{2305}', "projective"), ('\u{2306}', "perspective"),
+    ('\u{2307}', "wavy_line"), ('\u{230C}', "bottom_right_crop"), ('\u{230D}', "bottom_left_crop"),
+    ('\u{230E}', "top_right_crop"), ('\u{230F}', "top_left_crop"),
+    ('\u{2310}', "reversed_not_sign"), ('\u{2311}', "square_lozenge"), ('\u{2312}', "arc"),
+    ('\u{2313}', "segment"), ('\u{2314}', "sector"), ('\u{2315}', "telephone_recorder"),
+    ('\u{2316}', "position_indicator"), ('\u{2317}', "viewdata_square"),
+    ('\u{2318}', "place_of_interest_sign"), ('\u{2319}', "turned_not_sign"), ('\u{231A}', "watch"),
+    ('\u{231B}', "hourglass"), ('\u{231C}', "top_left_corner"), ('\u{231D}', "top_right_corner"),
+    ('\u{231E}', "bottom_left_corner"), ('\u{231F}', "bottom_right_corner"),
+    ('\u{2320}', "top_half_integral"), ('\u{2321}', "bottom_half_integral"), ('\u{2322}', "frown"),
+    ('\u{2323}', "smile"), ('\u{2324}', "up_arrowhead_between_two_horizontal_bars"),
+    ('\u{2325}', "option_key"), ('\u{2326}', "erase_to_the_right"),
+    ('\u{2327}', "x_in_a_rectangle_box"), ('\u{2328}', "keyboard"),
+    ('\u{232B}', "erase_to_the_left"), ('\u{232C}', "benzene_ring"), ('\u{232D}', "cylindricity"),
+    ('\u{232E}', "all_around_profile"), ('\u{232F}', "symmetry"), ('\u{2330}', "total_runout"),
+    ('\u{2331}', "dimension_origin"), ('\u{2332}', "conical_taper"), ('\u{2333}', "slope"),
+    ('\u{2334}', "counterbore"), ('\u{2335}', "countersink"),
+    ('\u{2336}', "apl_functional_symbol_i_beam"), ('\u{2337}', "apl_functional_symbol_squish_quad"),
+    ('\u{2338}', "apl_functional_symbol_quad_equal"),
+    ('\u{2339}', "apl_functional_symbol_quad_divide"),
+    ('\u{233A}', "apl_functional_symbol_quad_diamond"),
+    ('\u{233B}', "apl_functional_symbol_quad_jot"),
+    ('\u{233C}', "apl_functional_symbol_quad_circle"),
+    ('\u{233D}', "apl_functional_symbol_circle_stile"),
+    ('\u{233E}', "apl_functional_symbol_circle_jot"),
+    ('\u{233F}', "apl_functional_symbol_slash_bar"),
+    ('\u{2340}', "apl_functional_symbol_backslash_bar"),
+    ('\u{2341}', "apl_functional_symbol_quad_slash"),
+    ('\u{2342}', "apl_functional_symbol_quad_backslash"),
+    ('\u{2343}', "apl_functional_symbol_quad_less_than"),
+    ('\u{2344}', "apl_functional_symbol_quad_greater_than"),
+    ('\u{2345}', "apl_functional_symbol_leftwards_vane"),
+    ('\u{2346}', "apl_functional_symbol_rightwards_vane"),
+    ('\u{2347}', "apl_functional_symbol_quad_leftwards_arrow"),
+    ('\u{2348}', "apl_functional_symbol_quad_rightwards_arrow"),
+    ('\u{2349}', "apl_functional_symbol_circle_backslash"),
+    ('\u{234A}', "apl_functional_symbol_down_tack_underbar"),
+    ('\u{234B}', "apl_functional_symbol_delta_stile"),
+    ('\u{234C}', "apl_functional_symbol_quad_down_caret"),
+    ('\u{234D}', "apl_functional_symbol_quad_delta"),
+    ('\u{234E}', "apl_functional_symbol_down_tack_jot"),
+    ('\u{234F}', "apl_functional_symbol_upwards_vane"),
+    ('\u{2350}', "apl_functional_symbol_quad_upwards_arrow"),
+    ('\u{2351}', "apl_functional_symbol_up_tack_overbar"),
+    ('\u{2352}', "apl_functional_symbol_del_stile"),
+    ('\u{2353}', "apl_functional_symbol_quad_up_caret"),
+    ('\u{2354}', "apl_functional_symbol_quad_del"),
+    ('\u{2355}', "apl_functional_symbol_up_tack_jot"),
+    ('\u{2356}', "apl_functional_symbol_downwards_vane"),
+    ('\u{2357}', "apl_functional_symbol_quad_downwards_arrow"),
+    ('\u{2358}', "apl_functional_symbol_quote_underbar"),
+    ('\u{2359}', "apl_functional_symbol_delta_underbar"),
+    ('\u{235A}', "apl_functional_symbol_diamond_underbar"),
+    ('\u{235B}', "apl_functional_symbol_jot_underbar"),
+    ('\u{235C}', "apl_functional_symbol_circle_underbar"),
+    ('\u{235D}', "apl_functional_symbol_up_shoe_jot"),
+    ('\u{235E}', "apl_functional_symbol_quote_quad"),
+    ('\u{235F}', "apl_functional_symbol_circle_star"),
+    ('\u{2360}', "apl_functional_symbol_quad_colon"),
+    ('\u{2361}', "apl_functional_symbol_up_tack_diaeresis"),
+    ('\u{2362}', "apl_functional_symbol_del_diaeresis"),
+    ('\u{2363}', "apl_functional_symbol_star_diaeresis"),
+    ('\u{2364}', "apl_functional_symbol_jot_diaeresis"),
+    ('\u{2365}', "apl_functional_symbol_circle_diaeresis"),
+    ('\u{2366}', "apl_functional_symbol_down_shoe_stile"),
+    ('\u{2367}', "apl_functional_symbol_left_shoe_stile"),
+    ('\u{2368}', "apl_functional_symbol_tilde_diaeresis"),
+    ('\u{2369}', "apl_functional_symbol_greater_than_diaeresis"),
+    ('\u{236A}', "apl_functional_symbol_comma_bar"),
+    ('\u{236B}', "apl_functional_symbol_del_tilde"), ('\u{236C}', "apl_functional_symbol_zilde"),
+    ('\u{236D}', "apl_functional_symbol_stile_tilde"),
+    ('\u{236E}', "apl_functional_symbol_semicolon_underbar"),
+    ('\u{236F}', "apl_functional_symbol_quad_not_equal"),
+    ('\u{2370}', "apl_functional_symbol_quad_question"),
+    ('\u{2371}', "apl_functional_symbol_down_caret_tilde"),
+    ('\u{2372}', "apl_functional_symbol_up_caret_tilde"),
+    ('\u{2373}', "apl_functional_symbol_iota"), ('\u{2374}', "apl_functional_symbol_rho"),
+    ('\u{2375}', "apl_functional_symbol_omega"),
+    ('\u{2376}', "apl_functional_symbol_alpha_underbar"),
+    ('\u{2377}', "apl_functional_symbol_epsilon_underbar"),
+    ('\u{2378}', "apl_functional_symbol_iota_underbar"),
+    ('\u{2379}', "apl_functional_symbol_omega_underbar"),
+    ('\u{237A}', "apl_functional_symbol_alpha"), ('\u{237B}', "not_check_mark"),
+    ('\u{237C}', "right_angle_with_downwards_zigzag_arrow"), ('\u{237D}', "shouldered_open_box"),
+    ('\u{237E}', "bell_symbol"), ('\u{237F}', "vertical_line_with_middle_dot"),
+    ('\u{2380}', "insertion_symbol"), ('\u{2381}', "continuous_underline_symbol"),
+    ('\u{2382}', "discontinuous_underline_symbol"), ('\u{2383}', "emphasis_symbol"),
+    ('\u{2384}', "composition_symbol"), ('\u{2385}', "white_square_with_centre_vertical_line"),
+    ('\u{2386}', "enter_symbol"), ('\u{2387}', "alternative_key_symbol"),
+    ('\u{2388}', "helm_symbol"), ('\u{2389}', "circled_horizontal_bar_with_notch"),
+    ('\u{238A}', "circled_triangle_down"), ('\u{238B}', "broken_circle_with_northwest_arrow"),
+    ('\u{238C}', "undo_symbol"), ('\u{238D}', "monostable_symbol"),
+    ('\u{238E}', "hysteresis_symbol"), ('\u{238F}', "open_circuit_output_h_type_symbol"),
+    ('\u{2390}', "open_circuit_output_l_type_symbol"),
+    ('\u{2391}', "passive_pull_down_output_symbol"), ('\u{2392}', "passive_pull_up_output_symbol"),
+    ('\u{2393}', "direct_current_symbol_form_two"), ('\u{2394}', "software_function_symbol"),
+    ('\u{2395}', "apl_functional_symbol_quad"), ('\u{2396}', "decimal_separator_key_symbol"),
+    ('\u{2397}', "previous_page"), ('\u{2398}', "next_page"), ('\u{2399}', "print_screen_symbol"),
+    ('\u{239A}', "clear_screen_symbol"), ('\u{239B}', "left_parenthesis_upper_hook"),
+    ('\u{239C}', "left_parenthesis_extension"), ('\u{239D}', "left_parenthesis_lower_hook"),
+    ('\u{239E}', "right_parenthesis_upper_hook"), ('\u{239F}', "right_parenthesis_extension"),
+    ('\u{23A0}', "right_parenthesis_lower_hook"), ('\u{23A1}', "left_square_bracket_upper_corner"),
+    ('\u{23A2}', "left_square_bracket_extension"), ('\u{23A3}', "left_square_bracket_lower_corner"),
+    ('\u{23A4}', "right_square_bracket_upper_corner"),
+    ('\u{23A5}', "right_square_bracket_extension"),
+    ('\u{23A6}', "right_square_bracket_lower_corner"),
+    ('\u{23A7}', "left_curly_bracket_upper_hook"), ('\u{23A8}', "left_curly_bracket_middle_piece"),
+    ('\u{23A9}', "left_curly_bracket_lower_hook"), ('\u{23AA}', "curly_bracket_extension"),
+    ('\u{23AB}', "right_curly_bracket_upper_hook"),
+    ('\u{23AC}', "right_curly_bracket_middle_piece"),
+    ('\u{23AD}', "right_curly_bracket_lower_hook"), ('\u{23AE}', "integral_extension"),
+    ('\u{23AF}', "horizontal_line_extension"),
+    ('\u{23B0}', "upper_left_or_lower_right_curly_bracket_section"),
+    ('\u{23B1}', "upper_right_or_lower_left_curly_bracket_section"), ('\u{23B2}', "summation_top"),
+    ('\u{23B3}', "summation_bottom"), ('\u{23B4}', "top_square_bracket"),
+    ('\u{23B5}', "bottom_square_bracket"),
+    ('\u{23B6}', "bottom_square_bracket_over_top_square_bracket"),
+    ('\u{23B7}', "radical_symbol_bottom"), ('\u{23B8}', "left_vertical_box_line"),
+    ('\u{23B9}', "right_vertical_box_line"), ('\u{23BA}', "horizontal_scan_line_1"),
+    ('\u{23BB}', "horizontal_scan_line_3"), ('\u{23BC}', "horizontal_scan_line_7"),
+    ('\u{23BD}', "horizontal_scan_line_9"),
+    ('\u{23BE}', "dentistry_symbol_light_vertical_and_top_right"),
+    ('\u{23BF}', "dentistry_symbol_light_vertical_and_bottom_right"),
+    ('\u{23C0}', "dentistry_symbol_light_vertical_with_circle"),
+    ('\u{23C1}', "dentistry_symbol_light_down_and_horizontal_with_circle"),
+    ('\u{23C2}', "dentistry_symbol_light_up_and_horizontal_with_circle"),
+    ('\u{23C3}', "dentistry_symbol_light_vertical_with_triangle"),
+    ('\u{23C4}', "dentistry_symbol_light_down_and_horizontal_with_triangle"),
+    ('\u{23C5}', "dentistry_symbol_light_up_and_horizontal_with_triangle"),
+    ('\u{23C6}', "dentistry_symbol_light_vertical_and_wave"),
+    ('\u{23C7}', "dentistry_symbol_light_down_and_horizontal_with_wave"),
+    ('\u{23C8}', "dentistry_symbol_light_up_and_horizontal_with_wave"),
+    ('\u{23C9}', "dentistry_symbol_light_down_and_horizontal"),
+    ('\u{23CA}', "dentistry_symbol_light_up_and_horizontal"),
+    ('\u{23CB}', "dentistry_symbol_light_vertical_and_top_left"),
+    ('\u{23CC}', "dentistry_symbol_light_vertical_and_bottom_left"), ('\u{23CD}', "square_foot"),
+    ('\u{23CE}', "return_symbol"), ('\u{23CF}', "eject_symbol"),
+    ('\u{23D0}', "vertical_line_extension"), ('\u{23D1}', "metrical_breve"),
+    ('\u{23D2}', "metrical_long_over_short"), ('\u{23D3}', "metrical_short_over_long"),
+    ('\u{23D4}', "metrical_long_over_two_shorts"), ('\u{23D5}', "metrical_two_shorts_over_long"),
+    ('\u{23D6}', "metrical_two_shorts_joined"), ('\u{23D7}', "metrical_triseme"),
+    ('\u{23D8}', "metrical_tetraseme"), ('\u{23D9}', "metrical_pentaseme"),
+    ('\u{23DA}', "earth_ground"), ('\u{23DB}', "fuse"), ('\u{23DC}', "top_parenthesis"),
+    ('\u{23DD}', "bottom_parenthesis"), ('\u{23DE}', "top_curly_bracket"),
+    ('\u{23DF}', "bottom_curly_bracket"), ('\u{23E0}', "top_tortoise_shell_bracket"),
+    ('\u{23E1}', "bottom_tortoise_shell_bracket"), ('\u{23E2}', "white_trapezium"),
+    ('\u{23E3}', "benzene_ring_with_circle"), ('\u{23E4}', "straightness"),
+    ('\u{23E5}', "flatness"), ('\u{23E6}', "ac_current"), ('\u{23E7}', "electrical_intersection"),
+    ('\u{23E8}', "decimal_exponent_symbol"), ('\u{23E9}', "black_right_pointing_double_triangle"),
+    ('\u{23EA}', "black_left_pointing_double_triangle"),
+    ('\u{23EB}', "black_up_pointing_double_triangle"),
+    ('\u{23EC}', "black_down_pointing_double_triangle"),
+    ('\u{23ED}', "black_right_pointing_double_triangle_with_vertical_bar"),
+    ('\u{23EE}', "black_left_pointing_double_triangle_with_vertical_bar"),
+    ('\u{23EF}', "black_right_pointing_triangle_with_double_vertical_bar"),
+    ('\u{23F0}', "alarm_clock"), ('\u{23F1}', "stopwatch"), ('\u{23F2}', "timer_clock"),
+    ('\u{23F3}', "hourglass_with_flowing_sand"),
+    ('\u{23F4}', "black_medium_left_pointing_triangle"),
+    ('\u{23F5}', "black_medium_right_pointing_triangle"),
+    ('\u{23F6}', "black_medium_up_pointing_triangle"),
+    ('\u{23F7}', "black_medium_down_pointing_triangle"), ('\u{23F8}', "double_vertical_bar"),
+    ('\u{23F9}', "black_square_for_stop"), ('\u{23FA}', "black_circle_for_record"),
+    ('\u{23FB}', "power_symbol"), ('\u{23FC}', "power_on_off_symbol"),
+    ('\u{23FD}', "power_on_symbol"), ('\u{23FE}', "power_sleep_symbol"),
+    ('\u{23FF}', "observer_eye_symbol"), ('\u{2500}', "box_drawings_light_horizontal"),
+    ('\u{2501}', "box_drawings_heavy_horizontal"), ('\u{2502}', "box_drawings_light_vertical"),
+    ('\u{2503}', "box_drawings_heavy_vertical"),
+    ('\u{2504}', "box_drawings_light_triple_dash_horizontal"),
+    ('\u{2505}', "box_drawings_heavy_triple_dash_horizontal"),
+    ('\u{2506}', "box_drawings_light_triple_dash_vertical"),
+    ('\u{2507}', "box_drawings_heavy_triple_dash_vertical"),
+    ('\u{2508}', "box_drawings_light_quadruple_dash_horizontal"),
+    ('\u{2509}', "box_drawings_heavy_quadruple_dash_horizontal"),
+    ('\u{250A}', "box_drawings_light_quadruple_dash_vertical"),
+    ('\u{250B}', "box_drawings_heavy_quadruple_dash_vertical"),
+    ('\u{250C}', "box_drawings_light_down_and_right"),
+    ('\u{250D}', "box_drawings_down_light_and_right_heavy"),
+    ('\u{250E}', "box_drawings_down_heavy_and_right_light"),
+    ('\u{250F}', "box_drawings_heavy_down_and_right"),
+    ('\u{2510}', "box_drawings_light_down_and_left"),
+    ('\u{2511}', "box_drawings_down_light_and_left_heavy"),
+    ('\u{2512}', "box_drawings_down_heavy_and_left_light"),
+    ('\u{2513}', "box_drawings_heavy_down_and_left"),
+    ('\u{2514}', "box_drawings_light_up_and_right"),
+    ('\u{2515}', "box_drawings_up_light_and_right_heavy"),
+    ('\u{2516}', "box_drawings_up_heavy_and_right_light"),
+    ('\u{2517}', "box_drawings_heavy_up_and_right"), ('\u{2518}', "box_drawings_light_up_and_left"),
+    ('\u{2519}', "box_drawings_up_light_and_left_heavy"),
+    ('\u{251A}', "box_drawings_up_heavy_and_left_light"),
+    ('\u{251B}', "box_drawings_heavy_up_and_left"),
+    ('\u{251C}', "box_drawings_light_vertical_and_right"),
+    ('\u{251D}', "box_drawings_vertical_light_and_right_heavy"),
+    ('\u{251E}', "box_drawings_up_heavy_and_right_down_light"),
+    ('\u{251F}', "box_drawings_down_heavy_and_right_up_light"),
+    ('\u{2520}', "box_drawings_vertical_heavy_and_right_light"),
+    ('\u{2521}', "box_drawings_down_light_and_right_up_heavy"),
+    ('\u{2522}', "box_drawings_up_light_and_right_down_heavy"),
+    ('\u{2523}', "box_drawings_heavy_vertical_and_right"),
+    ('\u{2524}', "box_drawings_light_vertical_and_left"),
+    ('\u{2525}', "box_drawings_vertical_light_and_left_heavy"),
+    ('\u{2526}', "box_drawings_up_heavy_and_left_down_light"),
+    ('\u{2527}', "box_drawings_down_heavy_and_left_up_light"),
+    ('\u{2528}', "box_drawings_vertical_heavy_and_left_light"),
+    ('\u{2529}', "box_drawings_down_light_and_left_up_heavy"),
+    ('\u{252A}', "box_drawings_up_light_and_left_down_heavy"),
+    ('\u{252B}', "box_drawings_heavy_vertical_and_left"),
+    ('\u{252C}', "box_drawings_light_down_and_horizontal"),
+    ('\u{252D}', "box_drawings_left_heavy_and_right_down_light"),
+    ('\u{252E}', "box_drawings_right_heavy_and_left_down_light"),
+    ('\u{252F}', "box_drawings_down_light_and_horizontal_heavy"),
+    ('\u{2530}', "box_drawings_down_heavy_and_horizontal_light"),
+    ('\u{2531}', "box_drawings_right_light_and_left_down_heavy"),
+    ('\u{2532}', "box_drawings_left_light_and_right_down_heavy"),
+    ('\u{2533}', "box_drawings_heavy_down_and_horizontal"),
+    ('\u{2534}', "box_drawings_light_up_and_horizontal"),
+    ('\u{2535}', "box_drawings_left_heavy_and_right_up_light"),
+    ('\u{2536}', "box_drawings_right_heavy_and_left_up_light"),
+    ('\u{2537}', "box_drawings_up_light_and_horizontal_heavy"),
+    ('\u{2538}', "box_drawings_up_heavy_and_horizontal_light"),
+    ('\u{2539}', "box_drawings_right_light_and_left_up_heavy"),
+    ('\u{253A}', "box_drawings_left_light_and_right_up_heavy"),
+    ('\u{253B}', "box_drawings_heavy_up_and_horizontal"),
+    ('\u{253C}', "box_drawings_light_vertical_and_horizontal"),
+    ('\u{253D}', "box_drawings_left_heavy_and_right_vertical_light"),
+    ('\u{253E}', "box_drawings_right_heavy_and_left_vertical_light"),
+    ('\u{253F}', "box_drawings_vertical_light_and_horizontal_heavy"),
+    ('\u{2540}', "box_drawings_up_heavy_and_down_horizontal_light"),
+    ('\u{2541}', "box_drawings_down_heavy_and_up_horizontal_light"),
+    ('\u{2542}', "box_drawings_vertical_heavy_and_horizontal_light"),
+    ('\u{2543}', "box_drawings_left_up_heavy_and_right_down_light"),
+    ('\u{2544}', "box_drawings_right_up_heavy_and_left_down_light"),
+    ('\u{2545}', "box_drawings_left_down_heavy_and_right_up_light"),
+    ('\u{2546}', "box_drawings_right_down_heavy_and_left_up_light"),
+    ('\u{2547}', "box_drawings_down_light_and_up_horizontal_heavy"),
+    ('\u{2548}', "box_drawings_up_light_and_down_horizontal_heavy"),
+    ('\u{2549}', "box_drawings_right_light_and_left_vertical_heavy"),
+    ('\u{254A}', "box_drawings_left_light_and_right_vertical_heavy"),
+    ('\u{254B}', "box_drawings_heavy_vertical_and_horizontal"),
+    ('\u{254C}', "box_drawings_light_double_dash_horizontal"),
+    ('\u{254D}', "box_drawings_heavy_double_dash_horizontal"),
+    ('\u{254E}', "box_drawings_light_double_dash_vertical"),
+    ('\u{254F}', "box_drawings_heavy_double_dash_vertical"),
+    ('\u{2550}', "box_drawings_double_horizontal"), ('\u{2551}', "box_drawings_double_vertical"),
+    ('\u{2552}', "box_drawings_down_single_and_right_double"),
+    ('\u{2553}', "box_drawings_down_double_and_right_single"),
+    ('\u{2554}', "box_drawings_double_down_and_right"),
+    ('\u{2555}', "box_drawings_down_single_and_left_double"),
+    ('\u{2556}', "box_drawings_down_double_and_left_single"),
+    ('\u{2557}', "box_drawings_double_down_and_left"),
+    ('\u{2558}', "box_drawings_up_single_and_right_double"),
+    ('\u{2559}', "box_drawings_up_double_and_right_single"),
+    ('\u{255A}', "box_drawings_double_up_and_right"),
+    ('\u{255B}', "box_drawings_up_single_and_left_double"),
+    ('\u{255C}', "box_drawings_up_double_and_left_single"),
+    ('\u{255D}', "box_drawings_double_up_and_left"),
+    ('\u{255E}', "box_drawings_vertical_single_and_right_double"),
+    ('\u{255F}', "box_drawings_vertical_double_and_right_single"),
+    ('\u{2560}', "box_drawings_double_vertical_and_right"),
+    ('\u{2561}', "box_drawings_vertical_single_and_left_double"),
+    ('\u{2562}', "box_drawings_vertical_double_and_left_single"),
+    ('\u{2563}', "box_drawings_double_vertical_and_left"),
+    ('\u{2564}', "box_drawings_down_single_and_horizontal_double"),
+    ('\u{2565}', "box_drawings_down_double_and_horizontal_single"),
+    ('\u{2566}', "box_drawings_double_down_and_horizontal"),
+    ('\u{2567}', "box_drawings_up_single_and_horizontal_double"),
+    ('\u{2568}', "box_drawings_up_double_and_horizontal_single"),
+    ('\u{2569}', "box_drawings_double_up_and_horizontal"),
+    ('\u{256A}', "box_drawings_vertical_single_and_horizontal_double"),
+    ('\u{256B}', "box_drawings_vertical_double_and_horizontal_single"),
+    ('\u{256C}', "box_drawings_double_vertical_and_horizontal"),
+    ('\u{256D}', "box_drawings_light_arc_down_and_right"),
+    ('\u{256E}', "box_drawings_light_arc_down_and_left"),
+    ('\u{256F}', "box_drawings_light_arc_up_and_left"),
+    ('\u{2570}', "box_drawings_light_arc_up_and_right"),
+    ('\u{2571}', "box_drawings_light_diagonal_upper_right_to_lower_left"),
+    ('\u{2572}', "box_drawings_light_diagonal_upper_left_to_lower_right"),
+    ('\u{2573}', "box_drawings_light_diagonal_cross"), ('\u{2574}', "box_drawings_light_left"),
+    ('\u{2575}', "box_drawings_light_up"), ('\u{2576}', "box_drawings_light_right"),
+    ('\u{2577}', "box_drawings_light_down"), ('\u{2578}', "box_drawings_heavy_left"),
+    ('\u{2579}', "box_drawings_heavy_up"), ('\u{257A}', "box_drawings_heavy_right"),
+    ('\u{257B}', "box_drawings_heavy_down"),
+    ('\u{257C}', "box_drawings_light_left_and_heavy_right"),
+    ('\u{257D}', "box_drawings_light_up_and_heavy_down"),
+    ('\u{257E}', "box_drawings_heavy_left_and_light_right"),
+    ('\u{257F}', "box_drawings_heavy_up_and_light_down"), ('\u{2580}', "upper_half_block"),
+    ('\u{2581}', "lower_one_eighth_block"), ('\u{2582}', "lower_one_quarter_block"),
+    ('\u{2583}', "lower_three_eighths_block"), ('\u{2584}', "lower_half_block"),
+    ('\u{2585}', "lower_five_eighths_block"), ('\u{2586}', "lower_three_quarters_block"),
+    ('\u{2587}', "lower_seven_eighths_block"), ('\u{2588}', "full_block"),
+    ('\u{2589}', "left_seven_eighths_block"), ('\u{258A}', "left_three_quarters_block"),
+    ('\u{258B}', "left_five_eighths_block"), ('\u{258C}', "left_half_block"),
+    ('\u{258D}', "left_three_eighths_block"), ('\u{258E}', "left_one_quarter_block"),
+    ('\u{258F}', "left_one_eighth_block"), ('\u{2590}', "right_half_block"),
+    ('\u{2591}', "light_shade"), ('\u{2592}', "medium_shade"), ('\u{2593}', "dark_shade"),
+    ('\u{2594}', "upper_one_eighth_block"), ('\u{2595}', "right_one_eighth_block"),
+    ('\u{2596}', "quadrant_lower_left"), ('\u{2597}', "quadrant_lower_right"),
+    ('\u{2598}', "quadrant_upper_left"),
+    ('\u{2599}', "quadrant_upper_left_and_lower_left_and_lower_right"),
+    ('\u{259A}', "quadrant_upper_left_and_lower_right"),
+    ('\u{259B}', "quadrant_upper_left_and_upper_right_and_lower_left"),
+    ('\u{259C}', "quadrant_upper_left_and_upper_right_and_lower_right"),
+    ('\u{259D}', "quadrant_upper_right"), ('\u{259E}', "quadrant_upper_right_and_lower_left"),
+    ('\u{259F}', "quadrant_upper_right_and_lower_left_and_lower_right"),
+    ('\u{25A0}', "black_square"), ('\u{25A1}', "white_square"),
+    ('\u{25A2}', "white_square_with_rounded_corners"),
+    ('\u{25A3}', "white_square_containing_black_small_square"),
+    ('\u{25A4}', "square_with_horizontal_fill"), ('\u{25A5}', "square_with_vertical_fill"),
+    ('\u{25A6}', "square_with_orthogonal_crosshatch_fill"),
+    ('\u{25A7}', "square_with_upper_left_to_lower_right_fill"),
+    ('\u{25A8}', "square_with_upper_right_to_lower_left_fill"),
+    ('\u{25A9}', "square_with_diagonal_crosshatch_fill"), ('\u{25AA}', "black_small_square"),
+    ('\u{25AB}', "white_small_square"), ('\u{25AC}', "black_rectangle"),
+    ('\u{25AD}', "white_rectangle"), ('\u{25AE}', "black_vertical_rectangle"),
+    ('\u{25AF}', "white_vertical_rectangle"), ('\u{25B0}', "black_parallelogram"),
+    ('\u{25B1}', "white_parallelogram"), ('\u{25B2}', "black_up_pointing_triangle"),
+    ('\u{25B3}', "white_up_pointing_triangle"), ('\u{25B4}', "black_up_pointing_small_triangle"),
+    ('\u{25B5}', "white_up_pointing_small_triangle"), ('\u{25B6}', "black_right_pointing_triangle"),
+    ('\u{25B7}', "white_right_pointing_triangle"),
+    ('\u{25B8}', "black_right_pointing_small_triangle"),
+    ('\u{25B9}', "white_right_pointing_small_triangle"),
+    ('\u{25BA}', "black_right_pointing_pointer"), ('\u{25BB}', "white_right_pointing_pointer"),
+    ('\u{25BC}', "black_down_pointing_triangle"), ('\u{25BD}', "white_down_pointing_triangle"),
+    ('\u{25BE}', "black_down_pointing_small_triangle"),
+    ('\u{25BF}', "white_down_pointing_small_triangle"),
+    ('\u{25C0}', "black_left_pointing_triangle"), ('\u{25C1}', "white_left_pointing_triangle"),
+    ('\u{25C2}', "black_left_pointing_small_triangle"),
+    ('\u{25C3}', "white_left_pointing_small_triangle"), ('\u{25C4}', "black_left_pointing_pointer"),
+    ('\u{25C5}', "white_left_pointing_pointer"), ('\u{25C6}', "black_diamond"),
+    ('\u{25C7}', "white_diamond"), ('\u{25C8}', "white_diamond_containing_black_small_diamond"),
+    ('\u{25C9}', "fisheye"), ('\u{25CA}', "lozenge"), ('\u{25CB}', "white_circle"),
+    ('\u{25CC}', "dotted_circle"), ('\u{25CD}', "circle_with_vertical_fill"),
+    ('\u{25CE}', "bullseye"), ('\u{25CF}', "black_circle"),
+    ('\u{25D0}', "circle_with_left_half_black"), ('\u{25D1}', "circle_with_right_half_black"),
+    ('\u{25D2}', "circle_with_lower_half_black"), ('\u{25D3}', "circle_with_upper_half_black"),
+    ('\u{25D4}', "circle_with_upper_right_quadrant_black"),
+    ('\u{25D5}', "circle_with_all_but_upper_left_quadrant_black"),
+    ('\u{25D6}', "left_half_black_circle"), ('\u{25D7}', "right_half_black_circle"),
+    ('\u{25D8}', "inverse_bullet"), ('\u{25D9}', "inverse_white_circle"),
+    ('\u{25DA}', "upper_half_inverse_white_circle"),
+    ('\u{25DB}', "lower_half_inverse_white_circle"),
+    ('\u{25DC}', "upper_left_quadrant_circular_arc"),
+    ('\u{25DD}', "upper_right_quadrant_circular_arc"),
+    ('\u{25DE}', "lower_right_quadrant_circular_arc"),
+    ('\u{25DF}', "lower_left_quadrant_circular_arc"), ('\u{25E0}', "upper_half_circle"),
+    ('\u{25E1}', "lower_half_circle"), ('\u{25E2}', "black_lower_right_triangle"),
+    ('\u{25E3}', "black_lower_left_triangle"), ('\u{25E4}', "black_upper_left_triangle"),
+    ('\u{25E5}', "black_upper_right_triangle"), ('\u{25E6}', "white_bullet"),
+    ('\u{25E7}', "square_with_left_half_black"), ('\u{25E8}', "square_with_right_half_black"),
+    ('\u{25E9}', "square_with_upper_left_diagonal_half_black"),
+    ('\u{25EA}', "square_with_lower_right_diagonal_half_black"),
+    ('\u{25EB}', "white_square_with_vertical_bisecting_line"),
+    ('\u{25EC}', "white_up_pointing_triangle_with_dot"),
+    ('\u{25ED}', "up_pointing_triangle_with_left_half_black"),
+    ('\u{25EE}', "up_pointing_triangle_with_right_half_black"), ('\u{25EF}', "large_circle"),
+    ('\u{25F0}', "white_square_with_upper_left_quadrant"),
+    ('\u{25F1}', "white_square_with_lower_left_quadrant"),
+    ('\u{25F2}', "white_square_with_lower_right_quadrant"),
+    ('\u{25F3}', "white_square_with_upper_right_quadrant"),
+    ('\u{25F4}', "white_circle_with_upper_left_quadrant"),
+    ('\u{25F5}', "white_circle_with_lower_left_quadrant"),
+    ('\u{25F6}', "white_circle_with_lower_right_quadrant"),
+    ('\u{25F7}', "white_circle_with_upper_right_quadrant"), ('\u{25F8}', "upper_left_triangle"),
+    ('\u{25F9}', "upper_right_triangle"), ('\u{25FA}', "lower_left_triangle"),
+    ('\u{25FB}', "white_medium_square"), ('\u{25FC}', "black_medium_square"),
+    ('\u{25FD}', "white_medium_small_square"), ('\u{25FE}', "black_medium_small_square"),
+    ('\u{25FF}', "lower_right_triangle"), ('\u{2600}', "black_sun_with_rays"),
+    ('\u{2601}', "cloud"), ('\u{2602}', "umbrella"), ('\u{2603}', "snowman"), ('\u{2604}', "comet"),
+    ('\u{2605}', "black_star"), ('\u{2606}', "white_star"), ('\u{2607}', "lightning"),
+    ('\u{2608}', "thunderstorm"), ('\u{2609}', "sun"), ('\u{260A}', "ascending_node"),
+    ('\u{260B}', "descending_node"), ('\u{260C}', "conjunction"), ('\u{260D}', "opposition"),
+    ('\u{260E}', "black_telephone"), ('\u{260F}', "white_telephone"), ('\u{2610}', "ballot_box"),
+    ('\u{2611}', "ballot_box_with_check"), ('\u{2612}', "ballot_box_with_x"),
+    ('\u{2613}', "saltire"), ('\u{2614}', "umbrella_with_rain_drops"), ('\u{2615}', "hot_beverage"),
+    ('\u{2616}', "white_shogi_piece"), ('\u{2617}', "black_shogi_piece"), ('\u{2618}', "shamrock"),
+    ('\u{2619}', "reversed_rotated_floral_heart_bullet"), ('\u{261A}', "black_left_pointing_index"),
+    ('\u{261B}', "black_right_pointing_index"), ('\u{261C}', "white_left_pointing_index"),
+    ('\u{261D}', "white_up_pointing_index"), ('\u{261E}', "white_right_pointing_index"),
+    ('\u{261F}', "white_down_pointing_index"), ('\u{2620}', "skull_and_crossbones"),
+    ('\u{2621}', "caution_sign"), ('\u{2622}', "radioactive_sign"), ('\u{2623}', "biohazard_sign"),
+    ('\u{2624}', "caduceus"), ('\u{2625}', "ankh"), ('\u{2626}', "orthodox_cross"),
+    ('\u{2627}', "chi_rho"), ('\u{2628}', "cross_of_lorraine"), ('\u{2629}', "cross_of_jerusalem"),
+    ('\u{262A}', "star_and_crescent"), ('\u{262B}', "farsi_symbol"), ('\u{262C}', "adi_shakti"),
+    ('\u{262D}', "hammer_and_sickle"), ('\u{262E}', "peace_symbol"), ('\u{262F}', "yin_yang"),
+    ('\u{2630}', "trigram_for_heaven"), ('\u{2631}', "trigram_for_lake"),
+    ('\u{2632}', "trigram_for_fire"), ('\u{2633}', "trigram_for_thunder"),
+    ('\u{2634}', "trigram_for_wind"), ('\u{2635}', "trigram_for_water"),
+    ('\u{2636}', "trigram_for_mountain"), ('\u{2637}', "trigram_for_earth"),
+    ('\u{2638}', "wheel_of_dharma"), ('\u{2639}', "white_frowning_face"),
+    ('\u{263A}', "white_smiling_face"), ('\u{263B}', "black_smiling_face"),
+    ('\u{263C}', "white_sun_with_rays"), ('\u{263D}', "first_quarter_moon"),
+    ('\u{263E}', "last_quarter_moon"), ('\u{263F}', "mercury"), ('\u{2640}', "female_sign"),
+    ('\u{2641}', "earth"), ('\u{2642}', "male_sign"), ('\u{2643}', "jupiter"),
+    ('\u{2644}', "saturn"), ('\u{2645}', "uranus"), ('\u{2646}', "neptune"), ('\u{2647}', "pluto"),
+    ('\u{2648}', "aries"), ('\u{2649}', "taurus"), ('\u{264A}', "gemini"), ('\u{264B}', "cancer"),
+    ('\u{264C}', "leo"), ('\u{264D}', "virgo"), ('\u{264E}', "libra"), ('\u{264F}', "scorpius"),
+    ('\u{2650}', "sagittarius"), ('\u{2651}', "capricorn"), ('\u{2652}', "aquarius"),
+    ('\u{2653}', "pisces"), ('\u{2654}', "white_chess_king"), ('\u{2655}', "white_chess_queen"),
+    ('\u{2656}', "white_chess_rook"), ('\u{2657}', "white_chess_bishop"),
+    ('\u{2658}', "white_chess_knight"), ('\u{2659}', "white_chess_pawn"),
+    ('\u{265A}', "black_chess_king"), ('\u{265B}', "black_chess_queen"),
+    ('\u{265C}', "black_chess_rook"), ('\u{265D}', "black_chess_bishop"),
+    ('\u{265E}', "black_chess_knight"), ('\u{265F}', "black_chess_pawn"),
+    ('\u{2660}', "black_spade_suit"), ('\u{2661}', "white_heart_suit"),
+    ('\u{2662}', "white_diamond_suit"), ('\u{2663}', "black_club_suit"),
+    ('\u{2664}', "white_spade_suit"), ('\u{2665}', "black_heart_suit"),
+    ('\u{2666}', "black_diamond_suit"), ('\u{2667}', "white_club_suit"),
+    ('\u{2668}', "hot_springs"), ('\u{2669}', "quarter_note"), ('\u{266A}', "eighth_note"),
+    ('\u{266B}', "beamed_eighth_notes"), ('\u{266C}', "beamed_sixteenth_notes"),
+    ('\u{266D}', "music_flat_sign"), ('\u{266E}', "music_natural_sign"),
+    ('\u{266F}', "music_sharp_sign"), ('\u{2670}', "west_syriac_cross"),
+    ('\u{2671}', "east_syriac_cross"), ('\u{2672}', "universal_recycling_symbol"),
+    ('\u{2673}', "recycling_symbol_for_type_1_plastics"),
+    ('\u{2674}', "recycling_symbol_for_type_2_plastics"),
+    ('\u{2675}', "recycling_symbol_for_type_3_plastics"),
+    ('\u{2676}', "recycling_symbol_for_type_4_plastics"),
+    ('\u{2677}', "recycling_symbol_for_type_5_plastics"),
+    ('\u{2678}', "recycling_symbol_for_type_6_plastics"),
+    ('\u{2679}', "recycling_symbol_for_type_7_plastics"),
+    ('\u{267A}', "recycling_symbol_for_generic_materials"),
+    ('\u{267B}', "black_universal_recycling_symbol"), ('\u{267C}', "recycled_paper_symbol"),
+    ('\u{267D}', "partially_recycled_paper_symbol"), ('\u{267E}', "permanent_paper_sign"),
+    ('\u{267F}', "wheelchair_symbol"), ('\u{2680}', "die_face_1"), ('\u{2681}', "die_face_2"),
+    ('\u{2682}', "die_face_3"), ('\u{2683}', "die_face_4"), ('\u{2684}', "die_face_5"),
+    ('\u{2685}', "die_face_6"), ('\u{2686}', "white_circle_with_dot_right"),
+    ('\u{2687}', "white_circle_with_two_dots"), ('\u{2688}', "black_circle_with_white_dot_right"),
+    ('\u{2689}', "black_circle_with_two_white_dots"), ('\u{268A}', "monogram_for_yang"),
+    ('\u{268B}', "monogram_for_yin"), ('\u{268C}', "digram_for_greater_yang"),
+    ('\u{268D}', "digram_for_lesser_yin"), ('\u{268E}', "digram_for_lesser_yang"),
+    ('\u{268F}', "digram_for_greater_yin"), ('\u{2690}', "white_flag"), ('\u{2691}', "black_flag"),
+    ('\u{2692}', "hammer_and_pick"), ('\u{2693}', "anchor"), ('\u{2694}', "crossed_swords"),
+    ('\u{2695}', "staff_of_aesculapius"), ('\u{2696}', "scales"), ('\u{2697}', "alembic"),
+    ('\u{2698}', "flower"), ('\u{2699}', "gear"), ('\u{269A}', "staff_of_hermes"),
+    ('\u{269B}', "atom_symbol"), ('\u{269C}', "fleur_de_lis"), ('\u{269D}', "outlined_white_star"),
+    ('\u{269E}', "three_lines_converging_right"), ('\u{269F}', "three_lines_converging_left"),
+    ('\u{26A0}', "warning_sign"), ('\u{26A1}', "high_voltage_sign"),
+    ('\u{26A2}', "doubled_female_sign"), ('\u{26A3}', "doubled_male_sign"),
+    ('\u{26A4}', "interlocked_female_and_male_sign"), ('\u{26A5}', "male_and_female_sign"),
+    ('\u{26A6}', "male_with_stroke_sign"),
+    ('\u{26A7}', "male_with_stroke_and_male_and_female_sign"),
+    ('\u{26A8}', "vertical_male_with_stroke_sign"),
+    ('\u{26A9}', "horizontal_male_with_stroke_sign"), ('\u{26AA}', "medium_white_circle"),
+    ('\u{26AB}', "medium_black_circle"), ('\u{26AC}', "medium_small_white_circle"),
+    ('\u{26AD}', "marriage_symbol"), ('\u{26AE}', "divorce_symbol"),
+    ('\u{26AF}', "unmarried_partnership_symbol"), ('\u{26B0}', "coffin"),
+    ('\u{26B1}', "funeral_urn"), ('\u{26B2}', "neuter"), ('\u{26B3}', "ceres"),
+    ('\u{26B4}', "pallas"), ('\u{26B5}', "juno"), ('\u{26B6}', "vesta"), ('\u{26B7}', "chiron"),
+    ('\u{26B8}', "black_moon_lilith"), ('\u{26B9}', "sextile"), ('\u{26BA}', "semisextile"),
+    ('\u{26BB}', "quincunx"), ('\u{26BC}', "sesquiquadrate"), ('\u{26BD}', "soccer_ball"),
+    ('\u{26BE}', "baseball"), ('\u{26BF}', "squared_key"), ('\u{26C0}', "white_draughts_man"),
+    ('\u{26C1}', "white_draughts_king"), ('\u{26C2}', "black_draughts_man"),
+    ('\u{26C3}', "black_draughts_king"), ('\u{26C4}', "snowman_without_snow"),
+    ('\u{26C5}', "sun_behind_cloud"), ('\u{26C6}', "rain"), ('\u{26C7}', "black_snowman"),
+    ('\u{26C8}', "thunder_cloud_and_rain"), ('\u{26C9}', "turned_white_shogi_piece"),
+    ('\u{26CA}', "turned_black_shogi_piece"), ('\u{26CB}', "white_diamond_in_square"),
+    ('\u{26CC}', "crossing_lanes"), ('\u{26CD}', "disabled_car"), ('\u{26CE}', "ophiuchus"),
+    ('\u{26CF}', "pick"), ('\u{26D0}', "car_sliding"), ('\u{26D1}', "helmet_with_white_cross"),
+    ('\u{26D2}', "circled_crossing_lanes"), ('\u{26D3}', "chains"), ('\u{26D4}', "no_entry"),
+    ('\u{26D5}', "alternate_one_way_left_way_traffic"),
+    ('\u{26D6}', "black_two_way_left_way_traffic"), ('\u{26D7}', "white_two_way_left_way_traffic"),
+    ('\u{26D8}', "black_left_lane_merge"), ('\u{26D9}', "white_left_lane_merge"),
+    ('\u{26DA}', "drive_slow_sign"), ('\u{26DB}', "heavy_white_down_pointing_triangle"),
+    ('\u{26DC}', "left_closed_entry"), ('\u{26DD}', "squared_saltire"),
+    ('\u{26DE}', "falling_diagonal_in_white_circle_in_black_square"), ('\u{26DF}', "black_truck"),
+    ('\u{26E0}', "restricted_left_entry_1"), ('\u{26E1}', "restricted_left_entry_2"),
+    ('\u{26E2}', "astronomical_symbol_for_uranus"),
+    ('\u{26E3}', "heavy_circle_with_stroke_and_two_dots_above"), ('\u{26E4}', "pentagram"),
+    ('\u{26E5}', "right_handed_interlaced_pentagram"),
+    ('\u{26E6}', "left_handed_interlaced_pentagram"), ('\u{26E7}', "inverted_pentagram"),
+    ('\u{26E8}', "black_cross_on_shield"), ('\u{26E9}', "shinto_shrine"), ('\u{26EA}', "church"),
+    ('\u{26EB}', "castle"), ('\u{26EC}', "historic_site"), ('\u{26ED}', "gear_without_hub"),
+    ('\u{26EE}', "gear_with_handles"), ('\u{26EF}', "map_symbol_for_lighthouse"),
+    ('\u{26F0}', "mountain"), ('\u{26F1}', "umbrella_on_ground"), ('\u{26F2}', "fountain"),
+    ('\u{26F3}', "flag_in_hole"), ('\u{26F4}', "ferry"), ('\u{26F5}', "sailboat"),
+    ('\u{26F6}', "square_four_corners"), ('\u{26F7}', "skier"), ('\u{26F8}', "ice_skate"),
+    ('\u{26F9}', "person_with_ball"), ('\u{26FA}', "tent"), ('\u{26FB}', "japanese_bank_symbol"),
+    ('\u{26FC}', "headstone_graveyard_symbol"), ('\u{26FD}', "fuel_pump"),
+    ('\u{26FE}', "cup_on_black_square"),
+    ('\u{26FF}', "white_flag_with_horizontal_middle_black_stripe"),
+    ('\u{2700}', "black_safety_scissors"), ('\u{2701}', "upper_blade_scissors"),
+    ('\u{2702}', "black_scissors"), ('\u{2703}', "lower_blade_scissors"),
+    ('\u{2704}', "white_scissors"), ('\u{2705}', "white_heavy_check_mark"),
+    ('\u{2706}', "telephone_location_sign"), ('\u{2707}', "tape_drive"), ('\u{2708}', "airplane"),
+    ('\u{2709}', "envelope"), ('\u{270A}', "raised_fist"), ('\u{270B}', "raised_hand"),
+    ('\u{270C}', "victory_hand"), ('\u{270D}', "writing_hand"), ('\u{270E}', "lower_right_pencil"),
+    ('\u{270F}', "pencil"), ('\u{2710}', "upper_right_pencil"), ('\u{2711}', "white_nib"),
+    ('\u{2712}', "black_nib"), ('\u{2713}', "check_mark"), ('\u{2714}', "heavy_check_mark"),
+    ('\u{2715}', "multiplication_x"), ('\u{2716}', "heavy_multiplication_x"),
+    ('\u{2717}', "ballot_x"), ('\u{2718}', "heavy_ballot_x"), ('\u{2719}', "outlined_greek_cross"),
+    ('\u{271A}', "heavy_greek_cross"), ('\u{271B}', "open_centre_cross"),
+    ('\u{271C}', "heavy_open_centre_cross"), ('\u{271D}', "latin_cross"),
+    ('\u{271E}', "shadowed_white_latin_cross"), ('\u{271F}', "outlined_latin_cross"),
+    ('\u{2720}', "maltese_cross"), ('\u{2721}', "star_of_david"),
+    ('\u{2722}', "four_teardrop_spoked_asterisk"), ('\u{2723}', "four_balloon_spoked_asterisk"),
+    ('\u{2724}', "heavy_four_balloon_spoked_asterisk"), ('\u{2725}', "four_club_spoked_asterisk"),
+    ('\u{2726}', "black_four_pointed_star"), ('\u{2727}', "white_four_pointed_star"),
+    ('\u{2728}', "sparkles"), ('\u{2729}', "stress_outlined_white_star"),
+    ('\u{272A}', "circled_white_star"), ('\u{272B}', "open_centre_black_star"),
+    ('\u{272C}', "black_centre_white_star"), ('\u{272D}', "outlined_black_star"),
+    ('\u{272E}', "heavy_outlined_black_star"), ('\u{272F}', "pinwheel_star"),
+    ('\u{2730}', "shadowed_white_star"), ('\u{2731}', "heavy_asterisk"),
+    ('\u{2732}', "open_centre_asterisk"), ('\u{2733}', "eight_spoked_asterisk"),
+    ('\u{2734}', "eight_pointed_black_star"), ('\u{2735}', "eight_pointed_pinwheel_star"),
+    ('\u{2736}', "six_pointed_black_star"), ('\u{2737}', "eight_pointed_rectilinear_black_star"),
+    ('\u{2738}', "heavy_eight_pointed_rectilinear_black_star"),
+    ('\u{2739}', "twelve_pointed_black_star"), ('\u{273A}', "sixteen_pointed_asterisk"),
+    ('\u{273B}', "teardrop_spoked_asterisk"), ('\u{273C}', "open_centre_teardrop_spoked_asterisk"),
+    ('\u{273D}', "heavy_teardrop_spoked_asterisk"),
+    ('\u{273E}', "six_petalled_black_and_white_florette"), ('\u{273F}', "black_florette"),
+    ('\u{2740}', "white_florette"), ('\u{2741}', "eight_petalled_outlined_black_florette"),
+    ('\u{2742}', "circled_open_centre_eight_pointed_star"),
+    ('\u{2743}', "heavy_teardrop_spoked_pinwheel_asterisk"), ('\u{2744}', "snowflake"),
+    ('\u{2745}', "tight_trifoliate_snowflake"), ('\u{2746}', "heavy_chevron_snowflake"),
+    ('\u{2747}', "sparkle"), ('\u{2748}', "heavy_sparkle"), ('\u{2749}', "balloon_spoked_asterisk"),
+    ('\u{274A}', "eight_teardrop_spoked_propeller_asterisk"),
+    ('\u{274B}', "heavy_eight_teardrop_spoked_propeller_asterisk"), ('\u{274C}', "cross_mark"),
+    ('\u{274D}', "shadowed_white_circle"), ('\u{274E}', "negative_squared_cross_mark"),
+    ('\u{274F}', "lower_right_drop_shadowed_white_square"),
+    ('\u{2750}', "upper_right_drop_shadowed_white_square"),
+    ('\u{2751}', "lower_right_shadowed_white_square"),
+    ('\u{2752}', "upper_right_shadowed_white_square"), ('\u{2753}', "black_question_mark_ornament"),
+    ('\u{2754}', "white_question_mark_ornament"), ('\u{2755}', "white_exclamation_mark_ornament"),
+    ('\u{2756}', "black_diamond_minus_white_x"), ('\u{2757}', "heavy_exclamation_mark_symbol"),
+    ('\u{2758}', "light_vertical_bar"), ('\u{2759}', "medium_vertical_bar"),
+    ('\u{275A}', "heavy_vertical_bar"),
+    ('\u{275B}', "heavy_single_turned_comma_quotation_mark_ornament"),
+    ('\u{275C}', "heavy_single_comma_quotation_mark_ornament"),
+    ('\u{275D}', "heavy_double_turned_comma_quotation_mark_ornament"),
+    ('\u{275E}', "heavy_double_comma_quotation_mark_ornament"),
+    ('\u{275F}', "heavy_low_single_comma_quotation_mark_ornament"),
+    ('\u{2760}', "heavy_low_double_comma_quotation_mark_ornament"),
+    ('\u{2761}', "curved_stem_paragraph_sign_ornament"),
+    ('\u{2762}', "heavy_exclamation_mark_ornament"),
+    ('\u{2763}', "heavy_heart_exclamation_mark_ornament"), ('\u{2764}', "heavy_black_heart"),
+    ('\u{2765}', "rotated_heavy_black_heart_bullet"), ('\u{2766}', "floral_heart"),
+    ('\u{2767}', "rotated_floral_heart_bullet"), ('\u{2794}', "heavy_wide_headed_rightwards_arrow"),
+    ('\u{2795}', "heavy_plus_sign"), ('\u{2796}', "heavy_minus_sign"),
+    ('\u{2797}', "heavy_division_sign"), ('\u{2798}', "heavy_south_east_arrow"),
+    ('\u{2799}', "heavy_rightwards_arrow"), ('\u{279A}', "heavy_north_east_arrow"),
+    ('\u{279B}', "drafting_point_rightwards_arrow"),
+    ('\u{279C}', "heavy_round_tipped_rightwards_arrow"),
+    ('\u{279D}', "triangle_headed_rightwards_arrow"),
+    ('\u{279E}', "heavy_triangle_headed_rightwards_arrow"),
+    ('\u{279F}', "dashed_triangle_headed_rightwards_arrow"),
+    ('\u{27A0}', "heavy_dashed_triangle_headed_rightwards_arrow"),
+    ('\u{27A1}', "black_rightwards_arrow"),
+    ('\u{27A2}', "three_d_top_lighted_rightwards_arrowhead"),
+    ('\u{27A3}', "three_d_bottom_lighted_rightwards_arrowhead"),
+    ('\u{27A4}', "black_rightwards_arrowhead"),
+    ('\u{27A5}', "heavy_black_curved_downwards_and_rightwards_arrow"),
+    ('\u{27A6}', "heavy_black_curved_upwards_and_rightwards_arrow"),
+    ('\u{27A7}', "squat_black_rightwards_arrow"),
+    ('\u{27A8}', "heavy_concave_pointed_black_rightwards_arrow"),
+    ('\u{27A9}', "right_shaded_white_rightwards_arrow"),
+    ('\u{27AA}', "left_shaded_white_rightwards_arrow"),
+    ('\u{27AB}', "back_tilted_shadowed_white_rightwards_arrow"),
+    ('\u{27AC}', "front_tilted_shadowed_white_rightwards_arrow"),
+    ('\u{27AD}', "heavy_lower_right_shadowed_white_rightwards_arrow"),
+    ('\u{27AE}', "heavy_upper_right_shadowed_white_rightwards_arrow"),
+    ('\u{27AF}', "notched_lower_right_shadowed_white_rightwards_arrow"), ('\u{27B0}', "curly_loop"),
+    ('\u{27B1}', "notched_upper_right_shadowed_white_rightwards_arrow"),
+    ('\u{27B2}', "circled_heavy_white_rightwards_arrow"),
+    ('\u{27B3}', "white_feathered_rightwards_arrow"),
+    ('\u{27B4}', "black_feathered_south_east_arrow"),
+    ('\u{27B5}', "black_feathered_rightwards_arrow"),
+    ('\u{27B6}', "black_feathered_north_east_arrow"),
+    ('\u{27B7}', "heavy_black_feathered_south_east_arrow"),
+    ('\u{27B8}', "heavy_black_feathered_rightwards_arrow"),
+    ('\u{27B9}', "heavy_black_feathered_north_east_arrow"),
+    ('\u{27BA}', "teardrop_barbed_rightwards_arrow"),
+    ('\u{27BB}', "heavy_teardrop_shanked_rightwards_arrow"),
+    ('\u{27BC}', "wedge_tailed_rightwards_arrow"),
+    ('\u{27BD}', "heavy_wedge_tailed_rightwards_arrow"),
+    ('\u{27BE}', "open_outlined_rightwards_arrow"), ('\u{27BF}', "double_curly_loop"),
+    ('\u{2B00}', "north_east_white_arrow"), ('\u{2B01}', "north_west_white_arrow"),
+    ('\u{2B02}', "south_east_white_arrow"), ('\u{2B03}', "south_west_white_arrow"),
+    ('\u{2B04}', "left_right_white_arrow"), ('\u{2B05}', "leftwards_black_arrow"),
+    ('\u{2B06}', "upwards_black_arrow"), ('\u{2B07}', "downwards_black_arrow"),
+    ('\u{2B08}', "north_east_black_arrow"), ('\u{2B09}', "north_west_black_arrow"),
+    ('\u{2B0A}', "south_east_black_arrow"), ('\u{2B0B}', "south_west_black_arrow"),
+    ('\u{2B0C}', "left_right_black_arrow"), ('\u{2B0D}', "up_down_black_arrow"),
+    ('\u{2B0E}', "rightwards_arrow_with_tip_downwards"),
+    ('\u{2B0F}', "rightwards_arrow_with_tip_upwards"),
+    ('\u{2B10}', "leftwards_arrow_with_tip_downwards"),
+    ('\u{2B11}', "leftwards_arrow_with_tip_upwards"), ('\u{2B12}', "square_with_top_half_black"),
+    ('\u{2B13}', "square_with_bottom_half_black"),
+    ('\u{2B14}', "square_with_upper_right_diagonal_half_black"),
+    ('\u{2B15}', "square_with_lower_left_diagonal_half_black"),
+    ('\u{2B16}', "diamond_with_left_half_black"), ('\u{2B17}', "diamond_with_right_half_black"),
+    ('\u{2B18}', "diamond_with_top_half_black"), ('\u{2B19}', "diamond_with_bottom_half_black"),
+    ('\u{2B1A}', "dotted_square"), ('\u{2B1B}', "black_large_square"),
+    ('\u{2B1C}', "white_large_square"), ('\u{2B1D}', "black_very_small_square"),
+    ('\u{2B1E}', "white_very_small_square"), ('\u{2B1F}', "black_pentagon"),
+    ('\u{2B20}', "white_pentagon"), ('\u{2B21}', "white_hexagon"), ('\u{2B22}', "black_hexagon"),
+    ('\u{2B23}', "horizontal_black_hexagon"), ('\u{2B24}', "black_large_circle"),
+    ('\u{2B25}', "black_medium_diamond"), ('\u{2B26}', "white_medium_diamond"),
+    ('\u{2B27}', "black_medium_lozenge"), ('\u{2B28}', "white_medium_lozenge"),
+    ('\u{2B29}', "black_small_diamond"), ('\u{2B2A}', "black_small_lozenge"),
+    ('\u{2B2B}', "white_small_lozenge"), ('\u{2B2C}', "black_horizontal_ellipse"),
+    ('\u{2B2D}', "white_horizontal_ellipse"), ('\u{2B2E}', "black_vertical_ellipse"),
+    ('\u{2B2F}', "white_vertical_ellipse"), ('\u{2B30}', "left_arrow_with_small_circle"),
+    ('\u{2B31}', "three_leftwards_arrows"), ('\u{2B32}', "left_arrow_with_circled_plus"),
+    ('\u{2B33}', "long_leftwards_squiggle_arrow"),
+    ('\u{2B34}', "leftwards_two_headed_arrow_with_vertical_stroke"),
+    ('\u{2B35}', "leftwards_two_headed_arrow_with_double_vertical_stroke"),
+    ('\u{2B36}', "leftwards_two_headed_arrow_from_bar"),
+    ('\u{2B37}', "leftwards_two_headed_triple_dash_arrow"),
+    ('\u{2B38}', "leftwards_arrow_with_dotted_stem"),
+    ('\u{2B39}', "leftwards_arrow_with_tail_with_vertical_stroke"),
+    ('\u{2B3A}', "leftwards_arrow_with_tail_with_double_vertical_stroke"),
+    ('\u{2B3B}', "leftwards_two_headed_arrow_with_tail"),
+    ('\u{2B3C}', "leftwards_two_headed_arrow_with_tail_with_vertical_stroke"),
+    ('\u{2B3D}', "leftwards_two_headed_arrow_with_tail_with_double_vertical_stroke"),
+    ('\u{2B3E}', "leftwards_arrow_through_x"), ('\u{2B3F}', "wave_arrow_pointing_directly_left"),
+    ('\u{2B40}', "equals_sign_above_leftwards_arrow"),
+    ('\u{2B41}', "reverse_tilde_operator_above_leftwards_arrow"),
+    ('\u{2B42}', "leftwards_arrow_above_reverse_almost_equal_to"),
+    ('\u{2B43}', "rightwards_arrow_through_greater_than"),
+    ('\u{2B44}', "rightwards_arrow_through_superset"), ('\u{2B45}', "leftwards_quadruple_arrow"),
+    ('\u{2B46}', "rightwards_quadruple_arrow"),
+    ('\u{2B47}', "reverse_tilde_operator_above_rightwards_arrow"),
+    ('\u{2B48}', "rightwards_arrow_above_reverse_almost_equal_to"),
+    ('\u{2B49}', "tilde_operator_above_leftwards_arrow"),
+    ('\u{2B4A}', "leftwards_arrow_above_almost_equal_to"),
+    ('\u{2B4B}', "leftwards_arrow_above_reverse_tilde_operator"),
+    ('\u{2B4C}', "rightwards_arrow_above_reverse_tilde_operator"),
+    ('\u{2B4D}', "downwards_triangle_headed_zigzag_arrow"),
+    ('\u{2B4E}', "short_slanted_north_arrow"), ('\u{2B4F}', "short_backslanted_south_arrow"),
+    ('\u{2B50}', "white_medium_star"), ('\u{2B51}', "black_small_star"),
+    ('\u{2B52}', "white_small_star"), ('\u{2B53}', "black_right_pointing_pentagon"),
+    ('\u{2B54}', "white_right_pointing_pentagon"), ('\u{2B55}', "heavy_large_circle"),
+    ('\u{2B56}', "heavy_oval_with_oval_inside"), ('\u{2B57}', "heavy_circle_with_circle_inside"),
+    ('\u{2B58}', "heavy_circle"), ('\u{2B59}', "heavy_circled_saltire"),
+    ('\u{2B5A}', "slanted_north_arrow_with_hooked_head"),
+    ('\u{2B5B}', "backslanted_south_arrow_with_hooked_tail"),
+    ('\u{2B5C}', "slanted_north_arrow_with_horizontal_tail"),
+    ('\u{2B5D}', "backslanted_south_arrow_with_horizontal_tail"),
+    ('\u{2B5E}', "bent_arrow_pointing_downwards_then_north_east"),
+    ('\u{2B5F}', "short_bent_arrow_pointing_downwards_then_north_east"),
+    ('\u{2B60}', "leftwards_triangle_headed_arrow"), ('\u{2B61}', "upwards_triangle_headed_arrow"),
+    ('\u{2B62}', "rightwards_triangle_headed_arrow"),
+    ('\u{2B63}', "downwards_triangle_headed_arrow"),
+    ('\u{2B64}', "left_right_triangle_headed_arrow"), ('\u{2B65}', "up_down_triangle_headed_arrow"),
+    ('\u{2B66}', "north_west_triangle_headed_arrow"),
+    ('\u{2B67}', "north_east_triangle_headed_arrow"),
+    ('\u{2B68}', "south_east_triangle_headed_arrow"),
+    ('\u{2B69}', "south_west_triangle_headed_arrow"),
+    ('\u{2B6A}', "leftwards_triangle_headed_dashed_arrow"),
+    ('\u{2B6B}', "upwards_triangle_headed_dashed_arrow"),
+    ('\u{2B6C}', "rightwards_triangle_headed_dashed_arrow"),
+    ('\u{2B6D}', "downwards_triangle_headed_dashed_arrow"),
+    ('\u{2B6E}', "clockwise_triangle_headed_open_circle_arrow"),
+    ('\u{2B6F}', "anticlockwise_triangle_headed_open_circle_arrow"),
+    ('\u{2B70}', "leftwards_triangle_headed_arrow_to_bar"),
+    ('\u{2B71}', "upwards_triangle_headed_arrow_to_bar"),
+    ('\u{2B72}', "rightwards_triangle_headed_arrow_to_bar"),
+    ('\u{2B73}', "downwards_triangle_headed_arrow_to_bar"),
+    ('\u{2B76}', "north_west_triangle_headed_arrow_to_bar"),
+    ('\u{2B77}', "north_east_triangle_headed_arrow_to_bar"),
+    ('\u{2B78}', "south_east_triangle_headed_arrow_to_bar"),
+    ('\u{2B79}', "south_west_triangle_headed_arrow_to_bar"),
+    ('\u{2B7A}', "leftwards_triangle_headed_arrow_with_double_horizontal_stroke"),
+    ('\u{2B7B}', "upwards_triangle_headed_arrow_with_double_horizontal_stroke"),
+    ('\u{2B7C}', "rightwards_triangle_headed_arrow_with_double_horizontal_stroke"),
+    ('\u{2B7D}', "downwards_triangle_headed_arrow_with_double_horizontal_stroke"),
+    ('\u{2B7E}', "horizontal_tab_key"), ('\u{2B7F}', "vertical_tab_key"),
+    ('\u{2B80}', "leftwards_triangle_headed_arrow_over_rightwards_triangle_headed_arrow"),
+    ('\u{2B81}', "upwards_triangle_headed_arrow_leftwards_of_downwards_triangle_headed_arrow"),
+    ('\u{2B82}', "rightwards_triangle_headed_arrow_over_leftwards_triangle_headed_arrow"),
+    ('\u{2B83}', "downwards_triangle_headed_arrow_leftwards_of_upwards_triangle_headed_arrow"),
+    ('\u{2B84}', "leftwards_triangle_headed_paired_arrows"),
+    ('\u{2B85}', "upwards_triangle_headed_paired_arrows"),
+    ('\u{2B86}', "rightwards_triangle_headed_paired_arrows"),
+    ('\u{2B87}', "downwards_triangle_headed_paired_arrows"),
+    ('\u{2B88}', "leftwards_black_circled_white_arrow"),
+    ('\u{2B89}', "upwards_black_circled_white_arrow"),
+    ('\u{2B8A}', "rightwards_black_circled_white_arrow"),
+    ('\u{2B8B}', "downwards_black_circled_white_arrow"),
+    ('\u{2B8C}', "anticlockwise_triangle_headed_right_u_shaped_arrow"),
+    ('\u{2B8D}', "anticlockwise_triangle_headed_bottom_u_shaped_arrow"),
+    ('\u{2B8E}', "anticlockwise_triangle_headed_left_u_shaped_arrow"),
+    ('\u{2B8F}', "anticlockwise_triangle_headed_top_u_shaped_arrow"), ('\u{2B90}', "return_left"),
+    ('\u{2B91}', "return_right"), ('\u{2B92}', "newline_left"), ('\u{2B93}', "newline_right"),
+    ('\u{2B94}', "four_corner_arrows_circling_anticlockwise"),
+    ('\u{2B95}', "rightwards_black_arrow"), ('\u{2B97}', "symbol_for_type_a_electronics"),
+    ('\u{2B98}', "three_d_top_lighted_leftwards_equilateral_arrowhead"),
+    ('\u{2B99}', "three_d_right_lighted_upwards_equilateral_arrowhead"),
+    ('\u{2B9A}', "three_d_top_lighted_rightwards_equilateral_arrowhead"),
+    ('\u{2B9B}', "three_d_left_lighted_downwards_equilateral_arrowhead"),
+    ('\u{2B9C}', "black_leftwards_equilateral_arrowhead"),
+    ('\u{2B9D}', "black_upwards_equilateral_arrowhead"),
+    ('\u{2B9E}', "black_rightwards_equilateral_arrowhead"),
+    ('\u{2B9F}', "black_downwards_equilateral_arrowhead"),
+    ('\u{2BA0}', "downwards_triangle_headed_arrow_with_long_tip_leftwards"),
+    ('\u{2BA1}', "downwards_triangle_headed_arrow_with_long_tip_rightwards"),
+    ('\u{2BA2}', "upwards_triangle_headed_arrow_with_long_tip_leftwards"),
+    ('\u{2BA3}', "upwards_triangle_headed_arrow_with_long_tip_rightwards"),
+    ('\u{2BA4}', "leftwards_triangle_headed_arrow_with_long_tip_upwards"),
+    ('\u{2BA5}', "rightwards_triangle_headed_arrow_with_long_tip_upwards"),
+    ('\u{2BA6}', "leftwards_triangle_headed_arrow_with_long_tip_downwards"),
+    ('\u{2BA7}', "rightwards_triangle_headed_arrow_with_long_tip_downwards"),
+    ('\u{2BA8}', "black_curved_downwards_and_leftwards_arrow"),
+    ('\u{2BA9}', "black_curved_downwards_and_rightwards_arrow"),
+    ('\u{2BAA}', "black_curved_upwards_and_leftwards_arrow"),
+    ('\u{2BAB}', "black_curved_upwards_and_rightwards_arrow"),
+    ('\u{2BAC}', "black_curved_leftwards_and_upwards_arrow"),
+    ('\u{2BAD}', "black_curved_rightwards_and_upwards_arrow"),
+    ('\u{2BAE}', "black_curved_leftwards_and_downwards_arrow"),
+    ('\u{2BAF}', "black_curved_rightwards_and_downwards_arrow"),
+    ('\u{2BB0}', "ribbon_arrow_down_left"), ('\u{2BB1}', "ribbon_arrow_down_right"),
+    ('\u{2BB2}', "ribbon_arrow_up_left"), ('\u{2BB3}', "ribbon_arrow_up_right"),
+    ('\u{2BB4}', "ribbon_arrow_left_up"), ('\u{2BB5}', "ribbon_arrow_right_up"),
+    ('\u{2BB6}', "ribbon_arrow_left_down"), ('\u{2BB7}', "ribbon_arrow_right_down"),
+    ('\u{2BB8}', "upwards_white_arrow_from_bar_with_horizontal_bar"),
+    ('\u{2BB9}', "up_arrowhead_in_a_rectangle_box"), ('\u{2BBA}', "overlapping_white_squares"),
+    ('\u{2BBB}', "overlapping_white_and_black_squares"), ('\u{2BBC}', "overlapping_black_squares"),
+    ('\u{2BBD}', "ballot_box_with_light_x"), ('\u{2BBE}', "circled_x"),
+    ('\u{2BBF}', "circled_bold_x"), ('\u{2BC0}', "black_square_centred"),
+    ('\u{2BC1}', "black_diamond_centred"), ('\u{2BC2}', "turned_black_pentagon"),
+    ('\u{2BC3}', "horizontal_black_octagon"), ('\u{2BC4}', "black_octagon"),
+    ('\u{2BC5}', "black_medium_up_pointing_triangle_centred"),
+    ('\u{2BC6}', "black_medium_down_pointing_triangle_centred"),
+    ('\u{2BC7}', "black_medium_left_pointing_triangle_centred"),
+    ('\u{2BC8}', "black_medium_right_pointing_triangle_centred"), ('\u{2BC9}', "neptune_form_two"),
+    ('\u{2BCA}', "top_half_black_circle"), ('\u{2BCB}', "bottom_half_black_circle"),
+    ('\u{2BCC}', "light_four_pointed_black_cusp"),
+    ('\u{2BCD}', "rotated_light_four_pointed_black_cusp"), ('\u{2BCE}', "white_four_pointed_cusp"),
+    ('\u{2BCF}', "rotated_white_four_pointed_cusp"), ('\u{2BD0}', "square_position_indicator"),
+    ('\u{2BD1}', "uncertainty_sign"), ('\u{2BD2}', "group_mark"), ('\u{2BD3}', "pluto_form_two"),
+    ('\u{2BD4}', "pluto_form_three"), ('\u{2BD5}', "pluto_form_four"),
+    ('\u{2BD6}', "pluto_form_five"), ('\u{2BD7}', "transpluto"), ('\u{2BD8}', "proserpina"),
+    ('\u{2BD9}', "astraea"), ('\u{2BDA}', "hygiea"), ('\u{2BDB}', "pholus"), ('\u{2BDC}', "nessus"),
+    ('\u{2BDD}', "white_moon_selena"), ('\u{2BDE}', "black_diamond_on_cross"),
+    ('\u{2BDF}', "true_light_moon_arta"), ('\u{2BE0}', "cupido"), ('\u{2BE1}', "hades"),
+    ('\u{2BE2}', "zeus"), ('\u{2BE3}', "kronos"), ('\u{2BE4}', "apollon"), ('\u{2BE5}', "admetos"),
+    ('\u{2BE6}', "vulcanus"), ('\u{2BE7}', "poseidon"), ('\u{2BE8}', "left_half_black_star"),
+    ('\u{2BE9}', "right_half_black_star"), ('\u{2BEA}', "star_with_left_half_black"),
+    ('\u{2BEB}', "star_with_right_half_black"),
+    ('\u{2BEC}', "leftwards_two_headed_arrow_with_triangle_arrowheads"),
+    ('\u{2BED}', "upwards_two_headed_arrow_with_triangle_arrowheads"),
+    ('\u{2BEE}', "rightwards_two_headed_arrow_with_triangle_arrowheads"),
+    ('\u{2BEF}', "downwards_two_headed_arrow_with_triangle_arrowheads"),
+    ('\u{2BF0}', "eris_form_one"), ('\u{2BF1}', "eris_form_two"), ('\u{2BF2}', "sedna"),
+    ('\u{2BF3}', "russian_astrological_symbol_vigintile"),
+    ('\u{2BF4}', "russian_astrological_symbol_novile"),
+    ('\u{2BF5}', "russian_astrological_symbol_quintile"),
+    ('\u{2BF6}', "russian_astrological_symbol_binovile"),
+    ('\u{2BF7}', "russian_astrological_symbol_sentagon"),
+    ('\u{2BF8}', "russian_astrological_symbol_tredecile"),
+    ('\u{2BF9}', "equals_sign_with_infinity_below"), ('\u{2BFA}', "united_symbol"),
+    ('\u{2BFB}', "separated_symbol"), ('\u{2BFC}', "doubled_symbol"), ('\u{2BFD}', "passed_symbol"),
+    ('\u{2BFE}', "reversed_right_angle"), ('\u{2BFF}', "hellschreiber_pause_symbol"),
+    ('\u{1F300}', "cyclone"), ('\u{1F301}', "foggy"), ('\u{1F302}', "closed_umbrella"),
+    ('\u{1F303}', "night_with_stars"), ('\u{1F304}', "sunrise_over_mountains"),
+    ('\u{1F305}', "sunrise"), ('\u{1F306}', "cityscape_at_dusk"),
+    ('\u{1F307}', "sunset_over_buildings"), ('\u{1F308}', "rainbow"),
+    ('\u{1F309}', "bridge_at_night"), ('\u{1F30A}', "water_wave"), ('\u{1F30B}', "volcano"),
+    ('\u{1F30C}', "milky_way"), ('\u{1F30D}', "earth_globe_europe_africa"),
+    ('\u{1F30E}', "earth_globe_americas"), ('\u{1F30F}', "earth_globe_asia_australia"),
+    ('\u{1F310}', "globe_with_meridians"), ('\u{1F311}', "new_moon_symbol"),
+    ('\u{1F312}', "waxing_crescent_moon_symbol"), ('\u{1F313}', "first_quarter_moon_symbol"),
+    ('\u{1F314}', "waxing_gibbous_moon_symbol"), ('\u{1F315}', "full_moon_symbol"),
+    ('\u{1F316}', "waning_gibbous_moon_symbol"), ('\u{1F317}', "last_quarter_moon_symbol"),
+    ('\u{1F318}', "waning_crescent_moon_symbol"), ('\u{1F319}', "crescent_moon"),
+    ('\u{1F31A}', "new_moon_with_face"), ('\u{1F31B}', "first_quarter_moon_with_face"),
+    ('\u{1F31C}', "last_quarter_moon_with_face"), ('\u{1F31D}', "full_moon_with_face"),
+    ('\u{1F31E}', "sun_with_face"), ('\u{1F31F}', "glowing_star"), ('\u{1F320}', "shooting_star"),
+    ('\u{1F321}', "thermometer"), ('\u{1F322}', "black_droplet"), ('\u{1F323}', "white_sun"),
+    ('\u{1F324}', "white_sun_with_small_cloud"), ('\u{1F325}', "white_sun_behind_cloud"),
+    ('\u{1F326}', "white_sun_behind_cloud_with_rain"), ('\u{1F327}', "cloud_with_rain"),
+    ('\u{1F328}', "cloud_with_snow"), ('\u{1F329}', "cloud_with_lightning"),
+    ('\u{1F32A}', "cloud_with_tornado"), ('\u{1F32B}', "fog"), ('\u{1F32C}', "wind_blowing_face"),
+    ('\u{1F32D}', "hot_dog"), ('\u{1F32E}', "taco"), ('\u{1F32F}', "burrito"),
+    ('\u{1F330}', "chestnut"), ('\u{1F331}', "seedling"), ('\u{1F332}', "evergreen_tree"),
+    ('\u{1F333}', "deciduous_tree"), ('\u{1F334}', "palm_tree"), ('\u{1F335}', "cactus"),
+    ('\u{1F336}', "hot_pepper"), ('\u{1F337}', "tulip"), ('\u{1F338}', "cherry_blossom"),
+    ('\u{1F339}', "rose"), ('\u{1F33A}', "hibiscus"), ('\u{1F33B}', "sunflower"),
+    ('\u{1F33C}', "blossom"), ('\u{1F33D}', "ear_of_maize"), ('\u{1F33E}', "ear_of_rice"),
+    ('\u{1F33F}', "herb"), ('\u{1F340}', "four_leaf_clover"), ('\u{1F341}', "maple_leaf"),
+    ('\u{1F342}', "fallen_leaf"), ('\u{1F343}', "leaf_fluttering_in_wind"),
+    ('\u{1F344}', "mushroom"), ('\u{1F345}', "tomato"), ('\u{1F346}', "aubergine"),
+    ('\u{1F347}', "grapes"), ('\u{1F348}', "melon"), ('\u{1F349}', "watermelon"),
+    ('\u{1F34A}', "tangerine"), ('\u{1F34B}', "lemon"), ('\u{1F34C}', "banana"),
+    ('\u{1F34D}', "pineapple"), ('\u{1F34E}', "red_apple"), ('\u{1F34F}', "green_apple"),
+    ('\u{1F350}', "pear"), ('\u{1F351}', "peach"), ('\u{1F352}', "cherries"),
+    ('\u{1F353}', "strawberry"), ('\u{1F354}', "hamburger"), ('\u{1F355}', "slice_of_pizza"),
+    ('\u{1F356}', "meat_on_bone"), ('\u{1F357}', "poultry_leg"), ('\u{1F358}', "rice_cracker"),
+    ('\u{1F359}', "rice_ball"), ('\u{1F35A}', "cooked_rice"), ('\u{1F35B}', "curry_and_rice"),
+    ('\u{1F35C}', "steaming_bowl"), ('\u{1F35D}', "spaghetti"), ('\u{1F35E}', "bread"),
+    ('\u{1F35F}', "french_fries"), ('\u{1F360}', "roasted_sweet_potato"), ('\u{1F361}', "dango"),
+    ('\u{1F362}', "oden"), ('\u{1F363}', "sushi"), ('\u{1F364}', "fried_shrimp"),
+    ('\u{1F365}', "fish_cake_with_swirl_design"), ('\u{1F366}', "soft_ice_cream"),
+    ('\u{1F367}', "shaved_ice"), ('\u{1F368}', "ice_cream"), ('\u{1F369}', "doughnut"),
+    ('\u{1F36A}', "cookie"), ('\u{1F36B}', "chocolate_bar"), ('\u{1F36C}', "candy"),
+    ('\u{1F36D}', "lollipop"), ('\u{1F36E}', "custard"), ('\u{1F36F}', "honey_pot"),
+    ('\u{1F370}', "shortcake"), ('\u{1F371}', "bento_box"), ('\u{1F372}', "pot_of_food"),
+    ('\u{1F373}', "cooking"), ('\u{1F374}', "fork_and_knife"),
+    ('\u{1F375}', "teacup_without_handle"), ('\u{1F376}', "sake_bottle_and_cup"),
+    ('\u{1F377}', "wine_glass"), ('\u{1F378}', "cocktail_glass"), ('\u{1F379}', "tropical_drink"),
+    ('\u{1F37A}', "beer_mug"), ('\u{1F37B}', "clinking_beer_mugs"), ('\u{1F37C}', "baby_bottle"),
+    ('\u{1F37D}', "fork_and_knife_with_plate"), ('\u{1F37E}', "bottle_with_popping_cork"),
+    ('\u{1F37F}', "popcorn"), ('\u{1F380}', "ribbon"), ('\u{1F381}', "wrapped_present"),
+    ('\u{1F382}', "birthday_cake"), ('\u{1F383}', "jack_o_lantern"),
+    ('\u{1F384}', "christmas_tree"), ('\u{1F385}', "father_christmas"), ('\u{1F386}', "fireworks"),
+    ('\u{1F387}', "firework_sparkler"), ('\u{1F388}', "balloon"), ('\u{1F389}', "party_popper"),
+    ('\u{1F38A}', "confetti_ball"), ('\u{1F38B}', "tanabata_tree"), ('\u{1F38C}', "crossed_flags"),
+    ('\u{1F38D}', "pine_decoration"), ('\u{1F38E}', "japanese_dolls"),
+    ('\u{1F38F}', "carp_streamer"), ('\u{1F390}', "wind_chime"),
+    ('\u{1F391}', "moon_viewing_ceremony"), ('\u{1F392}', "school_satchel"),
+    ('\u{1F393}', "graduation_cap"), ('\u{1F394}', "heart_with_tip_on_the_left"),
+    ('\u{1F395}', "bouquet_of_flowers"), ('\u{1F396}', "military_medal"),
+    ('\u{1F397}', "reminder_ribbon"), ('\u{1F398}', "musical_keyboard_with_jacks"),
+    ('\u{1F399}', "studio_microphone"), ('\u{1F39A}', "level_slider"),
+    ('\u{1F39B}', "control_knobs"), ('\u{1F39C}', "beamed_ascending_musical_notes"),
+    ('\u{1F39D}', "beamed_descending_musical_notes"), ('\u{1F39E}', "film_frames"),
+    ('\u{1F39F}', "admission_tickets"), ('\u{1F3A0}', "carousel_horse"),
+    ('\u{1F3A1}', "ferris_wheel"), ('\u{1F3A2}', "roller_coaster"),
+    ('\u{1F3A3}', "fishing_pole_and_fish"), ('\u{1F3A4}', "microphone"),
+    ('\u{1F3A5}', "movie_camera"), ('\u{1F3A6}', "cinema"), ('\u{1F3A7}', "headphone"),
+    ('\u{1F3A8}', "artist_palette"), ('\u{1F3A9}', "top_hat"), ('\u{1F3AA}', "circus_tent"),
+    ('\u{1F3AB}', "ticket"), ('\u{1F3AC}', "clapper_board"), ('\u{1F3AD}', "performing_arts"),
+    ('\u{1F3AE}', "video_game"), ('\u{1F3AF}', "direct_hit"), ('\u{1F3B0}', "slot_machine"),
+    ('\u{1F3B1}', "billiards"), ('\u{1F3B2}', "game_die"), ('\u{1F3B3}', "bowling"),
+    ('\u{1F3B4}', "flower_playing_cards"), ('\u{1F3B5}', "musical_note"),
+    ('\u{1F3B6}', "multiple_musical_notes"), ('\u{1F3B7}', "saxophone"), ('\u{1F3B8}', "guitar"),
+    ('\u{1F3B9}', "musical_keyboard"), ('\u{1F3BA}', "trumpet"), ('\u{1F3BB}', "violin"),
+    ('\u{1F3BC}', "musical_score"), ('\u{1F3BD}', "running_shirt_with_sash"),
+    ('\u{1F3BE}', "tennis_racquet_and_ball"), ('\u{1F3BF}', "ski_and_ski_boot"),
+    ('\u{1F3C0}', "basketball_and_hoop"), ('\u{1F3C1}', "chequered_flag"),
+    ('\u{1F3C2}', "snowboarder"), ('\u{1F3C3}', "runner"), ('\u{1F3C4}', "surfer"),
+    ('\u{1F3C5}', "sports_medal"), ('\u{1F3C6}', "trophy"), ('\u{1F3C7}', "horse_racing"),
+    ('\u{1F3C8}', "american_football"), ('\u{1F3C9}', "rugby_football"), ('\u{1F3CA}', "swimmer"),
+    ('\u{1F3CB}', "weight_lifter"), ('\u{1F3CC}', "golfer"), ('\u{1F3CD}', "racing_motorcycle"),
+    ('\u{1F3CE}', "racing_car"), ('\u{1F3CF}', "cricket_bat_and_ball"), ('\u{1F3D0}', "volleyball"),
+    ('\u{1F3D1}', "field_hockey_stick_and_ball"), ('\u{1F3D2}', "ice_hockey_stick_and_puck"),
+    ('\u{1F3D3}', "table_tennis_paddle_and_ball"), ('\u{1F3D4}', "snow_capped_mountain"),
+    ('\u{1F3D5}', "camping"), ('\u{1F3D6}', "beach_with_umbrella"),
+    ('\u{1F3D7}', "building_construction"), ('\u{1F3D8}', "house_buildings"),
+    ('\u{1F3D9}', "cityscape"), ('\u{1F3DA}', "derelict_house_building"),
+    ('\u{1F3DB}', "classical_building"), ('\u{1F3DC}', "desert"), ('\u{1F3DD}', "desert_island"),
+    ('\u{1F3DE}', "national_park"), ('\u{1F3DF}', "stadium"), ('\u{1F3E0}', "house_building"),
+    ('\u{1F3E1}', "house_with_garden"), ('\u{1F3E2}', "office_building"),
+    ('\u{1F3E3}', "japanese_post_office"), ('\u{1F3E4}', "european_post_office"),
+    ('\u{1F3E5}', "hospital"), ('\u{1F3E6}', "bank"), ('\u{1F3E7}', "automated_teller_machine"),
+    ('\u{1F3E8}', "hotel"), ('\u{1F3E9}', "love_hotel"), ('\u{1F3EA}', "convenience_store"),
+    ('\u{1F3EB}', "school"), ('\u{1F3EC}', "department_store"), ('\u{1F3ED}', "factory"),
+    ('\u{1F3EE}', "izakaya_lantern"), ('\u{1F3EF}', "japanese_castle"),
+    ('\u{1F3F0}', "european_castle"), ('\u{1F3F1}', "white_pennant"),
+    ('\u{1F3F2}', "black_pennant"), ('\u{1F3F3}', "waving_white_flag"),
+    ('\u{1F3F4}', "waving_black_flag"), ('\u{1F3F5}', "rosette"), ('\u{1F3F6}', "black_rosette"),
+    ('\u{1F3F7}', "label"), ('\u{1F3F8}', "badminton_racquet_and_shuttlecock"),
+    ('\u{1F3F9}', "bow_and_arrow"), ('\u{1F3FA}', "amphora"), ('\u{1F400}', "rat"),
+    ('\u{1F401}', "mouse"), ('\u{1F402}', "ox"), ('\u{1F403}', "water_buffalo"),
+    ('\u{1F404}', "cow"), ('\u{1F405}', "tiger"), ('\u{1F406}', "leopard"), ('\u{1F407}', "rabbit"),
+    ('\u{1F408}', "cat"), ('\u{1F409}', "dragon"), ('\u{1F40A}', "crocodile"),
+    ('\u{1F40B}', "whale"), ('\u{1F40C}', "snail"), ('\u{1F40D}', "snake"), ('\u{1F40E}', "horse"),
+    ('\u{1F40F}', "ram"), ('\u{1F410}', "goat"), ('\u{1F411}', "sheep"), ('\u{1F412}', "monkey"),
+    ('\u{1F413}', "rooster"), ('\u{1F414}', "chicken"), ('\u{1F415}', "dog"), ('\u{1F416}', "pig"),
+    ('\u{1F417}', "boar"), ('\u{1F418}', "elephant"), ('\u{1F419}', "octopus"),
+    ('\u{1F41A}', "spiral_shell"), ('\u{1F41B}', "bug"), ('\u{1F41C}', "ant"),
+    ('\u{1F41D}', "honeybee"), ('\u{1F41E}', "lady_beetle"), ('\u{1F41F}', "fish"),
+    ('\u{1F420}', "tropical_fish"), ('\u{1F421}', "blowfish"), ('\u{1F422}', "turtle"),
+    ('\u{1F423}', "hatching_chick"), ('\u{1F424}', "baby_chick"),
+    ('\u{1F425}', "front_facing_baby_chick"), ('\u{1F426}', "bird"), ('\u{1F427}', "penguin"),
+    ('\u{1F428}', "koala"), ('\u{1F429}', "poodle"), ('\u{1F42A}', "dromedary_camel"),
+    ('\u{1F42B}', "bactrian_camel"), ('\u{1F42C}', "dolphin"), ('\u{1F42D}', "mouse_face"),
+    ('\u{1F42E}', "cow_face"), ('\u{1F42F}', "tiger_face"), ('\u{1F430}', "rabbit_face"),
+    ('\u{1F431}', "cat_face"), ('\u{1F432}', "dragon_face"), ('\u{1F433}', "spouting_whale"),
+    ('\u{1F434}', "horse_face"), ('\u{1F435}', "monkey_face"), ('\u{1F436}', "dog_face"),
+    ('\u{1F437}', "pig_face"), ('\u{1F438}', "frog_face"), ('\u{1F439}', "hamster_face"),
+    ('\u{1F43A}', "wolf_face"), ('\u{1F43B}', "bear_face"), ('\u{1F43C}', "panda_face"),
+    ('\u{1F43D}', "pig_nose"), ('\u{1F43E}', "paw_prints"), ('\u{1F43F}', "chipmunk"),
+    ('\u{1F440}', "eyes"), ('\u{1F441}', "eye"), ('\u{1F442}', "ear"), ('\u{1F443}', "nose"),
+    ('\u{1F444}', "mouth"), ('\u{1F445}', "tongue"),
+    ('\u{1F446}', "white_up_pointing_backhand_index"),
+    ('\u{1F447}', "white_down_pointing_backhand_index"),
+    ('\u{1F448}', "white_left_pointing_backhand_index"),
+    ('\u{1F449}', "white_right_pointing_backhand_index"), ('\u{1F44A}', "fisted_hand_sign"),
+    ('\u{1F44B}', "waving_hand_sign"), ('\u{1F44C}', "ok_hand_sign"),
+    ('\u{1F44D}', "thumbs_up_sign"), ('\u{1F44E}', "thumbs_down_sign"),
+    ('\u{1F44F}', "clapping_hands_sign"), ('\u{1F450}', "open_hands_sign"), ('\u{1F451}', "crown"),
+    ('\u{1F452}', "womans_hat"), ('\u{1F453}', "eyeglasses"), ('\u{1F454}', "necktie"),
+    ('\u{1F455}', "t_shirt"), ('\u{1F456}', "jeans"), ('\u{1F457}', "dress"),
+    ('\u{1F458}', "kimono"), ('\u{1F459}', "bikini"), ('\u{1F45A}', "womans_clothes"),
+    ('\u{1F45B}', "purse"), ('\u{1F45C}', "handbag"), ('\u{1F45D}', "pouch"),
+    ('\u{1F45E}', "mans_shoe"), ('\u{1F45F}', "athletic_shoe"), ('\u{1F460}', "high_heeled_shoe"),
+    ('\u{1F461}', "womans_sandal"), ('\u{1F462}', "womans_boots"), ('\u{1F463}', "footprints"),
+    ('\u{1F464}', "bust_in_silhouette"), ('\u{1F465}', "busts_in_silhouette"), ('\u{1F466}', "boy"),
+    ('\u{1F467}', "girl"), ('\u{1F468}', "man"), ('\u{1F469}', "woman"), ('\u{1F46A}', "family"),
+    ('\u{1F46B}', "man_and_woman_holding_hands"), ('\u{1F46C}', "two_men_holding_hands"),
+    ('\u{1F46D}', "two_women_holding_hands"), ('\u{1F46E}', "police_officer"),
+    ('\u{1F46F}', "woman_with_bunny_ears"), ('\u{1F470}', "bride_with_veil"),
+    ('\u{1F471}', "person_with_blond_hair"), ('\u{1F472}', "man_with_gua_pi_mao"),
+    ('\u{1F473}', "man_with_turban"), ('\u{1F474}', "older_man"), ('\u{1F475}', "older_woman"),
+    ('\u{1F476}', "baby"), ('\u{1F477}', "construction_worker"), ('\u{1F478}', "princess"),
+    ('\u{1F479}', "japanese_ogre"), ('\u{1F47A}', "japanese_goblin"), ('\u{1F47B}', "ghost"),
+    ('\u{1F47C}', "baby_angel"), ('\u{1F47D}', "extraterrestrial_alien"),
+    ('\u{1F47E}', "alien_monster"), ('\u{1F47F}', "imp"), ('\u{1F480}', "skull"),
+    ('\u{1F481}', "information_desk_person"), ('\u{1F482}', "guardsman"), ('\u{1F483}', "dancer"),
+    ('\u{1F484}', "lipstick"), ('\u{1F485}', "nail_polish"), ('\u{1F486}', "face_massage"),
+    ('\u{1F487}', "haircut"), ('\u{1F488}', "barber_pole"), ('\u{1F489}', "syringe"),
+    ('\u{1F48A}', "pill"), ('\u{1F48B}', "kiss_mark"), ('\u{1F48C}', "love_letter"),
+    ('\u{1F48D}', "ring"), ('\u{1F48E}', "gem_stone"), ('\u{1F48F}', "kiss"),
+    ('\u{1F490}', "bouquet"), ('\u{1F491}', "couple_with_heart"), ('\u{1F492}', "wedding"),
+    ('\u{1F493}', "beating_heart"), ('\u{1F494}', "broken_heart"), ('\u{1F495}', "two_hearts"),
+    ('\u{1F496}', "sparkling_heart"), ('\u{1F497}', "growing_heart"),
+    ('\u{1F498}', "heart_with_arrow"), ('\u{1F499}', "blue_heart"), ('\u{1F49A}', "green_heart"),
+    ('\u{1F49B}', "yellow_heart"), ('\u{1F49C}', "purple_heart"),
+    ('\u{1F49D}', "heart_with_ribbon"), ('\u{1F49E}', "revolving_hearts"),
+    ('\u{1F49F}', "heart_decoration"), ('\u{1F4A0}', "diamond_shape_with_a_dot_inside"),
+    ('\u{1F4A1}', "electric_light_bulb"), ('\u{1F4A2}', "anger_symbol"), ('\u{1F4A3}', "bomb"),
+    ('\u{1F4A4}', "sleeping_symbol"), ('\u{1F4A5}', "collision_symbol"),
+    ('\u{1F4A6}', "splashing_sweat_symbol"), ('\u{1F4A7}', "droplet"), ('\u{1F4A8}', "dash_symbol"),
+    ('\u{1F4A9}', "pile_of_poo"), ('\u{1F4AA}', "flexed_biceps"), ('\u{1F4AB}', "dizzy_symbol"),
+    ('\u{1F4AC}', "speech_balloon"), ('\u{1F4AD}', "thought_balloon"),
+    ('\u{1F4AE}', "white_flower"), ('\u{1F4AF}', "hundred_points_symbol"),
+    ('\u{1F4B0}', "money_bag"), ('\u{1F4B1}', "currency_exchange"),
+    ('\u{1F4B2}', "heavy_dollar_sign"), ('\u{1F4B3}', "credit_card"),
+    ('\u{1F4B4}', "banknote_with_yen_sign"), ('\u{1F4B5}', "banknote_with_dollar_sign"),
+    ('\u{1F4B6}', "banknote_with_euro_sign"), ('\u{1F4B7}', "banknote_with_pound_sign"),
+    ('\u{1F4B8}', "money_with_wings"), ('\u{1F4B9}', "chart_with_upwards_trend_and_yen_sign"),
+    ('\u{1F4BA}', "seat"), ('\u{1F4BB}', "personal_computer"), ('\u{1F4BC}', "briefcase"),
+    ('\u{1F4BD}', "minidisc"), ('\u{1F4BE}', "floppy_disk"), ('\u{1F4BF}', "optical_disc"),
+    ('\u{1F4C0}', "dvd"), ('\u{1F4C1}', "file_folder"), ('\u{1F4C2}', "open_file_folder"),
+    ('\u{1F4C3}', "page_with_curl"), ('\u{1F4C4}', "page_facing_up"), ('\u{1F4C5}', "calendar"),
+    ('\u{1F4C6}', "tear_off_calendar"), ('\u{1F4C7}', "card_index"),
+    ('\u{1F4C8}', "chart_with_upwards_trend"), ('\u{1F4C9}', "chart_with_downwards_trend"),
+    ('\u{1F4CA}', "bar_chart"), ('\u{1F4CB}', "clipboard"), ('\u{1F4CC}', "pushpin"),
+    ('\u{1F4CD}', "round_pushpin"), ('\u{1F4CE}', "paperclip"), ('\u{1F4CF}', "straight_ruler"),
+    ('\u{1F4D0}', "triangular_ruler"), ('\u{1F4D1}', "bookmark_tabs"), ('\u{1F4D2}', "ledger"),
+    ('\u{1F4D3}', "notebook"), ('\u{1F4D4}', "notebook_with_decorative_cover"),
+    ('\u{1F4D5}', "closed_book"), ('\u{1F4D6}', "open_book"), ('\u{1F4D7}', "green_book"),
+    ('\u{1F4D8}', "blue_book"), ('\u{1F4D9}', "orange_book"), ('\u{1F4DA}', "books"),
+    ('\u{1F4DB}', "name_badge"), ('\u{1F4DC}', "scroll"), ('\u{1F4DD}', "memo"),
+    ('\u{1F4DE}', "telephone_receiver"), ('\u{1F4DF}', "pager"), ('\u{1F4E0}', "fax_machine"),
+    ('\u{1F4E1}', "satellite_antenna"), ('\u{1F4E2}', "public_address_loudspeaker"),
+    ('\u{1F4E3}', "cheering_megaphone"), ('\u{1F4E4}', "outbox_tray"), ('\u{1F4E5}', "inbox_tray"),
+    ('\u{1F4E6}', "package"), ('\u{1F4E7}', "e_mail_symbol"), ('\u{1F4E8}', "incoming_envelope"),
+    ('\u{1F4E9}', "envelope_with_downwards_arrow_above"),
+    ('\u{1F4EA}', "closed_mailbox_with_lowered_flag"),
+    ('\u{1F4EB}', "closed_mailbox_with_raised_flag"),
+    ('\u{1F4EC}', "open_mailbox_with_raised_flag"), ('\u{1F4ED}', "open_mailbox_with_lowered_flag"),
+    ('\u{1F4EE}', "postbox"), ('\u{1F4EF}', "postal_horn"), ('\u{1F4F0}', "newspaper"),
+    ('\u{1F4F1}', "mobile_phone"), ('\u{1F4F2}', "mobile_phone_with_rightwards_arrow_at_left"),
+    ('\u{1F4F3}', "vibration_mode"), ('\u{1F4F4}', "mobile_phone_off"),
+    ('\u{1F4F5}', "no_mobile_phones"), ('\u{1F4F6}', "antenna_with_bars"), ('\u{1F4F7}', "camera"),
+    ('\u{1F4F8}', "camera_with_flash"), ('\u{1F4F9}', "video_camera"), ('\u{1F4FA}', "television"),
+    ('\u{1F4FB}', "radio"), ('\u{1F4FC}', "videocassette"), ('\u{1F4FD}', "film_projector"),
+    ('\u{1F4FE}', "portable_stereo"), ('\u{1F4FF}', "prayer_beads"),
+    ('\u{1F500}', "twisted_rightwards_arrows"),
+    ('\u{1F501}', "clockwise_rightwards_and_leftwards_open_circle_arrows"),
+    ('\u{1F502}', "clockwise_rightwards_and_leftwards_open_circle_arrows_with_circled_one_overlay"),
+    ('\u{1F503}', "clockwise_downwards_and_upwards_open_circle_arrows"),
+    ('\u{1F504}', "anticlockwise_downwards_and_upwards_open_circle_arrows"),
+    ('\u{1F505}', "low_brightness_symbol"), ('\u{1F506}', "high_brightness_symbol"),
+    ('\u{1F507}', "speaker_with_cancellation_stroke"), ('\u{1F508}', "speaker"),
+    ('\u{1F509}', "speaker_with_one_sound_wave"), ('\u{1F50A}', "speaker_with_three_sound_waves"),
+    ('\u{1F50B}', "battery"), ('\u{1F50C}', "electric_plug"),
+    ('\u{1F50D}', "left_pointing_magnifying_glass"),
+    ('\u{1F50E}', "right_pointing_magnifying_glass"), ('\u{1F50F}', "lock_with_ink_pen"),
+    ('\u{1F510}', "closed_lock_with_key"), ('\u{1F511}', "key"), ('\u{1F512}', "lock"),
+    ('\u{1F513}', "open_lock"), ('\u{1F514}', "bell"),
+    ('\u{1F515}', "bell_with_cancellation_stroke"), ('\u{1F516}', "bookmark"),
+    ('\u{1F517}', "link_symbol"), ('\u{1F518}', "radio_button"),
+    ('\u{1F519}', "back_with_leftwards_arrow_above"),
+    ('\u{1F51A}', "end_with_leftwards_arrow_above"),
+    ('\u{1F51B}', "on_with_exclamation_mark_with_left_right_arrow_above"),
+    ('\u{1F51C}', "soon_with_rightwards_arrow_above"),
+    ('\u{1F51D}', "top_with_upwards_arrow_above"), ('\u{1F51E}', "no_one_under_eighteen_symbol"),
+    ('\u{1F51F}', "keycap_ten"), ('\u{1F520}', "input_symbol_for_latin_capital_letters"),
+    ('\u{1F521}', "input_symbol_for_latin_small_letters"),
+    ('\u{1F522}', "input_symbol_for_numbers"), ('\u{1F523}', "input_symbol_for_symbols"),
+    ('\u{1F524}', "input_symbol_for_latin_letters"), ('\u{1F525}', "fire"),
+    ('\u{1F526}', "electric_torch"), ('\u{1F527}', "wrench"), ('\u{1F528}', "hammer"),
+    ('\u{1F529}', "nut_and_bolt"), ('\u{1F52A}', "hocho"), ('\u{1F52B}', "pistol"),
+    ('\u{1F52C}', "microscope"), ('\u{1F52D}', "telescope"), ('\u{1F52E}', "crystal_ball"),
+    ('\u{1F52F}', "six_pointed_star_with_middle_dot"),
+    ('\u{1F530}', "japanese_symbol_for_beginner"), ('\u{1F531}', "trident_emblem"),
+    ('\u{1F532}', "black_square_button"), ('\u{1F533}', "white_square_button"),
+    ('\u{1F534}', "large_red_circle"), ('\u{1F535}', "large_blue_circle"),
+    ('\u{1F536}', "large_orange_diamond"), ('\u{1F537}', "large_blue_diamond"),
+    ('\u{1F538}', "small_orange_diamond"), ('\u{1F539}', "small_blue_diamond"),
+    ('\u{1F53A}', "up_pointing_red_triangle"), ('\u{1F53B}', "down_pointing_red_triangle"),
+    ('\u{1F53C}', "up_pointing_small_red_triangle"),
+    ('\u{1F53D}', "down_pointing_small_red_triangle"),
+    ('\u{1F53E}', "lower_right_shadowed_white_circle"),
+    ('\u{1F53F}', "upper_right_shadowed_white_circle"), ('\u{1F540}', "circled_cross_pommee"),
+    ('\u{1F541}', "cross_pommee_with_half_circle_below"), ('\u{1F542}', "cross_pommee"),
+    ('\u{1F543}', "notched_left_semicircle_with_three_dots"),
+    ('\u{1F544}', "notched_right_semicircle_with_three_dots"),
+    ('\u{1F545}', "symbol_for_marks_chapter"), ('\u{1F546}', "white_latin_cross"),
+    ('\u{1F547}', "heavy_latin_cross"), ('\u{1F548}', "celtic_cross"), ('\u{1F549}', "om_symbol"),
+    ('\u{1F54A}', "dove_of_peace"), ('\u{1F54B}', "kaaba"), ('\u{1F54C}', "mosque"),
+    ('\u{1F54D}', "synagogue"), ('\u{1F54E}', "menorah_with_nine_branches"),
+    ('\u{1F54F}', "bowl_of_hygieia"), ('\u{1F550}', "clock_face_one_oclock"),
+    ('\u{1F551}', "clock_face_two_oclock"), ('\u{1F552}', "clock_face_three_oclock"),
+    ('\u{1F553}', "clock_face_four_oclock"), ('\u{1F554}', "clock_face_five_oclock"),
+    ('\u{1F555}', "clock_face_six_oclock"), ('\u{1F556}', "clock_face_seven_oclock"),
+    ('\u{1F557}', "clock_face_eight_oclock"), ('\u{1F558}', "clock_face_nine_oclock"),
+    ('\u{1F559}', "clock_face_ten_oclock"), ('\u{1F55A}', "clock_face_eleven_oclock"),
+    ('\u{1F55B}', "clock_face_twelve_oclock"), ('\u{1F55C}', "clock_face_one_thirty"),
+    ('\u{1F55D}', "clock_face_two_thirty"), ('\u{1F55E}', "clock_face_three_thirty"),
+    ('\u{1F55F}', "clock_face_four_thirty"), ('\u{1F560}', "clock_face_five_thirty"),
+    ('\u{1F561}', "clock_face_six_thirty"), ('\u{1F562}', "clock_face_seven_thirty"),
+    ('\u{1F563}', "clock_face_eight_thirty"), ('\u{1F564}', "clock_face_nine_thirty"),
+    ('\u{1F565}', "clock_face_ten_thirty"), ('\u{1F566}', "clock_face_eleven_thirty"),
+    ('\u{1F567}', "clock_face_twelve_thirty"), ('\u{1F568}', "right_speaker"),
+    ('\u{1F569}', "right_speaker_with_one_sound_wave"),
+    ('\u{1F56A}', "right_speaker_with_three_sound_waves"), ('\u{1F56B}', "bullhorn"),
+    ('\u{1F56C}', "bullhorn_with_sound_waves"), ('\u{1F56D}', "ringing_bell"),
+    ('\u{1F56E}', "book"), ('\u{1F56F}', "candle"), ('\u{1F570}', "mantelpiece_clock"),
+    ('\u{1F571}', "black_skull_and_crossbones"), ('\u{1F572}', "no_piracy"), ('\u{1F573}', "hole"),
+    ('\u{1F574}', "man_in_business_suit_levitating"), ('\u{1F575}', "sleuth_or_spy"),
+    ('\u{1F576}', "dark_sunglasses"), ('\u{1F577}', "spider"), ('\u{1F578}', "spider_web"),
+    ('\u{1F579}', "joystick"), ('\u{1F57A}', "man_dancing"),
+    ('\u{1F57B}', "left_hand_telephone_receiver"), ('\u{1F57C}', "telephone_receiver_with_page"),
+    ('\u{1F57D}', "right_hand_telephone_receiver"), ('\u{1F57E}', "white_touchtone_telephone"),
+    ('\u{1F57F}', "black_touchtone_telephone"), ('\u{1F580}', "telephone_on_top_of_modem"),
+    ('\u{1F581}', "clamshell_mobile_phone"), ('\u{1F582}', "back_of_envelope"),
+    ('\u{1F583}', "stamped_envelope"), ('\u{1F584}', "envelope_with_lightning"),
+    ('\u{1F585}', "flying_envelope"), ('\u{1F586}', "pen_over_stamped_envelope"),
+    ('\u{1F587}', "linked_paperclips"), ('\u{1F588}', "black_pushpin"),
+    ('\u{1F589}', "lower_left_pencil"), ('\u{1F58A}', "lower_left_ballpoint_pen"),
+    ('\u{1F58B}', "lower_left_fountain_pen"), ('\u{1F58C}', "lower_left_paintbrush"),
+    ('\u{1F58D}', "lower_left_crayon"), ('\u{1F58E}', "left_writing_hand"),
+    ('\u{1F58F}', "turned_ok_hand_sign"), ('\u{1F590}', "raised_hand_with_fingers_splayed"),
+    ('\u{1F591}', "reversed_raised_hand_with_fingers_splayed"),
+    ('\u{1F592}', "reversed_thumbs_up_sign"), ('\u{1F593}', "reversed_thumbs_down_sign"),
+    ('\u{1F594}', "reversed_victory_hand"),
+    ('\u{1F595}', "reversed_hand_with_middle_finger_extended"),
+    ('\u{1F596}', "raised_hand_with_part_between_middle_and_ring_fingers"),
+    ('\u{1F597}', "white_down_pointing_left_hand_index"),
+    ('\u{1F598}', "sideways_white_left_pointing_index"),
+    ('\u{1F599}', "sideways_white_right_pointing_index"),
+    ('\u{1F59A}', "sideways_black_left_pointing_index"),
+    ('\u{1F59B}', "sideways_black_right_pointing_index"),
+    ('\u{1F59C}', "black_left_pointing_backhand_index"),
+    ('\u{1F59D}', "black_right_pointing_backhand_index"),
+    ('\u{1F59E}', "sideways_white_up_pointing_index"),
+    ('\u{1F59F}', "sideways_white_down_pointing_index"),
+    ('\u{1F5A0}', "sideways_black_up_pointing_index"),
+    ('\u{1F5A1}', "sideways_black_down_pointing_index"),
+    ('\u{1F5A2}', "black_up_pointing_backhand_index"),
+    ('\u{1F5A3}', "black_down_pointing_backhand_index"), ('\u{1F5A4}', "black_heart"),
+    ('\u{1F5A5}', "desktop_computer"), ('\u{1F5A6}', "keyboard_and_mouse"),
+    ('\u{1F5A7}', "three_networked_computers"), ('\u{1F5A8}', "printer"),
+    ('\u{1F5A9}', "pocket_calculator"), ('\u{1F5AA}', "black_hard_shell_floppy_disk"),
+    ('\u{1F5AB}', "white_hard_shell_floppy_disk"), ('\u{1F5AC}', "soft_shell_floppy_disk"),
+    ('\u{1F5AD}', "tape_cartridge"), ('\u{1F5AE}', "wired_keyboard"),
+    ('\u{1F5AF}', "one_button_mouse"), ('\u{1F5B0}', "two_button_mouse"),
+    ('\u{1F5B1}', "three_button_mouse"), ('\u{1F5B2}', "trackball"),
+    ('\u{1F5B3}', "old_personal_computer"), ('\u{1F5B4}', "hard_disk"), ('\u{1F5B5}', "screen"),
+    ('\u{1F5B6}', "printer_icon"), ('\u{1F5B7}', "fax_icon"), ('\u{1F5B8}', "optical_disc_icon"),
+    ('\u{1F5B9}', "document_with_text"), ('\u{1F5BA}', "document_with_text_and_picture"),
+    ('\u{1F5BB}', "document_with_picture"), ('\u{1F5BC}', "frame_with_picture"),
+    ('\u{1F5BD}', "frame_with_tiles"), ('\u{1F5BE}', "frame_with_an_x"),
+    ('\u{1F5BF}', "black_folder"), ('\u{1F5C0}', "folder"), ('\u{1F5C1}', "open_folder"),
+    ('\u{1F5C2}', "card_index_dividers"), ('\u{1F5C3}', "card_file_box"),
+    ('\u{1F5C4}', "file_cabinet"), ('\u{1F5C5}', "empty_note"), ('\u{1F5C6}', "empty_note_page"),
+    ('\u{1F5C7}', "empty_note_pad"), ('\u{1F5C8}', "note"), ('\u{1F5C9}', "note_page"),
+    ('\u{1F5CA}', "note_pad"), ('\u{1F5CB}', "empty_document"), ('\u{1F5CC}', "empty_page"),
+    ('\u{1F5CD}', "empty_pages"), ('\u{1F5CE}', "document"), ('\u{1F5CF}', "page"),
+    ('\u{1F5D0}', "pages"), ('\u{1F5D1}', "wastebasket"), ('\u{1F5D2}', "spiral_note_pad"),
+    ('\u{1F5D3}', "spiral_calendar_pad"), ('\u{1F5D4}', "desktop_window"),
+    ('\u{1F5D5}', "minimize"), ('\u{1F5D6}', "maximize"), ('\u{1F5D7}', "overlap"),
+    ('\u{1F5D8}', "clockwise_right_and_left_semicircle_arrows"), ('\u{1F5D9}', "cancellation_x"),
+    ('\u{1F5DA}', "increase_font_size_symbol"), ('\u{1F5DB}', "decrease_font_size_symbol"),
+    ('\u{1F5DC}', "compression"), ('\u{1F5DD}', "old_key"), ('\u{1F5DE}', "rolled_up_newspaper"),
+    ('\u{1F5DF}', "page_with_circled_text"), ('\u{1F5E0}', "stock_chart"),
+    ('\u{1F5E1}', "dagger_knife"), ('\u{1F5E2}', "lips"),
+    ('\u{1F5E3}', "speaking_head_in_silhouette"), ('\u{1F5E4}', "three_rays_above"),
+    ('\u{1F5E5}', "three_rays_below"), ('\u{1F5E6}', "three_rays_left"),
+    ('\u{1F5E7}', "three_rays_right"), ('\u{1F5E8}', "left_speech_bubble"),
+    ('\u{1F5E9}', "right_speech_bubble"), ('\u{1F5EA}', "two_speech_bubbles"),
+    ('\u{1F5EB}', "three_speech_bubbles"), ('\u{1F5EC}', "left_thought_bubble"),
+    ('\u{1F5ED}', "right_thought_bubble"), ('\u{1F5EE}', "left_anger_bubble"),
+    ('\u{1F5EF}', "right_anger_bubble"), ('\u{1F5F0}', "mood_bubble"),
+    ('\u{1F5F1}', "lightning_mood_bubble"), ('\u{1F5F2}', "lightning_mood"),
+    ('\u{1F5F3}', "ballot_box_with_ballot"), ('\u{1F5F4}', "ballot_script_x"),
+    ('\u{1F5F5}', "ballot_box_with_script_x"), ('\u{1F5F6}', "ballot_bold_script_x"),
+    ('\u{1F5F7}', "ballot_box_with_bold_script_x"), ('\u{1F5F8}', "light_check_mark"),
+    ('\u{1F5F9}', "ballot_box_with_bold_check"), ('\u{1F5FA}', "world_map"),
+    ('\u{1F5FB}', "mount_fuji"), ('\u{1F5FC}', "tokyo_tower"), ('\u{1F5FD}', "statue_of_liberty"),
+    ('\u{1F5FE}', "silhouette_of_japan"), ('\u{1F5FF}', "moyai"), ('\u{1F600}', "grinning_face"),
+    ('\u{1F601}', "grinning_face_with_smiling_eyes"), ('\u{1F602}', "face_with_tears_of_joy"),
+    ('\u{1F603}', "smiling_face_with_open_mouth"),
+    ('\u{1F604}', "smiling_face_with_open_mouth_and_smiling_eyes"),
+    ('\u{1F605}', "smiling_face_with_open_mouth_and_cold_sweat"),
+    ('\u{1F606}', "smiling_face_with_open_mouth_and_tightly_closed_eyes"),
+    ('\u{1F607}', "smiling_face_with_halo"), ('\u{1F608}', "smiling_face_with_horns"),
+    ('\u{1F609}', "winking_face"), ('\u{1F60A}', "smiling_face_with_smiling_eyes"),
+    ('\u{1F60B}', "face_savouring_delicious_food"), ('\u{1F60C}', "relieved_face"),
+    ('\u{1F60D}', "smiling_face_with_heart_shaped_eyes"),
+    ('\u{1F60E}', "smiling_face_with_sunglasses"), ('\u{1F60F}', "smirking_face"),
+    ('\u{1F610}', "neutral_face"), ('\u{1F611}', "expressionless_face"),
+    ('\u{1F612}', "unamused_face"), ('\u{1F613}', "face_with_cold_sweat"),
+    ('\u{1F614}', "pensive_face"), ('\u{1F615}', "confused_face"), ('\u{1F616}', "confounded_face"),
+    ('\u{1F617}', "kissing_face"), ('\u{1F618}', "face_throwing_a_kiss"),
+    ('\u{1F619}', "kissing_face_with_smiling_eyes"), ('\u{1F61A}', "kissing_face_with_closed_eyes"),
+    ('\u{1F61B}', "face_with_stuck_out_tongue"),
+    ('\u{1F61C}', "face_with_stuck_out_tongue_and_winking_eye"),
+    ('\u{1F61D}', "face_with_stuck_out_tongue_and_tightly_closed_eyes"),
+    ('\u{1F61E}', "disappointed_face"), ('\u{1F61F}', "worried_face"), ('\u{1F620}', "angry_face"),
+    ('\u{1F621}', "pouting_face"), ('\u{1F622}', "crying_face"), ('\u{1F623}', "persevering_face"),
+    ('\u{1F624}', "face_with_look_of_triumph"), ('\u{1F625}', "disappointed_but_relieved_face"),
+    ('\u{1F626}', "frowning_face_with_open_mouth"), ('\u{1F627}', "anguished_face"),
+    ('\u{1F628}', "fearful_face"), ('\u{1F629}', "weary_face"), ('\u{1F62A}', "sleepy_face"),
+    ('\u{1F62B}', "tired_face"), ('\u{1F62C}', "grimacing_face"),
+    ('\u{1F62D}', "loudly_crying_face"), ('\u{1F62E}', "face_with_open_mouth"),
+    ('\u{1F62F}', "hushed_face"), ('\u{1F630}', "face_with_open_mouth_and_cold_sweat"),
+    ('\u{1F631}', "face_screaming_in_fear"), ('\u{1F632}', "astonished_face"),
+    ('\u{1F633}', "flushed_face"), ('\u{1F634}', "sleeping_face"), ('\u{1F635}', "dizzy_face"),
+    ('\u{1F636}', "face_without_mouth"), ('\u{1F637}', "face_with_medical_mask"),
+    ('\u{1F638}', "grinning_cat_face_with_smiling_eyes"),
+    ('\u{1F639}', "cat_face_with_tears_of_joy"), ('\u{1F63A}', "smiling_cat_face_with_open_mouth"),
+    ('\u{1F63B}', "smiling_cat_face_with_heart_shaped_eyes"),
+    ('\u{1F63C}', "cat_face_with_wry_smile"), ('\u{1F63D}', "kissing_cat_face_with_closed_eyes"),
+    ('\u{1F63E}', "pouting_cat_face"), ('\u{1F63F}', "crying_cat_face"),
+    ('\u{1F640}', "weary_cat_face"), ('\u{1F641}', "slightly_frowning_face"),
+    ('\u{1F642}', "slightly_smiling_face"), ('\u{1F643}', "upside_down_face"),
+    ('\u{1F644}', "face_with_rolling_eyes"), ('\u{1F645}', "face_with_no_good_gesture"),
+    ('\u{1F646}', "face_with_ok_gesture"), ('\u{1F647}', "person_bowing_deeply"),
+    ('\u{1F648}', "see_no_evil_monkey"), ('\u{1F649}', "hear_no_evil_monkey"),
+    ('\u{1F64A}', "speak_no_evil_monkey"), ('\u{1F64B}', "happy_person_raising_one_hand"),
+    ('\u{1F64C}', "person_raising_both_hands_in_celebration"), ('\u{1F64D}', "person_frowning"),
+    ('\u{1F64E}', "person_with_pouting_face"), ('\u{1F64F}', "person_with_folded_hands"),
+    ('\u{1F650}', "north_west_pointing_leaf"), ('\u{1F651}', "south_west_pointing_leaf"),
+    ('\u{1F652}', "north_east_pointing_leaf"), ('\u{1F653}', "south_east_pointing_leaf"),
+    ('\u{1F654}', "turned_north_west_pointing_leaf"),
+    ('\u{1F655}', "turned_south_west_pointing_leaf"),
+    ('\u{1F656}', "turned_north_east_pointing_leaf"),
+    ('\u{1F657}', "turned_south_east_pointing_leaf"),
+    ('\u{1F658}', "north_west_pointing_vine_leaf"), ('\u{1F659}', "south_west_pointing_vine_leaf"),
+    ('\u{1F65A}', "north_east_pointing_vine_leaf"), ('\u{1F65B}', "south_east_pointing_vine_leaf"),
+    ('\u{1F65C}', "heavy_north_west_pointing_vine_leaf"),
+    ('\u{1F65D}', "heavy_south_west_pointing_vine_leaf"),
+    ('\u{1F65E}', "heavy_north_east_pointing_vine_leaf"),
+    ('\u{1F65F}', "heavy_south_east_pointing_vine_leaf"), ('\u{1F660}', "north_west_pointing_bud"),
+    ('\u{1F661}', "south_west_pointing_bud"), ('\u{1F662}', "north_east_pointing_bud"),
+    ('\u{1F663}', "south_east_pointing_bud"), ('\u{1F664}', "heavy_north_west_pointing_bud"),
+    ('\u{1F665}', "heavy_south_west_pointing_bud"), ('\u{1F666}', "heavy_north_east_pointing_bud"),
+    ('\u{1F667}', "heavy_south_east_pointing_bud"), ('\u{1F668}', "hollow_quilt_square_ornament"),
+    ('\u{1F669}', "hollow_quilt_square_ornament_in_black_square"),
+    ('\u{1F66A}', "solid_quilt_square_ornament"),
+    ('\u{1F66B}', "solid_quilt_square_ornament_in_black_square"), ('\u{1F66C}', "leftwards_rocket"),
+    ('\u{1F66D}', "upwards_rocket"), ('\u{1F66E}', "rightwards_rocket"),
+    ('\u{1F66F}', "downwards_rocket"), ('\u{1F670}', "script_ligature_et_ornament"),
+    ('\u{1F671}', "heavy_script_ligature_et_ornament"), ('\u{1F672}', "ligature_open_et_ornament"),
+    ('\u{1F673}', "heavy_ligature_open_et_ornament"), ('\u{1F674}', "heavy_ampersand_ornament"),
+    ('\u{1F675}', "swash_ampersand_ornament"),
+    ('\u{1F676}', "sans_serif_heavy_double_turned_comma_quotation_mark_ornament"),
+    ('\u{1F677}', "sans_serif_heavy_double_comma_quotation_mark_ornament"),
+    ('\u{1F678}', "sans_serif_heavy_low_double_comma_quotation_mark_ornament"),
+    ('\u{1F679}', "heavy_interrobang_ornament"), ('\u{1F67A}', "sans_serif_interrobang_ornament"),
+    ('\u{1F67B}', "heavy_sans_serif_interrobang_ornament"), ('\u{1F67C}', "very_heavy_solidus"),
+    ('\u{1F67D}', "very_heavy_reverse_solidus"), ('\u{1F67E}', "checker_board"),
+    ('\u{1F67F}', "reverse_checker_board"), ('\u{1F680}', "rocket"), ('\u{1F681}', "helicopter"),
+    ('\u{1F682}', "steam_locomotive"), ('\u{1F683}', "railway_car"),
+    ('\u{1F684}', "high_speed_train"), ('\u{1F685}', "high_speed_train_with_bullet_nose"),
+    ('\u{1F686}', "train"), ('\u{1F687}', "metro"), ('\u{1F688}', "light_rail"),
+    ('\u{1F689}', "station"), ('\u{1F68A}', "tram"), ('\u{1F68B}', "tram_car"),
+    ('\u{1F68C}', "bus"), ('\u{1F68D}', "oncoming_bus"), ('\u{1F68E}', "trolleybus"),
+    ('\u{1F68F}', "bus_stop"), ('\u{1F690}', "minibus"), ('\u{1F691}', "ambulance"),
+    ('\u{1F692}', "fire_engine"), ('\u{1F693}', "police_car"), ('\u{1F694}', "oncoming_police_car"),
+    ('\u{1F695}', "taxi"), ('\u{1F696}', "oncoming_taxi"), ('\u{1F697}', "automobile"),
+    ('\u{1F698}', "oncoming_automobile"), ('\u{1F699}', "recreational_vehicle"),
+    ('\u{1F69A}', "delivery_truck"), ('\u{1F69B}', "articulated_lorry"), ('\u{1F69C}', "tractor"),
+    ('\u{1F69D}', "monorail"), ('\u{1F69E}', "mountain_railway"),
+    ('\u{1F69F}', "suspension_railway"), ('\u{1F6A0}', "mountain_cableway"),
+    ('\u{1F6A1}', "aerial_tramway"), ('\u{1F6A2}', "ship"), ('\u{1F6A3}', "rowboat"),
+    ('\u{1F6A4}', "speedboat"), ('\u{1F6A5}', "horizontal_traffic_light"),
+    ('\u{1F6A6}', "vertical_traffic_light"), ('\u{1F6A7}', "construction_sign"),
+    ('\u{1F6A8}', "police_cars_revolving_light"), ('\u{1F6A9}', "triangular_flag_on_post"),
+    ('\u{1F6AA}', "door"), ('\u{1F6AB}', "no_entry_sign"), ('\u{1F6AC}', "smoking_symbol"),
+    ('\u{1F6AD}', "no_smoking_symbol"), ('\u{1F6AE}', "put_litter_in_its_place_symbol"),
+    ('\u{1F6AF}', "do_not_litter_symbol"), ('\u{1F6B0}', "potable_water_symbol"),
+    ('\u{1F6B1}', "non_potable_water_symbol"), ('\u{1F6B2}', "bicycle"),
+    ('\u{1F6B3}', "no_bicycles"), ('\u{1F6B4}', "bicyclist"), ('\u{1F6B5}', "mountain_bicyclist"),
+    ('\u{1F6B6}', "pedestrian"), ('\u{1F6B7}', "no_pedestrians"),
+    ('\u{1F6B8}', "children_crossing"), ('\u{1F6B9}', "mens_symbol"),
+    ('\u{1F6BA}', "womens_symbol"), ('\u{1F6BB}', "restroom"), ('\u{1F6BC}', "baby_symbol"),
+    ('\u{1F6BD}', "toilet"), ('\u{1F6BE}', "water_closet"), ('\u{1F6BF}', "shower"),
+    ('\u{1F6C0}', "bath"), ('\u{1F6C1}', "bathtub"), ('\u{1F6C2}', "passport_control"),
+    ('\u{1F6C3}', "customs"), ('\u{1F6C4}', "baggage_claim"), ('\u{1F6C5}', "left_luggage"),
+    ('\u{1F6C6}', "triangle_with_rounded_corners"), ('\u{1F6C7}', "prohibited_sign"),
+    ('\u{1F6C8}', "circled_information_source"), ('\u{1F6C9}', "boys_symbol"),
+    ('\u{1F6CA}', "girls_symbol"), ('\u{1F6CB}', "couch_and_lamp"),
+    ('\u{1F6CC}', "sleeping_accommodation"), ('\u{1F6CD}', "shopping_bags"),
+    ('\u{1F6CE}', "bellhop_bell"), ('\u{1F6CF}', "bed"), ('\u{1F6D0}', "place_of_worship"),
+    ('\u{1F6D1}', "octagonal_sign"), ('\u{1F6D2}', "shopping_trolley"), ('\u{1F6D3}', "stupa"),
+    ('\u{1F6D4}', "pagoda"), ('\u{1F6D5}', "hindu_temple"), ('\u{1F6D6}', "hut"),
+    ('\u{1F6D7}', "elevator"), ('\u{1F6DD}', "playground_slide"), ('\u{1F6DE}', "wheel"),
+    ('\u{1F6DF}', "ring_buoy"), ('\u{1F6E0}', "hammer_and_wrench"), ('\u{1F6E1}', "shield"),
+    ('\u{1F6E2}', "oil_drum"), ('\u{1F6E3}', "motorway"), ('\u{1F6E4}', "railway_track"),
+    ('\u{1F6E5}', "motor_boat"), ('\u{1F6E6}', "up_pointing_military_airplane"),
+    ('\u{1F6E7}', "up_pointing_airplane"), ('\u{1F6E8}', "up_pointing_small_airplane"),
+    ('\u{1F6E9}', "small_airplane"), ('\u{1F6EA}', "northeast_pointing_airplane"),
+    ('\u{1F6EB}', "airplane_departure"), ('\u{1F6EC}', "airplane_arriving"),
+    ('\u{1F6F0}', "satellite"), ('\u{1F6F1}', "oncoming_fire_engine"),
+    ('\u{1F6F2}', "diesel_locomotive"), ('\u{1F6F3}', "passenger_ship"), ('\u{1F6F4}', "scooter"),
+    ('\u{1F6F5}', "motor_scooter"), ('\u{1F6F6}', "canoe"), ('\u{1F6F7}', "sled"),
+    ('\u{1F6F8}', "flying_saucer"), ('\u{1F6F9}', "skateboard"), ('\u{1F6FA}', "auto_rickshaw"),
+    ('\u{1F6FB}', "pickup_truck"), ('\u{1F6FC}', "roller_skate"),
+    ('\u{1F780}', "black_left_pointing_isosceles_right_triangle"),
+    ('\u{1F781}', "black_up_pointing_isosceles_right_triangle"),
+    ('\u{1F782}', "black_right_pointing_isosceles_right_triangle"),
+    ('\u{1F783}', "black_down_pointing_isosceles_right_triangle"),
+    ('\u{1F784}', "black_slightly_small_circle"), ('\u{1F785}', "medium_bold_white_circle"),
+    ('\u{1F786}', "bold_white_circle"), ('\u{1F787}', "heavy_white_circle"),
+    ('\u{1F788}', "very_heavy_white_circle"), ('\u{1F789}', "extremely_heavy_white_circle"),
+    ('\u{1F78A}', "white_circle_containing_black_small_circle"), ('\u{1F78B}', "round_target"),
+    ('\u{1F78C}', "black_tiny_square"), ('\u{1F78D}', "black_slightly_small_square"),
+    ('\u{1F78E}', "light_white_square"), ('\u{1F78F}', "medium_white_square"),
+    ('\u{1F790}', "bold_white_square"), ('\u{1F791}', "heavy_white_square"),
+    ('\u{1F792}', "very_heavy_white_square"), ('\u{1F793}', "extremely_heavy_white_square"),
+    ('\u{1F794}', "white_square_containing_black_very_small_square"),
+    ('\u{1F795}', "white_square_containing_black_medium_square"), ('\u{1F796}', "square_target"),
+    ('\u{1F797}', "black_tiny_diamond"), ('\u{1F798}', "black_very_small_diamond"),
+    ('\u{1F799}', "black_medium_small_diamond"),
+    ('\u{1F79A}', "white_diamond_containing_black_very_small_diamond"),
+    ('\u{1F79B}', "white_diamond_containing_black_medium_diamond"), ('\u{1F79C}', "diamond_target"),
+    ('\u{1F79D}', "black_tiny_lozenge"), ('\u{1F79E}', "black_very_small_lozenge"),
+    ('\u{1F79F}', "black_medium_small_lozenge"),
+    ('\u{1F7A0}', "white_lozenge_containing_black_small_lozenge"),
+    ('\u{1F7A1}', "thin_greek_cross"), ('\u{1F7A2}', "light_greek_cross"),
+    ('\u{1F7A3}', "medium_greek_cross"), ('\u{1F7A4}', "bold_greek_cross"),
+    ('\u{1F7A5}', "very_bold_greek_cross"), ('\u{1F7A6}', "very_heavy_greek_cross"),
+    ('\u{1F7A7}', "extremely_heavy_greek_cross"), ('\u{1F7A8}', "thin_saltire"),
+    ('\u{1F7A9}', "light_saltire"), ('\u{1F7AA}', "medium_saltire"), ('\u{1F7AB}', "bold_saltire"),
+    ('\u{1F7AC}', "heavy_saltire"), ('\u{1F7AD}', "very_heavy_saltire"),
+    ('\u{1F7AE}', "extremely_heavy_saltire"), ('\u{1F7AF}', "light_five_spoked_asterisk"),
+    ('\u{1F7B0}', "medium_five_spoked_asterisk"), ('\u{1F7B1}', "bold_five_spoked_asterisk"),
+    ('\u{1F7B2}', "heavy_five_spoked_asterisk"), ('\u{1F7B3}', "very_heavy_five_spoked_asterisk"),
+    ('\u{1F7B4}', "extremely_heavy_five_spoked_asterisk"),
+    ('\u{1F7B5}', "light_six_spoked_asterisk"), ('\u{1F7B6}', "medium_six_spoked_asterisk"),
+    ('\u{1F7B7}', "bold_six_spoked_asterisk"), ('\u{1F7B8}', "heavy_six_spoked_asterisk"),
+    ('\u{1F7B9}', "very_heavy_six_spoked_asterisk"),
+    ('\u{1F7BA}', "extremely_heavy_six_spoked_asterisk"),
+    ('\u{1F7BB}', "light_eight_spoked_asterisk"), ('\u{1F7BC}', "medium_eight_spoked_asterisk"),
+    ('\u{1F7BD}', "bold_eight_spoked_asterisk"), ('\u{1F7BE}', "heavy_eight_spoked_asterisk"),
+    ('\u{1F7BF}', "very_heavy_eight_spoked_asterisk"),
+    ('\u{1F7C0}', "light_three_pointed_black_star"),
+    ('\u{1F7C1}', "medium_three_pointed_black_star"), ('\u{1F7C2}', "three_pointed_black_star"),
+    ('\u{1F7C3}', "medium_three_pointed_pinwheel_star"),
+    ('\u{1F7C4}', "light_four_pointed_black_star"), ('\u{1F7C5}', "medium_four_pointed_black_star"),
+    ('\u{1F7C6}', "four_pointed_black_star"), ('\u{1F7C7}', "medium_four_pointed_pinwheel_star"),
+    ('\u{1F7C8}', "reverse_light_four_pointed_pinwheel_star"),
+    ('\u{1F7C9}', "light_five_pointed_black_star"), ('\u{1F7CA}', "heavy_five_pointed_black_star"),
+    ('\u{1F7CB}', "medium_six_pointed_black_star"), ('\u{1F7CC}', "heavy_six_pointed_black_star"),
+    ('\u{1F7CD}', "six_pointed_pinwheel_star"), ('\u{1F7CE}', "medium_eight_pointed_black_star"),
+    ('\u{1F7CF}', "heavy_eight_pointed_black_star"),
+    ('\u{1F7D0}', "very_heavy_eight_pointed_black_star"),
+    ('\u{1F7D1}', "heavy_eight_pointed_pinwheel_star"),
+    ('\u{1F7D2}', "light_twelve_pointed_black_star"),
+    ('\u{1F7D3}', "heavy_twelve_pointed_black_star"),
+    ('\u{1F7D4}', "heavy_twelve_pointed_pinwheel_star"), ('\u{1F7D5}', "circled_triangle"),
+    ('\u{1F7D6}', "negative_circled_triangle"), ('\u{1F7D7}', "circled_square"),
+    ('\u{1F7D8}', "negative_circled_square"), ('\u{1F7E0}', "large_orange_circle"),
+    ('\u{1F7E1}', "large_yellow_circle"), ('\u{1F7E2}', "large_green_circle"),
+    ('\u{1F7E3}', "large_purple_circle"), ('\u{1F7E4}', "large_brown_circle"),
+    ('\u{1F7E5}', "large_red_square"), ('\u{1F7E6}', "large_blue_square"),
+    ('\u{1F7E7}', "large_orange_square"), ('\u{1F7E8}', "large_yellow_square"),
+    ('\u{1F7E9}', "large_green_square"), ('\u{1F7EA}', "large_purple_square"),
+    ('\u{1F7EB}', "large_brown_square"), ('\u{1F7F0}', "heavy_equals_sign"),
+    ('\u{1F900}', "circled_cross_formee_with_four_dots"),
+    ('\u{1F901}', "circled_cross_formee_with_two_dots"), ('\u{1F902}', "circled_cross_formee"),
+    ('\u{1F903}', "left_half_circle_with_four_dots"),
+    ('\u{1F904}', "left_half_circle_with_three_dots"),
+    ('\u{1F905}', "left_half_circle_with_two_dots"), ('\u{1F906}', "left_half_circle_with_dot"),
+    ('\u{1F907}', "left_half_circle"), ('\u{1F908}', "downward_facing_hook"),
+    ('\u{1F909}', "downward_facing_notched_hook"), ('\u{1F90A}', "downward_facing_hook_with_dot"),
+    ('\u{1F90B}', "downward_facing_notched_hook_with_dot"), ('\u{1F90C}', "pinched_fingers"),
+    ('\u{1F90D}', "white_heart"), ('\u{1F90E}', "brown_heart"), ('\u{1F90F}', "pinching_hand"),
+    ('\u{1F910}', "zipper_mouth_face"), ('\u{1F911}', "money_mouth_face"),
+    ('\u{1F912}', "face_with_thermometer"), ('\u{1F913}', "nerd_face"),
+    ('\u{1F914}', "thinking_face"), ('\u{1F915}', "face_with_head_bandage"),
+    ('\u{1F916}', "robot_face"), ('\u{1F917}', "hugging_face"), ('\u{1F918}', "sign_of_the_horns"),
+    ('\u{1F919}', "call_me_hand"), ('\u{1F91A}', "raised_back_of_hand"),
+    ('\u{1F91B}', "left_facing_fist"), ('\u{1F91C}', "right_facing_fist"),
+    ('\u{1F91D}', "handshake"), ('\u{1F91E}', "hand_with_index_and_middle_fingers_crossed"),
+    ('\u{1F91F}', "i_love_you_hand_sign"), ('\u{1F920}', "face_with_cowboy_hat"),
+    ('\u{1F921}', "clown_face"), ('\u{1F922}', "nauseated_face"),
+    ('\u{1F923}', "rolling_on_the_floor_laughing"), ('\u{1F924}', "drooling_face"),
+    ('\u{1F925}', "lying_face"), ('\u{1F926}', "face_palm"), ('\u{1F927}', "sneezing_face"),
+    ('\u{1F928}', "face_with_one_eyebrow_raised"), ('\u{1F929}', "grinning_face_with_star_eyes"),
+    ('\u{1F92A}', "grinning_face_with_one_large_and_one_small_eye"),
+    ('\u{1F92B}', "face_with_finger_covering_closed_lips"),
+    ('\u{1F92C}', "serious_face_with_symbols_covering_mouth"),
+    ('\u{1F92D}', "smiling_face_with_smiling_eyes_and_hand_covering_mouth"),
+    ('\u{1F92E}', "face_with_open_mouth_vomiting"),
+    ('\u{1F92F}', "shocked_face_with_exploding_head"), ('\u{1F930}', "pregnant_woman"),
+    ('\u{1F931}', "breast_feeding"), ('\u{1F932}', "palms_up_together"), ('\u{1F933}', "selfie"),
+    ('\u{1F934}', "prince"), ('\u{1F935}', "man_in_tuxedo"), ('\u{1F936}', "mother_christmas"),
+    ('\u{1F937}', "shrug"), ('\u{1F938}', "person_doing_cartwheel"), ('\u{1F939}', "juggling"),
+    ('\u{1F93A}', "fencer"), ('\u{1F93B}', "modern_pentathlon"), ('\u{1F93C}', "wrestlers"),
+    ('\u{1F93D}', "water_polo"), ('\u{1F93E}', "handball"), ('\u{1F93F}', "diving_mask"),
+    ('\u{1F940}', "wilted_flower"), ('\u{1F941}', "drum_with_drumsticks"),
+    ('\u{1F942}', "clinking_glasses"), ('\u{1F943}', "tumbler_glass"), ('\u{1F944}', "spoon"),
+    ('\u{1F945}', "goal_net"), ('\u{1F946}', "rifle"), ('\u{1F947}', "first_place_medal"),
+    ('\u{1F948}', "second_place_medal"), ('\u{1F949}', "third_place_medal"),
+    ('\u{1F94A}', "boxing_glove"), ('\u{1F94B}', "martial_arts_uniform"),
+    ('\u{1F94C}', "curling_stone"), ('\u{1F94D}', "lacrosse_stick_and_ball"),
+    ('\u{1F94E}', "softball"), ('\u{1F94F}', "flying_disc"), ('\u{1F950}', "croissant"),
+    ('\u{1F951}', "avocado"), ('\u{1F952}', "cucumber"), ('\u{1F953}', "bacon"),
+    ('\u{1F954}', "potato"), ('\u{1F955}', "carrot"), ('\u{1F956}', "baguette_bread"),
+    ('\u{1F957}', "green_salad"), ('\u{1F958}', "shallow_pan_of_food"),
+    ('\u{1F959}', "stuffed_flatbread"), ('\u{1F95A}', "egg"), ('\u{1F95B}', "glass_of_milk"),
+    ('\u{1F95C}', "peanuts"), ('\u{1F95D}', "kiwifruit"), ('\u{1F95E}', "pancakes"),
+    ('\u{1F95F}', "dumpling"), ('\u{1F960}', "fortune_cookie"), ('\u{1F961}', "takeout_box"),
+    ('\u{1F962}', "chopsticks"), ('\u{1F963}', "bowl_with_spoon"), ('\u{1F964}', "cup_with_straw"),
+    ('\u{1F965}', "coconut"), ('\u{1F966}', "broccoli"), ('\u{1F967}', "pie"),
+    ('\u{1F968}', "pretzel"), ('\u{1F969}', "cut_of_meat"), ('\u{1F96A}', "sandwich"),
+    ('\u{1F96B}', "canned_food"), ('\u{1F96C}', "leafy_green"), ('\u{1F96D}', "mango"),
+    ('\u{1F96E}', "moon_cake"), ('\u{1F96F}', "bagel"),
+    ('\u{1F970}', "smiling_face_with_smiling_eyes_and_three_hearts"), ('\u{1F971}', "yawning_face"),
+    ('\u{1F972}', "smiling_face_with_tear"), ('\u{1F973}', "face_with_party_horn_and_party_hat"),
+    ('\u{1F974}', "face_with_uneven_eyes_and_wavy_mouth"), ('\u{1F975}', "overheated_face"),
+    ('\u{1F976}', "freezing_face"), ('\u{1F977}', "ninja"), ('\u{1F978}', "disguised_face"),
+    ('\u{1F979}', "face_holding_back_tears"), ('\u{1F97A}', "face_with_pleading_eyes"),
+    ('\u{1F97B}', "sari"), ('\u{1F97C}', "lab_coat"), ('\u{1F97D}', "goggles"),
+    ('\u{1F97E}', "hiking_boot"), ('\u{1F97F}', "flat_shoe"), ('\u{1F980}', "crab"),
+    ('\u{1F981}', "lion_face"), ('\u{1F982}', "scorpion"), ('\u{1F983}', "turkey"),
+    ('\u{1F984}', "unicorn_face"), ('\u{1F985}', "eagle"), ('\u{1F986}', "duck"),
+    ('\u{1F987}', "bat"), ('\u{1F988}', "shark"), ('\u{1F989}', "owl"), ('\u{1F98A}', "fox_face"),
+    ('\u{1F98B}', "butterfly"), ('\u{1F98C}', "deer"), ('\u{1F98D}', "gorilla"),
+    ('\u{1F98E}', "lizard"), ('\u{1F98F}', "rhinoceros"), ('\u{1F990}', "shrimp"),
+    ('\u{1F991}', "squid"), ('\u{1F992}', "giraffe_face"), ('\u{1F993}', "zebra_face"),
+    ('\u{1F994}', "hedgehog"), ('\u{1F995}', "sauropod"), ('\u{1F996}', "t_rex"),
+    ('\u{1F997}', "cricket"), ('\u{1F998}', "kangaroo"), ('\u{1F999}', "llama"),
+    ('\u{1F99A}', "peacock"), ('\u{1F99B}', "hippopotamus"), ('\u{1F99C}', "parrot"),
+    ('\u{1F99D}', "raccoon"), ('\u{1F99E}', "lobster"), ('\u{1F99F}', "mosquito"),
+    ('\u{1F9A0}', "microbe"), ('\u{1F9A1}', "badger"), ('\u{1F9A2}', "swan"),
+    ('\u{1F9A3}', "mammoth"), ('\u{1F9A4}', "dodo"), ('\u{1F9A5}', "sloth"), ('\u{1F9A6}', "otter"),
+    ('\u{1F9A7}', "orangutan"), ('\u{1F9A8}', "skunk"), ('\u{1F9A9}', "flamingo"),
+    ('\u{1F9AA}', "oyster"), ('\u{1F9AB}', "beaver"), ('\u{1F9AC}', "bison"), ('\u{1F9AD}', "seal"),
+    ('\u{1F9AE}', "guide_dog"), ('\u{1F9AF}', "probing_cane"),
+    ('\u{1F9B0}', "emoji_component_red_hair"), ('\u{1F9B1}', "emoji_component_curly_hair"),
+    ('\u{1F9B2}', "emoji_component_bald"), ('\u{1F9B3}', "emoji_component_white_hair"),
+    ('\u{1F9B4}', "bone"), ('\u{1F9B5}', "leg"), ('\u{1F9B6}', "foot"), ('\u{1F9B7}', "tooth"),
+    ('\u{1F9B8}', "superhero"), ('\u{1F9B9}', "supervillain"), ('\u{1F9BA}', "safety_vest"),
+    ('\u{1F9BB}', "ear_with_hearing_aid"), ('\u{1F9BC}', "motorized_wheelchair"),
+    ('\u{1F9BD}', "manual_wheelchair"), ('\u{1F9BE}', "mechanical_arm"),
+    ('\u{1F9BF}', "mechanical_leg"), ('\u{1F9C0}', "cheese_wedge"), ('\u{1F9C1}', "cupcake"),
+    ('\u{1F9C2}', "salt_shaker"), ('\u{1F9C3}', "beverage_box"), ('\u{1F9C4}', "garlic"),
+    ('\u{1F9C5}', "onion"), ('\u{1F9C6}', "falafel"), ('\u{1F9C7}', "waffle"),
+    ('\u{1F9C8}', "butter"), ('\u{1F9C9}', "mate_drink"), ('\u{1F9CA}', "ice_cube"),
+    ('\u{1F9CB}', "bubble_tea"), ('\u{1F9CC}', "troll"), ('\u{1F9CD}', "standing_person"),
+    ('\u{1F9CE}', "kneeling_person"), ('\u{1F9CF}', "deaf_person"),
+    ('\u{1F9D0}', "face_with_monocle"), ('\u{1F9D1}', "adult"), ('\u{1F9D2}', "child"),
+    ('\u{1F9D3}', "older_adult"), ('\u{1F9D4}', "bearded_person"),
+    ('\u{1F9D5}', "person_with_headscarf"), ('\u{1F9D6}', "person_in_steamy_room"),
+    ('\u{1F9D7}', "person_climbing"), ('\u{1F9D8}', "person_in_lotus_position"),
+    ('\u{1F9D9}', "mage"), ('\u{1F9DA}', "fairy"), ('\u{1F9DB}', "vampire"),
+    ('\u{1F9DC}', "merperson"), ('\u{1F9DD}', "elf"), ('\u{1F9DE}', "genie"),
+    ('\u{1F9DF}', "zombie"), ('\u{1F9E0}', "brain"), ('\u{1F9E1}', "orange_heart"),
+    ('\u{1F9E2}', "billed_cap"), ('\u{1F9E3}', "scarf"), ('\u{1F9E4}', "gloves"),
+    ('\u{1F9E5}', "coat"), ('\u{1F9E6}', "socks"), ('\u{1F9E7}', "red_gift_envelope"),
+    ('\u{1F9E8}', "firecracker"), ('\u{1F9E9}', "jigsaw_puzzle_piece"), ('\u{1F9EA}', "test_tube"),
+    ('\u{1F9EB}', "petri_dish"), ('\u{1F9EC}', "dna_double_helix"), ('\u{1F9ED}', "compass"),
+    ('\u{1F9EE}', "abacus"), ('\u{1F9EF}', "fire_extinguisher"), ('\u{1F9F0}', "toolbox"),
+    ('\u{1F9F1}', "brick"), ('\u{1F9F2}', "magnet"), ('\u{1F9F3}', "luggage"),
+    ('\u{1F9F4}', "lotion_bottle"), ('\u{1F9F5}', "spool_of_thread"), ('\u{1F9F6}', "ball_of_yarn"),
+    ('\u{1F9F7}', "safety_pin"), ('\u{1F9F8}', "teddy_bear"), ('\u{1F9F9}', "broom"),
+    ('\u{1F9FA}', "basket"), ('\u{1F9FB}', "roll_of_paper"), ('\u{1F9FC}', "bar_of_soap"),
+    ('\u{1F9FD}', "sponge"), ('\u{1F9FE}', "receipt"), ('\u{1F9FF}', "nazar_amulet"),
+    ('\u{1FA70}', "ballet_shoes"), ('\u{1FA71}', "one_piece_swimsuit"), ('\u{1FA72}', "briefs"),
+    ('\u{1FA73}', "shorts"), ('\u{1FA74}', "thong_sandal"), ('\u{1FA78}', "drop_of_blood"),
+    ('\u{1FA79}', "adhesive_bandage"), ('\u{1FA7A}', "stethoscope"), ('\u{1FA7B}', "x_ray"),
+    ('\u{1FA7C}', "crutch"), ('\u{1FA80}', "yo_yo"), ('\u{1FA81}', "kite"),
+    ('\u{1FA82}', "parachute"), ('\u{1FA83}', "boomerang"), ('\u{1FA84}', "magic_wand"),
+    ('\u{1FA85}', "pinata"), ('\u{1FA86}', "nesting_dolls"), ('\u{1FA90}', "ringed_planet"),
+    ('\u{1FA91}', "chair"), ('\u{1FA92}', "razor"), ('\u{1FA93}', "axe"),
+    ('\u{1FA94}', "diya_lamp"), ('\u{1FA95}', "banjo"), ('\u{1FA96}', "military_helmet"),
+    ('\u{1FA97}', "accordion"), ('\u{1FA98}', "long_drum"), ('\u{1FA99}', "coin"),
+    ('\u{1FA9A}', "carpentry_saw"), ('\u{1FA9B}', "screwdriver"), ('\u{1FA9C}', "ladder"),
+    ('\u{1FA9D}', "hook"), ('\u{1FA9E}', "mirror"), ('\u{1FA9F}', "window"),
+    ('\u{1FAA0}', "plunger"), ('\u{1FAA1}', "sewing_needle"), ('\u{1FAA2}', "knot"),
+    ('\u{1FAA3}', "bucket"), ('\u{1FAA4}', "mouse_trap"), ('\u{1FAA5}', "toothbrush"),
+    ('\u{1FAA6}', "headstone"), ('\u{1FAA7}', "placard"), ('\u{1FAA8}', "rock"),
+    ('\u{1FAA9}', "mirror_ball"), ('\u{1FAAA}', "identification_card"),
+    ('\u{1FAAB}', "low_battery"), ('\u{1FAAC}', "hamsa"), ('\u{1FAB0}', "fly"),
+    ('\u{1FAB1}', "worm"), ('\u{1FAB2}', "beetle"), ('\u{1FAB3}', "cockroach"),
+    ('\u{1FAB4}', "potted_plant"), ('\u{1FAB5}', "wood"), ('\u{1FAB6}', "feather"),
+    ('\u{1FAB7}', "lotus"), ('\u{1FAB8}', "coral"), ('\u{1FAB9}', "empty_nest"),
+    ('\u{1FABA}', "nest_with_eggs"), ('\u{1FAC0}', "anatomical_heart"), ('\u{1FAC1}', "lungs"),
+    ('\u{1FAC2}', "people_hugging"), ('\u{1FAC3}', "pregnant_man"),
+    ('\u{1FAC4}', "pregnant_person"), ('\u{1FAC5}', "person_with_crown"),
+    ('\u{1FAD0}', "blueberries"), ('\u{1FAD1}', "bell_pepper"), ('\u{1FAD2}', "olive"),
+    ('\u{1FAD3}', "flatbread"), ('\u{1FAD4}', "tamale"), ('\u{1FAD5}', "fondue"),
+    ('\u{1FAD6}', "teapot"), ('\u{1FAD7}', "pouring_liquid"), ('\u{1FAD8}', "beans"),
+    ('\u{1FAD9}', "jar"), ('\u{1FAE0}', "melting_face"), ('\u{1FAE1}', "saluting_face"),
+    ('\u{1FAE2}', "face_with_open_eyes_and_hand_over_mouth"),
+    ('\u{1FAE3}', "face_with_peeking_eye"), ('\u{1FAE4}', "face_with_diagonal_mouth"),
+    ('\u{1FAE5}', "dotted_line_face"), ('\u{1FAE6}', "biting_lip"), ('\u{1FAE7}', "bubbles"),
+    ('\u{1FAF0}', "hand_with_index_finger_and_thumb_crossed"), ('\u{1FAF1}', "rightwards_hand"),
+    ('\u{1FAF2}', "leftwards_hand"), ('\u{1FAF3}', "palm_down_hand"), ('\u{1FAF4}', "palm_up_hand"),
+    ('\u{1FAF5}', "index_pointing_at_the_viewer"), ('\u{1FAF6}', "heart_hands"),
+];
+
+/// Slugified Unicode name of a symbol, like `☃` -> `snowman`
+pub(crate) fn symbol_name(one_char: char) -> Option<&'static str> {
+    SYMBOL_NAMES
+        .binary_search_by_key(&one_char, |(symbol, _)| *symbol)
+        .ok()
+        .map(|index| SYMBOL_NAMES[index].1)
+}
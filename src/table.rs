@@ -0,0 +1,813 @@
+//! Transliteration tables: the built-in one, and the tables of the users layered on top of it
+
+use std::ops::RangeInclusive;
+#[cfg(feature = "serde")]
+use std::{collections::BTreeMap, path::Path};
+
+use crate::NotoxError;
+
+/// Built-in mappings of the characters to ASCII, sorted by character:
+/// the typographic quotes, the soft hyphen and the middle dot are removed without breaking the word
+#[rustfmt::skip]
+pub const BUILTIN_TABLE: &[(char, &str)] = &[
+    ('A', "A"), ('B', "B"), ('C', "C"), ('D', "D"), ('E', "E"), ('F', "F"),
+    ('G', "G"), ('H', "H"), ('I', "I"), ('J', "J"), ('K', "K"), ('L', "L"),
+    ('M', "M"), ('N', "N"), ('O', "O"), ('P', "P"), ('Q', "Q"), ('R', "R"),
+    ('S', "S"), ('T', "T"), ('U', "U"), ('V', "V"), ('W', "W"), ('X', "X"),
+    ('Y', "Y"), ('Z', "Z"), ('a', "a"), ('b', "b"), ('c', "c"), ('d', "d"),
+    ('e', "e"), ('f', "f"), ('g', "g"), ('h', "h"), ('i', "i"), ('j', "j"),
+    ('k', "k"), ('l', "l"), ('m', "m"), ('n', "n"), ('o', "o"), ('p', "p"),
+    ('q', "q"), ('r', "r"), ('s', "s"), ('t', "t"), ('u', "u"), ('v', "v"),
+    ('w', "w"), ('x', "x"), ('y', "y"), ('z', "z"), ('\u{008C}', "OE"), ('\u{009C}', "oe"),
+    ('ª', "a"), ('«', ""), ('\u{00AD}', ""), ('²', "2"), ('³', "3"), ('µ', "u"),
+    ('·', ""), ('¹', "1"), ('º', "o"), ('»', ""), ('¼', "1-4"), ('½', "1-2"),
+    ('¾', "3-4"), ('À', "A"), ('Á', "A"), ('Â', "A"), ('Ã', "A"), ('Ä', "A"),
+    ('Å', "A"), ('Æ', "AE"), ('Ç', "C"), ('È', "E"), ('É', "E"), ('Ê', "E"),
+    ('Ë', "E"), ('Ì', "I"), ('Í', "I"), ('Î', "I"), ('Ï', "I"), ('Ð', "D"),
+    ('Ñ', "N"), ('Ò', "O"), ('Ó', "O"), ('Ô', "O"), ('Õ', "O"), ('Ö', "O"),
+    ('Ø', "O"), ('Ù', "U"), ('Ú', "U"), ('Û', "U"), ('Ü', "U"), ('Ý', "Y"),
+    ('Þ', "TH"), ('ß', "ss"), ('à', "a"), ('á', "a"), ('â', "a"), ('ã', "a"),
+    ('ä', "a"), ('å', "a"), ('æ', "ae"), ('ç', "c"), ('è', "e"), ('é', "e"),
+    ('ê', "e"), ('ë', "e"), ('ì', "i"), ('í', "i"), ('î', "i"), ('ï', "i"),
+    ('ð', "d"), ('ñ', "n"), ('ò', "o"), ('ó', "o"), ('ô', "o"), ('õ', "o"),
+    ('ö', "o"), ('ø', "o"), ('ù', "u"), ('ú', "u"), ('û', "u"), ('ü', "u"),
+    ('ý', "y"), ('þ', "th"), ('ÿ', "y"), ('Ā', "A"), ('ā', "a"), ('Ă', "A"),
+    ('ă', "a"), ('Ą', "A"), ('ą', "a"), ('Ć', "C"), ('ć', "c"), ('Ĉ', "C"),
+    ('ĉ', "c"), ('Ċ', "C"), ('ċ', "c"), ('Č', "C"), ('č', "c"), ('Ď', "D"),
+    ('ď', "d"), ('Đ', "D"), ('đ', "d"), ('Ē', "E"), ('ē', "e"), ('Ĕ', "E"),
+    ('ĕ', "e"), ('Ė', "E"), ('ė', "e"), ('Ę', "E"), ('ę', "e"), ('Ě', "E"),
+    ('ě', "e"), ('Ĝ', "G"), ('ĝ', "g"), ('Ğ', "G"), ('ğ', "g"), ('Ġ', "G"),
+    ('ġ', "g"), ('Ģ', "G"), ('ģ', "g"), ('Ĥ', "H"), ('ĥ', "h"), ('Ħ', "H"),
+    ('ħ', "h"), ('Ĩ', "I"), ('ĩ', "i"), ('Ī', "I"), ('ī', "i"), ('Ĭ', "I"),
+    ('ĭ', "i"), ('Į', "I"), ('į', "i"), ('İ', "I"), ('ı', "i"), ('Ĳ', "IJ"),
+    ('ĳ', "ij"), ('Ĵ', "J"), ('ĵ', "j"), ('Ķ', "K"), ('ķ', "k"), ('ĸ', "q"),
+    ('Ĺ', "L"), ('ĺ', "l"), ('Ļ', "L"), ('ļ', "l"), ('Ľ', "L"), ('ľ', "l"),
+    ('Ŀ', "L"), ('ŀ', "l"), ('Ł', "L"), ('ł', "l"), ('Ń', "N"), ('ń', "n"),
+    ('Ņ', "N"), ('ņ', "n"), ('Ň', "N"), ('ň', "n"), ('ŉ', "n"), ('Ŋ', "NG"),
+    ('ŋ', "ng"), ('Ō', "O"), ('ō', "o"), ('Ŏ', "O"), ('ŏ', "o"), ('Ő', "O"),
+    ('ő', "o"), ('Œ', "OE"), ('œ', "oe"), ('Ŕ', "R"), ('ŕ', "r"), ('Ŗ', "R"),
+    ('ŗ', "r"), ('Ř', "R"), ('ř', "r"), ('Ś', "S"), ('ś', "s"), ('Ŝ', "S"),
+    ('ŝ', "s"), ('Ş', "S"), ('ş', "s"), ('Š', "S"), ('š', "s"), ('Ţ', "T"),
+    ('ţ', "t"), ('Ť', "T"), ('ť', "t"), ('Ŧ', "T"), ('ŧ', "t"), ('Ũ', "U"),
+    ('ũ', "u"), ('Ū', "U"), ('ū', "u"), ('Ŭ', "U"), ('ŭ', "u"), ('Ů', "U"),
+    ('ů', "u"), ('Ű', "U"), ('ű', "u"), ('Ų', "U"), ('ų', "u"), ('Ŵ', "W"),
+    ('ŵ', "w"), ('Ŷ', "Y"), ('ŷ', "y"), ('Ÿ', "Y"), ('Ź', "Z"), ('ź', "z"),
+    ('Ż', "Z"), ('ż', "z"), ('Ž', "Z"), ('ž', "z"), ('ſ', "s"), ('ƀ', "b"),
+    ('Ɓ', "B"), ('Ƃ', "B"), ('ƃ', "b"), ('Ɔ', "O"), ('Ƈ', "C"), ('ƈ', "c"),
+    ('Ɖ', "D"), ('Ɗ', "D"), ('Ƌ', "D"), ('ƌ', "d"), ('Ǝ', "E"), ('Ə', "E"),
+    ('Ɛ', "E"), ('Ƒ', "F"), ('ƒ', "f"), ('Ɠ', "G"), ('Ɣ', "G"), ('ƕ', "hv"),
+    ('Ɩ', "I"), ('Ɨ', "I"), ('Ƙ', "K"), ('ƙ', "k"), ('ƚ', "l"), ('ƛ', "tl"),
+    ('Ɯ', "M"), ('Ɲ', "N"), ('ƞ', "n"), ('Ɵ', "O"), ('Ơ', "O"), ('ơ', "o"),
+    ('Ƣ', "OI"), ('ƣ', "oi"), ('Ƥ', "P"), ('ƥ', "p"), ('Ʀ', "R"), ('Ʃ', "SH"),
+    ('ƫ', "t"), ('Ƭ', "T"), ('ƭ', "t"), ('Ʈ', "T"), ('Ư', "U"), ('ư', "u"),
+    ('Ʊ', "U"), ('Ʋ', "V"), ('Ƴ', "Y"), ('ƴ', "y"), ('Ƶ', "Z"), ('ƶ', "z"),
+    ('Ʒ', "ZH"), ('ƺ', "zh"), ('ƿ', "w"), ('Ǆ', "DZ"), ('ǅ', "Dz"), ('ǆ', "dz"),
+    ('Ǉ', "LJ"), ('ǈ', "Lj"), ('ǉ', "lj"), ('Ǌ', "NJ"), ('ǋ', "Nj"), ('ǌ', "nj"),
+    ('Ǎ', "A"), ('ǎ', "a"), ('Ǐ', "I"), ('ǐ', "i"), ('Ǒ', "O"), ('ǒ', "o"),
+    ('Ǔ', "U"), ('ǔ', "u"), ('Ǖ', "U"), ('ǖ', "u"), ('Ǘ', "U"), ('ǘ', "u"),
+    ('Ǚ', "U"), ('ǚ', "u"), ('Ǜ', "U"), ('ǜ', "u"), ('ǝ', "e"), ('Ǟ', "A"),
+    ('ǟ', "a"), ('Ǡ', "A"), ('ǡ', "a"), ('Ǣ', "AE"), ('ǣ', "ae"), ('Ǥ', "G"),
+    ('ǥ', "g"), ('Ǧ', "G"), ('ǧ', "g"), ('Ǩ', "K"), ('ǩ', "k"), ('Ǫ', "O"),
+    ('ǫ', "o"), ('Ǭ', "O"), ('ǭ', "o"), ('Ǯ', "ZH"), ('ǯ', "zh"), ('ǰ', "j"),
+    ('Ǳ', "DZ"), ('ǲ', "Dz"), ('ǳ', "dz"), ('Ǵ', "G"), ('ǵ', "g"), ('Ƕ', "HV"),
+    ('Ƿ', "W"), ('Ǹ', "N"), ('ǹ', "n"), ('Ǻ', "A"), ('ǻ', "a"), ('Ǽ', "AE"),
+    ('ǽ', "ae"), ('Ǿ', "O"), ('ǿ', "o"), ('Ȁ', "A"), ('ȁ', "a"), ('Ȃ', "A"),
+    ('ȃ', "a"), ('Ȅ', "E"), ('ȅ', "e"), ('Ȇ', "E"), ('ȇ', "e"), ('Ȉ', "I"),
+    ('ȉ', "i"), ('Ȋ', "I"), ('ȋ', "i"), ('Ȍ', "O"), ('ȍ', "o"), ('Ȏ', "O"),
+    ('ȏ', "o"), ('Ȑ', "R"), ('ȑ', "r"), ('Ȓ', "R"), ('ȓ', "r"), ('Ȕ', "U"),
+    ('ȕ', "u"), ('Ȗ', "U"), ('ȗ', "u"), ('Ș', "S"), ('ș', "s"), ('Ț', "T"),
+    ('ț', "t"), ('Ȝ', "Y"), ('ȝ', "y"), ('Ȟ', "H"), ('ȟ', "h"), ('Ƞ', "N"),
+    ('ȡ', "d"), ('Ȣ', "OU"), ('ȣ', "ou"), ('Ȥ', "Z"), ('ȥ', "z"), ('Ȧ', "A"),
+    ('ȧ', "a"), ('Ȩ', "E"), ('ȩ', "e"), ('Ȫ', "O"), ('ȫ', "o"), ('Ȭ', "O"),
+    ('ȭ', "o"), ('Ȯ', "O"), ('ȯ', "o"), ('Ȱ', "O"), ('ȱ', "o"), ('Ȳ', "Y"),
+    ('ȳ', "y"), ('ȴ', "l"), ('ȵ', "n"), ('ȶ', "t"), ('ȷ', "j"), ('ȸ', "db"),
+    ('ȹ', "qp"), ('Ⱥ', "A"), ('Ȼ', "C"), ('ȼ', "c"), ('Ƚ', "L"), ('Ⱦ', "T"),
+    ('ȿ', "s"), ('ɀ', "z"), ('Ƀ', "B"), ('Ʉ', "U"), ('Ʌ', "V"), ('Ɇ', "E"),
+    ('ɇ', "e"), ('Ɉ', "J"), ('ɉ', "j"), ('Ɋ', "Q"), ('ɋ', "q"), ('Ɍ', "R"),
+    ('ɍ', "r"), ('Ɏ', "Y"), ('ɏ', "y"), ('ɐ', "a"), ('ɑ', "a"), ('ɒ', "a"),
+    ('ɓ', "b"), ('ɔ', "o"), ('ɕ', "c"), ('ɖ', "d"), ('ɗ', "d"), ('ɘ', "e"),
+    ('ə', "e"), ('ɚ', "e"), ('ɛ', "e"), ('ɜ', "e"), ('ɝ', "e"), ('ɞ', "e"),
+    ('ɟ', "j"), ('ɠ', "g"), ('ɡ', "g"), ('ɢ', "g"), ('ɣ', "g"), ('ɤ', "o"),
+    ('ɥ', "h"), ('ɦ', "h"), ('ɧ', "h"), ('ɨ', "i"), ('ɩ', "i"), ('ɪ', "i"),
+    ('ɫ', "l"), ('ɬ', "l"), ('ɭ', "l"), ('ɮ', "l"), ('ɯ', "m"), ('ɰ', "m"),
+    ('ɱ', "m"), ('ɲ', "n"), ('ɳ', "n"), ('ɴ', "n"), ('ɵ', "o"), ('ɶ', "oe"),
+    ('ɷ', "o"), ('ɸ', "f"), ('ɹ', "r"), ('ɺ', "r"), ('ɻ', "r"), ('ɼ', "r"),
+    ('ɽ', "r"), ('ɾ', "r"), ('ɿ', "r"), ('ʀ', "r"), ('ʁ', "r"), ('ʂ', "s"),
+    ('ʃ', "sh"), ('ʄ', "j"), ('ʆ', "sh"), ('ʇ', "t"), ('ʈ', "t"), ('ʉ', "u"),
+    ('ʊ', "u"), ('ʋ', "v"), ('ʌ', "v"), ('ʍ', "w"), ('ʎ', "y"), ('ʏ', "y"),
+    ('ʐ', "z"), ('ʑ', "z"), ('ʒ', "zh"), ('ʓ', "zh"), ('ʗ', "c"), ('ʙ', "b"),
+    ('ʚ', "e"), ('ʛ', "g"), ('ʜ', "h"), ('ʝ', "j"), ('ʞ', "k"), ('ʟ', "l"),
+    ('ʠ', "q"), ('ʣ', "dz"), ('ʤ', "dzh"), ('ʥ', "dz"), ('ʦ', "ts"), ('ʧ', "tsh"),
+    ('ʨ', "tc"), ('ʩ', "fng"), ('ʪ', "ls"), ('ʫ', "lz"), ('ʮ', "h"), ('ʯ', "h"),
+    ('ʰ', "h"), ('ʱ', "h"), ('ʲ', "j"), ('ʳ', "r"), ('ʴ', "r"), ('ʵ', "r"),
+    ('ʶ', "r"), ('ʷ', "w"), ('ʸ', "y"), ('ˠ', "g"), ('ˡ', "l"), ('ˢ', "s"),
+    ('ˣ', "x"), ('٠', "0"), ('١', "1"), ('٢', "2"), ('٣', "3"), ('٤', "4"),
+    ('٥', "5"), ('٦', "6"), ('٧', "7"), ('٨', "8"), ('٩', "9"), ('۰', "0"),
+    ('۱', "1"), ('۲', "2"), ('۳', "3"), ('۴', "4"), ('۵', "5"), ('۶', "6"),
+    ('۷', "7"), ('۸', "8"), ('۹', "9"), ('߀', "0"), ('߁', "1"), ('߂', "2"),
+    ('߃', "3"), ('߄', "4"), ('߅', "5"), ('߆', "6"), ('߇', "7"), ('߈', "8"),
+    ('߉', "9"), ('०', "0"), ('१', "1"), ('२', "2"), ('३', "3"), ('४', "4"),
+    ('५', "5"), ('६', "6"), ('७', "7"), ('८', "8"), ('९', "9"), ('০', "0"),
+    ('১', "1"), ('২', "2"), ('৩', "3"), ('৪', "4"), ('৫', "5"), ('৬', "6"),
+    ('৭', "7"), ('৮', "8"), ('৯', "9"), ('੦', "0"), ('੧', "1"), ('੨', "2"),
+    ('੩', "3"), ('੪', "4"), ('੫', "5"), ('੬', "6"), ('੭', "7"), ('੮', "8"),
+    ('੯', "9"), ('૦', "0"), ('૧', "1"), ('૨', "2"), ('૩', "3"), ('૪', "4"),
+    ('૫', "5"), ('૬', "6"), ('૭', "7"), ('૮', "8"), ('૯', "9"), ('୦', "0"),
+    ('୧', "1"), ('୨', "2"), ('୩', "3"), ('୪', "4"), ('୫', "5"), ('୬', "6"),
+    ('୭', "7"), ('୮', "8"), ('୯', "9"), ('௦', "0"), ('௧', "1"), ('௨', "2"),
+    ('௩', "3"), ('௪', "4"), ('௫', "5"), ('௬', "6"), ('௭', "7"), ('௮', "8"),
+    ('௯', "9"), ('౦', "0"), ('౧', "1"), ('౨', "2"), ('౩', "3"), ('౪', "4"),
+    ('౫', "5"), ('౬', "6"), ('౭', "7"), ('౮', "8"), ('౯', "9"), ('೦', "0"),
+    ('೧', "1"), ('೨', "2"), ('೩', "3"), ('೪', "4"), ('೫', "5"), ('೬', "6"),
+    ('೭', "7"), ('೮', "8"), ('೯', "9"), ('൦', "0"), ('൧', "1"), ('൨', "2"),
+    ('൩', "3"), ('൪', "4"), ('൫', "5"), ('൬', "6"), ('൭', "7"), ('൮', "8"),
+    ('൯', "9"), ('෦', "0"), ('෧', "1"), ('෨', "2"), ('෩', "3"), ('෪', "4"),
+    ('෫', "5"), ('෬', "6"), ('෭', "7"), ('෮', "8"), ('෯', "9"), ('๐', "0"),
+    ('๑', "1"), ('๒', "2"), ('๓', "3"), ('๔', "4"), ('๕', "5"), ('๖', "6"),
+    ('๗', "7"), ('๘', "8"), ('๙', "9"), ('໐', "0"), ('໑', "1"), ('໒', "2"),
+    ('໓', "3"), ('໔', "4"), ('໕', "5"), ('໖', "6"), ('໗', "7"), ('໘', "8"),
+    ('໙', "9"), ('༠', "0"), ('༡', "1"), ('༢', "2"), ('༣', "3"), ('༤', "4"),
+    ('༥', "5"), ('༦', "6"), ('༧', "7"), ('༨', "8"), ('༩', "9"), ('၀', "0"),
+    ('၁', "1"), ('၂', "2"), ('၃', "3"), ('၄', "4"), ('၅', "5"), ('၆', "6"),
+    ('၇', "7"), ('၈', "8"), ('၉', "9"), ('႐', "0"), ('႑', "1"), ('႒', "2"),
+    ('႓', "3"), ('႔', "4"), ('႕', "5"), ('႖', "6"), ('႗', "7"), ('႘', "8"),
+    ('႙', "9"), ('០', "0"), ('១', "1"), ('២', "2"), ('៣', "3"), ('៤', "4"),
+    ('៥', "5"), ('៦', "6"), ('៧', "7"), ('៨', "8"), ('៩', "9"), ('᠐', "0"),
+    ('᠑', "1"), ('᠒', "2"), ('᠓', "3"), ('᠔', "4"), ('᠕', "5"), ('᠖', "6"),
+    ('᠗', "7"), ('᠘', "8"), ('᠙', "9"), ('᥆', "0"), ('᥇', "1"), ('᥈', "2"),
+    ('᥉', "3"), ('᥊', "4"), ('᥋', "5"), ('᥌', "6"), ('᥍', "7"), ('᥎', "8"),
+    ('᥏', "9"), ('᧐', "0"), ('᧑', "1"), ('᧒', "2"), ('᧓', "3"), ('᧔', "4"),
+    ('᧕', "5"), ('᧖', "6"), ('᧗', "7"), ('᧘', "8"), ('᧙', "9"), ('᪀', "0"),
+    ('᪁', "1"), ('᪂', "2"), ('᪃', "3"), ('᪄', "4"), ('᪅', "5"), ('᪆', "6"),
+    ('᪇', "7"), ('᪈', "8"), ('᪉', "9"), ('᪐', "0"), ('᪑', "1"), ('᪒', "2"),
+    ('᪓', "3"), ('᪔', "4"), ('᪕', "5"), ('᪖', "6"), ('᪗', "7"), ('᪘', "8"),
+    ('᪙', "9"), ('᭐', "0"), ('᭑', "1"), ('᭒', "2"), ('᭓', "3"), ('᭔', "4"),
+    ('᭕', "5"), ('᭖', "6"), ('᭗', "7"), ('᭘', "8"), ('᭙', "9"), ('᮰', "0"),
+    ('᮱', "1"), ('᮲', "2"), ('᮳', "3"), ('᮴', "4"), ('᮵', "5"), ('᮶', "6"),
+    ('᮷', "7"), ('᮸', "8"), ('᮹', "9"), ('᱀', "0"), ('᱁', "1"), ('᱂', "2"),
+    ('᱃', "3"), ('᱄', "4"), ('᱅', "5"), ('᱆', "6"), ('᱇', "7"), ('᱈', "8"),
+    ('᱉', "9"), ('᱐', "0"), ('᱑', "1"), ('᱒', "2"), ('᱓', "3"), ('᱔', "4"),
+    ('᱕', "5"), ('᱖', "6"), ('᱗', "7"), ('᱘', "8"), ('᱙', "9"), ('ᴀ', "a"),
+    ('ᴁ', "ae"), ('ᴂ', "ae"), ('ᴃ', "b"), ('ᴄ', "c"), ('ᴅ', "d"), ('ᴆ', "d"),
+    ('ᴇ', "e"), ('ᴈ', "e"), ('ᴉ', "i"), ('ᴊ', "j"), ('ᴋ', "k"), ('ᴌ', "l"),
+    ('ᴍ', "m"), ('ᴎ', "n"), ('ᴏ', "o"), ('ᴐ', "o"), ('ᴑ', "o"), ('ᴒ', "o"),
+    ('ᴓ', "o"), ('ᴔ', "oe"), ('ᴕ', "ou"), ('ᴖ', "o"), ('ᴗ', "o"), ('ᴘ', "p"),
+    ('ᴙ', "r"), ('ᴚ', "r"), ('ᴛ', "t"), ('ᴜ', "u"), ('ᴝ', "u"), ('ᴞ', "u"),
+    ('ᴟ', "m"), ('ᴠ', "v"), ('ᴡ', "w"), ('ᴢ', "z"), ('ᴣ', "zh"), ('ᴦ', "g"),
+    ('ᴧ', "l"), ('ᴨ', "p"), ('ᴩ', "r"), ('ᴪ', "ps"), ('ᴫ', "l"), ('ᴬ', "A"),
+    ('ᴭ', "AE"), ('ᴮ', "B"), ('ᴯ', "B"), ('ᴰ', "D"), ('ᴱ', "E"), ('ᴲ', "E"),
+    ('ᴳ', "G"), ('ᴴ', "H"), ('ᴵ', "I"), ('ᴶ', "J"), ('ᴷ', "K"), ('ᴸ', "L"),
+    ('ᴹ', "M"), ('ᴺ', "N"), ('ᴻ', "N"), ('ᴼ', "O"), ('ᴽ', "OU"), ('ᴾ', "P"),
+    ('ᴿ', "R"), ('ᵀ', "T"), ('ᵁ', "U"), ('ᵂ', "W"), ('ᵃ', "a"), ('ᵄ', "a"),
+    ('ᵅ', "a"), ('ᵆ', "ae"), ('ᵇ', "b"), ('ᵈ', "d"), ('ᵉ', "e"), ('ᵊ', "e"),
+    ('ᵋ', "e"), ('ᵌ', "e"), ('ᵍ', "g"), ('ᵎ', "i"), ('ᵏ', "k"), ('ᵐ', "m"),
+    ('ᵑ', "ng"), ('ᵒ', "o"), ('ᵓ', "o"), ('ᵔ', "o"), ('ᵕ', "o"), ('ᵖ', "p"),
+    ('ᵗ', "t"), ('ᵘ', "u"), ('ᵙ', "u"), ('ᵚ', "m"), ('ᵛ', "v"), ('ᵝ', "v"),
+    ('ᵞ', "g"), ('ᵟ', "d"), ('ᵠ', "f"), ('ᵡ', "ch"), ('ᵢ', "i"), ('ᵣ', "r"),
+    ('ᵤ', "u"), ('ᵥ', "v"), ('ᵦ', "v"), ('ᵧ', "g"), ('ᵨ', "r"), ('ᵩ', "f"),
+    ('ᵪ', "ch"), ('ᵫ', "ue"), ('ᵬ', "b"), ('ᵭ', "d"), ('ᵮ', "f"), ('ᵯ', "m"),
+    ('ᵰ', "n"), ('ᵱ', "p"), ('ᵲ', "r"), ('ᵳ', "r"), ('ᵴ', "s"), ('ᵵ', "t"),
+    ('ᵶ', "z"), ('ᵷ', "g"), ('ᵸ', "n"), ('ᵹ', "g"), ('ᵺ', "th"), ('ᵻ', "i"),
+    ('ᵼ', "i"), ('ᵽ', "p"), ('ᵾ', "u"), ('ᵿ', "u"), ('ᶀ', "b"), ('ᶁ', "d"),
+    ('ᶂ', "f"), ('ᶃ', "g"), ('ᶄ', "k"), ('ᶅ', "l"), ('ᶆ', "m"), ('ᶇ', "n"),
+    ('ᶈ', "p"), ('ᶉ', "r"), ('ᶊ', "s"), ('ᶋ', "sh"), ('ᶌ', "v"), ('ᶍ', "x"),
+    ('ᶎ', "z"), ('ᶏ', "a"), ('ᶐ', "a"), ('ᶑ', "d"), ('ᶒ', "e"), ('ᶓ', "e"),
+    ('ᶔ', "e"), ('ᶕ', "e"), ('ᶖ', "i"), ('ᶗ', "o"), ('ᶘ', "sh"), ('ᶙ', "u"),
+    ('ᶚ', "zh"), ('ᶛ', "a"), ('ᶜ', "c"), ('ᶝ', "c"), ('ᶞ', "d"), ('ᶟ', "e"),
+    ('ᶠ', "f"), ('ᶡ', "j"), ('ᶢ', "g"), ('ᶣ', "h"), ('ᶤ', "i"), ('ᶥ', "i"),
+    ('ᶦ', "i"), ('ᶧ', "i"), ('ᶨ', "j"), ('ᶩ', "l"), ('ᶪ', "l"), ('ᶫ', "l"),
+    ('ᶬ', "m"), ('ᶭ', "m"), ('ᶮ', "n"), ('ᶯ', "n"), ('ᶰ', "n"), ('ᶱ', "o"),
+    ('ᶲ', "f"), ('ᶳ', "s"), ('ᶴ', "sh"), ('ᶵ', "t"), ('ᶶ', "u"), ('ᶷ', "u"),
+    ('ᶸ', "u"), ('ᶹ', "v"), ('ᶺ', "v"), ('ᶻ', "z"), ('ᶼ', "z"), ('ᶽ', "z"),
+    ('ᶾ', "zh"), ('ᶿ', "th"), ('Ḁ', "A"), ('ḁ', "a"), ('Ḃ', "B"), ('ḃ', "b"),
+    ('Ḅ', "B"), ('ḅ', "b"), ('Ḇ', "B"), ('ḇ', "b"), ('Ḉ', "C"), ('ḉ', "c"),
+    ('Ḋ', "D"), ('ḋ', "d"), ('Ḍ', "D"), ('ḍ', "d"), ('Ḏ', "D"), ('ḏ', "d"),
+    ('Ḑ', "D"), ('ḑ', "d"), ('Ḓ', "D"), ('ḓ', "d"), ('Ḕ', "E"), ('ḕ', "e"),
+    ('Ḗ', "E"), ('ḗ', "e"), ('Ḙ', "E"), ('ḙ', "e"), ('Ḛ', "E"), ('ḛ', "e"),
+    ('Ḝ', "E"), ('ḝ', "e"), ('Ḟ', "F"), ('ḟ', "f"), ('Ḡ', "G"), ('ḡ', "g"),
+    ('Ḣ', "H"), ('ḣ', "h"), ('Ḥ', "H"), ('ḥ', "h"), ('Ḧ', "H"), ('ḧ', "h"),
+    ('Ḩ', "H"), ('ḩ', "h"), ('Ḫ', "H"), ('ḫ', "h"), ('Ḭ', "I"), ('ḭ', "i"),
+    ('Ḯ', "I"), ('ḯ', "i"), ('Ḱ', "K"), ('ḱ', "k"), ('Ḳ', "K"), ('ḳ', "k"),
+    ('Ḵ', "K"), ('ḵ', "k"), ('Ḷ', "L"), ('ḷ', "l"), ('Ḹ', "L"), ('ḹ', "l"),
+    ('Ḻ', "L"), ('ḻ', "l"), ('Ḽ', "L"), ('ḽ', "l"), ('Ḿ', "M"), ('ḿ', "m"),
+    ('Ṁ', "M"), ('ṁ', "m"), ('Ṃ', "M"), ('ṃ', "m"), ('Ṅ', "N"), ('ṅ', "n"),
+    ('Ṇ', "N"), ('ṇ', "n"), ('Ṉ', "N"), ('ṉ', "n"), ('Ṋ', "N"), ('ṋ', "n"),
+    ('Ṍ', "O"), ('ṍ', "o"), ('Ṏ', "O"), ('ṏ', "o"), ('Ṑ', "O"), ('ṑ', "o"),
+    ('Ṓ', "O"), ('ṓ', "o"), ('Ṕ', "P"), ('ṕ', "p"), ('Ṗ', "P"), ('ṗ', "p"),
+    ('Ṙ', "R"), ('ṙ', "r"), ('Ṛ', "R"), ('ṛ', "r"), ('Ṝ', "R"), ('ṝ', "r"),
+    ('Ṟ', "R"), ('ṟ', "r"), ('Ṡ', "S"), ('ṡ', "s"), ('Ṣ', "S"), ('ṣ', "s"),
+    ('Ṥ', "S"), ('ṥ', "s"), ('Ṧ', "S"), ('ṧ', "s"), ('Ṩ', "S"), ('ṩ', "s"),
+    ('Ṫ', "T"), ('ṫ', "t"), ('Ṭ', "T"), ('ṭ', "t"), ('Ṯ', "T"), ('ṯ', "t"),
+    ('Ṱ', "T"), ('ṱ', "t"), ('Ṳ', "U"), ('ṳ', "u"), ('Ṵ', "U"), ('ṵ', "u"),
+    ('Ṷ', "U"), ('ṷ', "u"), ('Ṹ', "U"), ('ṹ', "u"), ('Ṻ', "U"), ('ṻ', "u"),
+    ('Ṽ', "V"), ('ṽ', "v"), ('Ṿ', "V"), ('ṿ', "v"), ('Ẁ', "W"), ('ẁ', "w"),
+    ('Ẃ', "W"), ('ẃ', "w"), ('Ẅ', "W"), ('ẅ', "w"), ('Ẇ', "W"), ('ẇ', "w"),
+    ('Ẉ', "W"), ('ẉ', "w"), ('Ẋ', "X"), ('ẋ', "x"), ('Ẍ', "X"), ('ẍ', "x"),
+    ('Ẏ', "Y"), ('ẏ', "y"), ('Ẑ', "Z"), ('ẑ', "z"), ('Ẓ', "Z"), ('ẓ', "z"),
+    ('Ẕ', "Z"), ('ẕ', "z"), ('ẖ', "h"), ('ẗ', "t"), ('ẘ', "w"), ('ẙ', "y"),
+    ('ẚ', "a"), ('ẛ', "s"), ('ẜ', "s"), ('ẝ', "s"), ('ẞ', "SS"), ('ẟ', "d"),
+    ('Ạ', "A"), ('ạ', "a"), ('Ả', "A"), ('ả', "a"), ('Ấ', "A"), ('ấ', "a"),
+    ('Ầ', "A"), ('ầ', "a"), ('Ẩ', "A"), ('ẩ', "a"), ('Ẫ', "A"), ('ẫ', "a"),
+    ('Ậ', "A"), ('ậ', "a"), ('Ắ', "A"), ('ắ', "a"), ('Ằ', "A"), ('ằ', "a"),
+    ('Ẳ', "A"), ('ẳ', "a"), ('Ẵ', "A"), ('ẵ', "a"), ('Ặ', "A"), ('ặ', "a"),
+    ('Ẹ', "E"), ('ẹ', "e"), ('Ẻ', "E"), ('ẻ', "e"), ('Ẽ', "E"), ('ẽ', "e"),
+    ('Ế', "E"), ('ế', "e"), ('Ề', "E"), ('ề', "e"), ('Ể', "E"), ('ể', "e"),
+    ('Ễ', "E"), ('ễ', "e"), ('Ệ', "E"), ('ệ', "e"), ('Ỉ', "I"), ('ỉ', "i"),
+    ('Ị', "I"), ('ị', "i"), ('Ọ', "O"), ('ọ', "o"), ('Ỏ', "O"), ('ỏ', "o"),
+    ('Ố', "O"), ('ố', "o"), ('Ồ', "O"), ('ồ', "o"), ('Ổ', "O"), ('ổ', "o"),
+    ('Ỗ', "O"), ('ỗ', "o"), ('Ộ', "O"), ('ộ', "o"), ('Ớ', "O"), ('ớ', "o"),
+    ('Ờ', "O"), ('ờ', "o"), ('Ở', "O"), ('ở', "o"), ('Ỡ', "O"), ('ỡ', "o"),
+    ('Ợ', "O"), ('ợ', "o"), ('Ụ', "U"), ('ụ', "u"), ('Ủ', "U"), ('ủ', "u"),
+    ('Ứ', "U"), ('ứ', "u"), ('Ừ', "U"), ('ừ', "u"), ('Ử', "U"), ('ử', "u"),
+    ('Ữ', "U"), ('ữ', "u"), ('Ự', "U"), ('ự', "u"), ('Ỳ', "Y"), ('ỳ', "y"),
+    ('Ỵ', "Y"), ('ỵ', "y"), ('Ỷ', "Y"), ('ỷ', "y"), ('Ỹ', "Y"), ('ỹ', "y"),
+    ('Ỻ', "LL"), ('ỻ', "ll"), ('Ỽ', "V"), ('ỽ', "v"), ('Ỿ', "Y"), ('ỿ', "y"),
+    ('‘', ""), ('’', ""), ('‚', ""), ('‛', ""), ('“', ""), ('”', ""),
+    ('„', ""), ('‟', ""), ('‹', ""), ('›', ""), ('⁰', "0"), ('ⁱ', "i"),
+    ('⁴', "4"), ('⁵', "5"), ('⁶', "6"), ('⁷', "7"), ('⁸', "8"), ('⁹', "9"),
+    ('ⁿ', "n"), ('₀', "0"), ('₁', "1"), ('₂', "2"), ('₃', "3"), ('₄', "4"),
+    ('₅', "5"), ('₆', "6"), ('₇', "7"), ('₈', "8"), ('₉', "9"), ('ₐ', "a"),
+    ('ₑ', "e"), ('ₒ', "o"), ('ₓ', "x"), ('ₔ', "e"), ('ₕ', "h"), ('ₖ', "k"),
+    ('ₗ', "l"), ('ₘ', "m"), ('ₙ', "n"), ('ₚ', "p"), ('ₛ', "s"), ('ₜ', "t"),
+    ('ℂ', "C"), ('ℇ', "E"), ('ℊ', "g"), ('ℋ', "H"), ('ℌ', "H"), ('ℍ', "H"),
+    ('ℎ', "h"), ('ℏ', "h"), ('ℐ', "I"), ('ℑ', "I"), ('ℒ', "L"), ('ℓ', "l"),
+    ('ℕ', "N"), ('ℙ', "P"), ('ℚ', "Q"), ('ℛ', "R"), ('ℜ', "R"), ('ℝ', "R"),
+    ('ℤ', "Z"), ('\u{2126}', "O"), ('ℨ', "Z"), ('\u{212A}', "K"), ('\u{212B}', "A"), ('ℬ', "B"),
+    ('ℭ', "C"), ('ℯ', "e"), ('ℰ', "E"), ('ℱ', "F"), ('Ⅎ', "F"), ('ℳ', "M"),
+    ('ℴ', "o"), ('ℹ', "i"), ('ℼ', "p"), ('ℽ', "g"), ('ℾ', "G"), ('ℿ', "P"),
+    ('ⅅ', "D"), ('ⅆ', "d"), ('ⅇ', "e"), ('ⅈ', "i"), ('ⅉ', "j"), ('ⅎ', "f"),
+    ('⅐', "1-7"), ('⅑', "1-9"), ('⅒', "1-10"), ('⅓', "1-3"), ('⅔', "2-3"), ('⅕', "1-5"),
+    ('⅖', "2-5"), ('⅗', "3-5"), ('⅘', "4-5"), ('⅙', "1-6"), ('⅚', "5-6"), ('⅛', "1-8"),
+    ('⅜', "3-8"), ('⅝', "5-8"), ('⅞', "7-8"), ('Ⅰ', "I"), ('Ⅱ', "II"), ('Ⅲ', "III"),
+    ('Ⅳ', "IV"), ('Ⅴ', "V"), ('Ⅵ', "VI"), ('Ⅶ', "VII"), ('Ⅷ', "VIII"), ('Ⅸ', "IX"),
+    ('Ⅹ', "X"), ('Ⅺ', "XI"), ('Ⅻ', "XII"), ('Ⅼ', "L"), ('Ⅽ', "C"), ('Ⅾ', "D"),
+    ('Ⅿ', "M"), ('ⅰ', "i"), ('ⅱ', "ii"), ('ⅲ', "iii"), ('ⅳ', "iv"), ('ⅴ', "v"),
+    ('ⅵ', "vi"), ('ⅶ', "vii"), ('ⅷ', "viii"), ('ⅸ', "ix"), ('ⅹ', "x"), ('ⅺ', "xi"),
+    ('ⅻ', "xii"), ('ⅼ', "l"), ('ⅽ', "c"), ('ⅾ', "d"), ('ⅿ', "m"), ('ↀ', "M"),
+    ('ↄ', "c"), ('ↅ', "VI"), ('ↆ', "L"), ('↉', "0-3"), ('①', "1"), ('②', "2"),
+    ('③', "3"), ('④', "4"), ('⑤', "5"), ('⑥', "6"), ('⑦', "7"), ('⑧', "8"),
+    ('⑨', "9"), ('⑩', "10"), ('⑪', "11"), ('⑫', "12"), ('⑬', "13"), ('⑭', "14"),
+    ('⑮', "15"), ('⑯', "16"), ('⑰', "17"), ('⑱', "18"), ('⑲', "19"), ('⑳', "20"),
+    ('⑴', "1"), ('⑵', "2"), ('⑶', "3"), ('⑷', "4"), ('⑸', "5"), ('⑹', "6"),
+    ('⑺', "7"), ('⑻', "8"), ('⑼', "9"), ('⑽', "10"), ('⑾', "11"), ('⑿', "12"),
+    ('⒀', "13"), ('⒁', "14"), ('⒂', "15"), ('⒃', "16"), ('⒄', "17"), ('⒅', "18"),
+    ('⒆', "19"), ('⒇', "20"), ('⒈', "1"), ('⒉', "2"), ('⒊', "3"), ('⒋', "4"),
+    ('⒌', "5"), ('⒍', "6"), ('⒎', "7"), ('⒏', "8"), ('⒐', "9"), ('⒑', "10"),
+    ('⒒', "11"), ('⒓', "12"), ('⒔', "13"), ('⒕', "14"), ('⒖', "15"), ('⒗', "16"),
+    ('⒘', "17"), ('⒙', "18"), ('⒚', "19"), ('⒛', "20"), ('Ⓐ', "A"), ('Ⓑ', "B"),
+    ('Ⓒ', "C"), ('Ⓓ', "D"), ('Ⓔ', "E"), ('Ⓕ', "F"), ('Ⓖ', "G"), ('Ⓗ', "H"),
+    ('Ⓘ', "I"), ('Ⓙ', "J"), ('Ⓚ', "K"), ('Ⓛ', "L"), ('Ⓜ', "M"), ('Ⓝ', "N"),
+    ('Ⓞ', "O"), ('Ⓟ', "P"), ('Ⓠ', "Q"), ('Ⓡ', "R"), ('Ⓢ', "S"), ('Ⓣ', "T"),
+    ('Ⓤ', "U"), ('Ⓥ', "V"), ('Ⓦ', "W"), ('Ⓧ', "X"), ('Ⓨ', "Y"), ('Ⓩ', "Z"),
+    ('ⓐ', "a"), ('ⓑ', "b"), ('ⓒ', "c"), ('ⓓ', "d"), ('ⓔ', "e"), ('ⓕ', "f"),
+    ('ⓖ', "g"), ('ⓗ', "h"), ('ⓘ', "i"), ('ⓙ', "j"), ('ⓚ', "k"), ('ⓛ', "l"),
+    ('ⓜ', "m"), ('ⓝ', "n"), ('ⓞ', "o"), ('ⓟ', "p"), ('ⓠ', "q"), ('ⓡ', "r"),
+    ('ⓢ', "s"), ('ⓣ', "t"), ('ⓤ', "u"), ('ⓥ', "v"), ('ⓦ', "w"), ('ⓧ', "x"),
+    ('ⓨ', "y"), ('ⓩ', "z"), ('⓪', "0"), ('⓫', "11"), ('⓬', "12"), ('⓭', "13"),
+    ('⓮', "14"), ('⓯', "15"), ('⓰', "16"), ('⓱', "17"), ('⓲', "18"), ('⓳', "19"),
+    ('⓴', "20"), ('⓵', "1"), ('⓶', "2"), ('⓷', "3"), ('⓸', "4"), ('⓹', "5"),
+    ('⓺', "6"), ('⓻', "7"), ('⓼', "8"), ('⓽', "9"), ('⓾', "10"), ('⓿', "0"),
+    ('❶', "1"), ('❷', "2"), ('❸', "3"), ('❹', "4"), ('❺', "5"), ('❻', "6"),
+    ('❼', "7"), ('❽', "8"), ('❾', "9"), ('❿', "10"), ('➀', "1"), ('➁', "2"),
+    ('➂', "3"), ('➃', "4"), ('➄', "5"), ('➅', "6"), ('➆', "7"), ('➇', "8"),
+    ('➈', "9"), ('➉', "10"), ('➊', "1"), ('➋', "2"), ('➌', "3"), ('➍', "4"),
+    ('➎', "5"), ('➏', "6"), ('➐', "7"), ('➑', "8"), ('➒', "9"), ('➓', "10"),
+    ('Ⱡ', "L"), ('ⱡ', "l"), ('Ɫ', "L"), ('Ᵽ', "P"), ('Ɽ', "R"), ('ⱥ', "a"),
+    ('ⱦ', "t"), ('Ⱨ', "H"), ('ⱨ', "h"), ('Ⱪ', "K"), ('ⱪ', "k"), ('Ⱬ', "Z"),
+    ('ⱬ', "z"), ('Ɑ', "A"), ('Ɱ', "M"), ('Ɐ', "A"), ('Ɒ', "A"), ('ⱱ', "v"),
+    ('Ⱳ', "W"), ('ⱳ', "w"), ('ⱴ', "v"), ('Ⱶ', "H"), ('ⱶ', "h"), ('ⱷ', "f"),
+    ('ⱸ', "e"), ('ⱹ', "r"), ('ⱺ', "o"), ('ⱻ', "e"), ('ⱼ', "j"), ('ⱽ', "V"),
+    ('Ȿ', "S"), ('Ɀ', "Z"), ('㉈', "10"), ('㉉', "20"), ('㉊', "30"), ('㉋', "40"),
+    ('㉌', "50"), ('㉍', "60"), ('㉎', "70"), ('㉏', "80"), ('㉑', "21"), ('㉒', "22"),
+    ('㉓', "23"), ('㉔', "24"), ('㉕', "25"), ('㉖', "26"), ('㉗', "27"), ('㉘', "28"),
+    ('㉙', "29"), ('㉚', "30"), ('㉛', "31"), ('㉜', "32"), ('㉝', "33"), ('㉞', "34"),
+    ('㉟', "35"), ('㊱', "36"), ('㊲', "37"), ('㊳', "38"), ('㊴', "39"), ('㊵', "40"),
+    ('㊶', "41"), ('㊷', "42"), ('㊸', "43"), ('㊹', "44"), ('㊺', "45"), ('㊻', "46"),
+    ('㊼', "47"), ('㊽', "48"), ('㊾', "49"), ('㊿', "50"), ('꘠', "0"), ('꘡', "1"),
+    ('꘢', "2"), ('꘣', "3"), ('꘤', "4"), ('꘥', "5"), ('꘦', "6"), ('꘧', "7"),
+    ('꘨', "8"), ('꘩', "9"), ('Ꜣ', "A"), ('ꜣ', "a"), ('Ꜧ', "H"), ('ꜧ', "h"),
+    ('Ꜩ', "TZ"), ('ꜩ', "tz"), ('ꜰ', "f"), ('ꜱ', "s"), ('Ꜳ', "AA"), ('ꜳ', "aa"),
+    ('Ꜵ', "AO"), ('ꜵ', "ao"), ('Ꜷ', "AU"), ('ꜷ', "au"), ('Ꜹ', "AV"), ('ꜹ', "av"),
+    ('Ꜻ', "AV"), ('ꜻ', "av"), ('Ꜽ', "AY"), ('ꜽ', "ay"), ('Ꜿ', "C"), ('ꜿ', "c"),
+    ('Ꝁ', "K"), ('ꝁ', "k"), ('Ꝃ', "K"), ('ꝃ', "k"), ('Ꝅ', "K"), ('ꝅ', "k"),
+    ('Ꝇ', "L"), ('ꝇ', "l"), ('Ꝉ', "L"), ('ꝉ', "l"), ('Ꝋ', "O"), ('ꝋ', "o"),
+    ('Ꝍ', "O"), ('ꝍ', "o"), ('Ꝏ', "OO"), ('ꝏ', "oo"), ('Ꝑ', "P"), ('ꝑ', "p"),
+    ('Ꝓ', "P"), ('ꝓ', "p"), ('Ꝕ', "P"), ('ꝕ', "p"), ('Ꝗ', "Q"), ('ꝗ', "q"),
+    ('Ꝙ', "Q"), ('ꝙ', "q"), ('Ꝛ', "R"), ('ꝛ', "r"), ('Ꝝ', "R"), ('ꝝ', "r"),
+    ('Ꝟ', "V"), ('ꝟ', "v"), ('Ꝡ', "VY"), ('ꝡ', "vy"), ('Ꝣ', "Z"), ('ꝣ', "z"),
+    ('Ꝥ', "TH"), ('ꝥ', "th"), ('Ꝧ', "TH"), ('ꝧ', "th"), ('Ꝩ', "V"), ('ꝩ', "v"),
+    ('Ꝫ', "ET"), ('ꝫ', "et"), ('Ꝭ', "IS"), ('ꝭ', "is"), ('Ꝯ', "CON"), ('ꝯ', "con"),
+    ('ꝰ', "us"), ('ꝱ', "d"), ('ꝲ', "l"), ('ꝳ', "m"), ('ꝴ', "n"), ('ꝵ', "r"),
+    ('ꝶ', "r"), ('ꝷ', "t"), ('ꝸ', "um"), ('Ꝺ', "D"), ('ꝺ', "d"), ('Ꝼ', "F"),
+    ('ꝼ', "f"), ('Ᵹ', "G"), ('Ꝿ', "G"), ('ꝿ', "g"), ('Ꞁ', "L"), ('ꞁ', "l"),
+    ('Ꞃ', "R"), ('ꞃ', "r"), ('Ꞅ', "S"), ('ꞅ', "s"), ('Ꞇ', "T"), ('ꞇ', "t"),
+    ('Ɥ', "H"), ('ꞎ', "l"), ('Ꞑ', "N"), ('ꞑ', "n"), ('Ꞓ', "C"), ('ꞓ', "c"),
+    ('ꞔ', "c"), ('ꞕ', "h"), ('Ꞗ', "B"), ('ꞗ', "b"), ('Ꞙ', "F"), ('ꞙ', "f"),
+    ('Ꞛ', "AE"), ('ꞛ', "ae"), ('Ꞝ', "OE"), ('ꞝ', "oe"), ('Ꞟ', "UE"), ('ꞟ', "ue"),
+    ('Ꞡ', "G"), ('ꞡ', "g"), ('Ꞣ', "K"), ('ꞣ', "k"), ('Ꞥ', "N"), ('ꞥ', "n"),
+    ('Ꞧ', "R"), ('ꞧ', "r"), ('Ꞩ', "S"), ('ꞩ', "s"), ('Ɦ', "H"), ('Ɜ', "E"),
+    ('Ɡ', "G"), ('Ɬ', "L"), ('Ɪ', "I"), ('ꞯ', "q"), ('Ʞ', "K"), ('Ʇ', "T"),
+    ('Ʝ', "J"), ('Ꭓ', "X"), ('Ꞵ', "B"), ('ꞵ', "b"), ('Ꞷ', "O"), ('ꞷ', "o"),
+    ('Ꞹ', "U"), ('ꞹ', "u"), ('Ꞻ', "A"), ('ꞻ', "a"), ('Ꞽ', "I"), ('ꞽ', "i"),
+    ('Ꞿ', "U"), ('ꞿ', "u"), ('Ꟁ', "O"), ('ꟁ', "o"), ('Ꟃ', "W"), ('ꟃ', "w"),
+    ('Ꞔ', "C"), ('Ʂ', "S"), ('Ᶎ', "Z"), ('Ꟈ', "D"), ('ꟈ', "d"), ('Ꟊ', "S"),
+    ('ꟊ', "s"), ('\u{A7CB}', "O"), ('\u{A7CC}', "S"), ('\u{A7CD}', "s"), ('Ꟑ', "G"), ('ꟑ', "g"),
+    ('\u{A7D2}', "TH"), ('ꟓ', "th"), ('\u{A7D4}', "W"), ('ꟕ', "w"), ('Ꟗ', "S"), ('ꟗ', "s"),
+    ('Ꟙ', "S"), ('ꟙ', "s"), ('\u{A7DA}', "L"), ('\u{A7DB}', "l"), ('\u{A7DC}', "TL"), ('\u{A7F1}', "S"),
+    ('ꟲ', "C"), ('ꟳ', "F"), ('ꟴ', "Q"), ('Ꟶ', "H"), ('ꟶ', "h"), ('ꟷ', "i"),
+    ('ꟸ', "H"), ('ꟹ', "oe"), ('ꟺ', "m"), ('ꟻ', "F"), ('ꟼ', "P"), ('ꟽ', "M"),
+    ('ꟾ', "I"), ('ꟿ', "M"), ('꣐', "0"), ('꣑', "1"), ('꣒', "2"), ('꣓', "3"),
+    ('꣔', "4"), ('꣕', "5"), ('꣖', "6"), ('꣗', "7"), ('꣘', "8"), ('꣙', "9"),
+    ('꤀', "0"), ('꤁', "1"), ('꤂', "2"), ('꤃', "3"), ('꤄', "4"), ('꤅', "5"),
+    ('꤆', "6"), ('꤇', "7"), ('꤈', "8"), ('꤉', "9"), ('꧐', "0"), ('꧑', "1"),
+    ('꧒', "2"), ('꧓', "3"), ('꧔', "4"), ('꧕', "5"), ('꧖', "6"), ('꧗', "7"),
+    ('꧘', "8"), ('꧙', "9"), ('꧰', "0"), ('꧱', "1"), ('꧲', "2"), ('꧳', "3"),
+    ('꧴', "4"), ('꧵', "5"), ('꧶', "6"), ('꧷', "7"), ('꧸', "8"), ('꧹', "9"),
+    ('꩐', "0"), ('꩑', "1"), ('꩒', "2"), ('꩓', "3"), ('꩔', "4"), ('꩕', "5"),
+    ('꩖', "6"), ('꩗', "7"), ('꩘', "8"), ('꩙', "9"), ('ꬰ', "a"), ('ꬱ', "a"),
+    ('ꬲ', "e"), ('ꬳ', "e"), ('ꬴ', "e"), ('ꬵ', "f"), ('ꬶ', "g"), ('ꬷ', "l"),
+    ('ꬸ', "l"), ('ꬹ', "l"), ('ꬺ', "m"), ('ꬻ', "n"), ('ꬼ', "ng"), ('ꬽ', "o"),
+    ('ꬾ', "o"), ('ꬿ', "o"), ('ꭀ', "oe"), ('ꭁ', "oe"), ('ꭂ', "oe"), ('ꭃ', "o"),
+    ('ꭄ', "o"), ('ꭅ', "r"), ('ꭆ', "r"), ('ꭇ', "r"), ('ꭈ', "rr"), ('ꭉ', "r"),
+    ('ꭊ', "rr"), ('ꭋ', "r"), ('ꭌ', "r"), ('ꭍ', "sh"), ('ꭎ', "u"), ('ꭏ', "u"),
+    ('ꭐ', "ui"), ('ꭑ', "ui"), ('ꭒ', "u"), ('ꭓ', "x"), ('ꭔ', "x"), ('ꭕ', "x"),
+    ('ꭖ', "x"), ('ꭗ', "x"), ('ꭘ', "x"), ('ꭙ', "x"), ('ꭚ', "y"), ('ꭜ', "h"),
+    ('ꭝ', "l"), ('ꭞ', "l"), ('ꭟ', "u"), ('ꭠ', "e"), ('ꭡ', "ie"), ('ꭢ', "oe"),
+    ('ꭣ', "uo"), ('ꭤ', "a"), ('ꭥ', "o"), ('ꭦ', "dz"), ('ꭧ', "ts"), ('ꭨ', "r"),
+    ('ꭩ', "w"), ('꯰', "0"), ('꯱', "1"), ('꯲', "2"), ('꯳', "3"), ('꯴', "4"),
+    ('꯵', "5"), ('꯶', "6"), ('꯷', "7"), ('꯸', "8"), ('꯹', "9"), ('＂', ""),
+    ('＇', ""), ('０', "0"), ('１', "1"), ('２', "2"), ('３', "3"), ('４', "4"),
+    ('５', "5"), ('６', "6"), ('７', "7"), ('８', "8"), ('９', "9"), ('Ａ', "A"),
+    ('Ｂ', "B"), ('Ｃ', "C"), ('Ｄ', "D"), ('Ｅ', "E"), ('Ｆ', "F"), ('Ｇ', "G"),
+    ('Ｈ', "H"), ('Ｉ', "I"), ('Ｊ', "J"), ('Ｋ', "K"), ('Ｌ', "L"), ('Ｍ', "M"),
+    ('Ｎ', "N"), ('Ｏ', "O"), ('Ｐ', "P"), ('Ｑ', "Q"), ('Ｒ', "R"), ('Ｓ', "S"),
+    ('Ｔ', "T"), ('Ｕ', "U"), ('Ｖ', "V"), ('Ｗ', "W"), ('Ｘ', "X"), ('Ｙ', "Y"),
+    ('Ｚ', "Z"), ('ａ', "a"), ('ｂ', "b"), ('ｃ', "c"), ('ｄ', "d"), ('ｅ', "e"),
+    ('ｆ', "f"), ('ｇ', "g"), ('ｈ', "h"), ('ｉ', "i"), ('ｊ', "j"), ('ｋ', "k"),
+    ('ｌ', "l"), ('ｍ', "m"), ('ｎ', "n"), ('ｏ', "o"), ('ｐ', "p"), ('ｑ', "q"),
+    ('ｒ', "r"), ('ｓ', "s"), ('ｔ', "t"), ('ｕ', "u"), ('ｖ', "v"), ('ｗ', "w"),
+    ('ｘ', "x"), ('ｙ', "y"), ('ｚ', "z"), ('𐒠', "0"), ('𐒡', "1"), ('𐒢', "2"),
+    ('𐒣', "3"), ('𐒤', "4"), ('𐒥', "5"), ('𐒦', "6"), ('𐒧', "7"), ('𐒨', "8"),
+    ('𐒩', "9"), ('𐞀', "aa"), ('𐞃', "ae"), ('𐞄', "b"), ('𐞅', "b"), ('𐞇', "dz"),
+    ('𐞈', "dz"), ('𐞉', "dz"), ('𐞊', "dzh"), ('𐞋', "d"), ('𐞌', "d"), ('𐞍', "d"),
+    ('𐞎', "e"), ('𐞏', "e"), ('𐞐', "fng"), ('𐞑', "o"), ('𐞒', "g"), ('𐞓', "g"),
+    ('𐞔', "g"), ('𐞕', "h"), ('𐞖', "h"), ('𐞗', "h"), ('𐞘', "j"), ('𐞙', "ls"),
+    ('𐞚', "lz"), ('𐞛', "l"), ('𐞜', "l"), ('𐞝', "l"), ('𐞞', "l"), ('𐞟', "l"),
+    ('𐞠', "y"), ('𐞡', "y"), ('𐞢', "o"), ('𐞣', "oe"), ('𐞤', "o"), ('𐞥', "q"),
+    ('𐞦', "r"), ('𐞧', "r"), ('𐞨', "r"), ('𐞩', "r"), ('𐞪', "r"), ('𐞫', "tc"),
+    ('𐞬', "ts"), ('𐞭', "ts"), ('𐞮', "tsh"), ('𐞯', "t"), ('𐞰', "v"), ('𐞲', "y"),
+    ('𐞺', "s"), ('𐴰', "0"), ('𐴱', "1"), ('𐴲', "2"), ('𐴳', "3"), ('𐴴', "4"),
+    ('𐴵', "5"), ('𐴶', "6"), ('𐴷', "7"), ('𐴸', "8"), ('𐴹', "9"), ('\u{10D40}', "0"),
+    ('\u{10D41}', "1"), ('\u{10D42}', "2"), ('\u{10D43}', "3"), ('\u{10D44}', "4"), ('\u{10D45}', "5"), ('\u{10D46}', "6"),
+    ('\u{10D47}', "7"), ('\u{10D48}', "8"), ('\u{10D49}', "9"), ('𑁦', "0"), ('𑁧', "1"), ('𑁨', "2"),
+    ('𑁩', "3"), ('𑁪', "4"), ('𑁫', "5"), ('𑁬', "6"), ('𑁭', "7"), ('𑁮', "8"),
+    ('𑁯', "9"), ('𑃰', "0"), ('𑃱', "1"), ('𑃲', "2"), ('𑃳', "3"), ('𑃴', "4"),
+    ('𑃵', "5"), ('𑃶', "6"), ('𑃷', "7"), ('𑃸', "8"), ('𑃹', "9"), ('𑄶', "0"),
+    ('𑄷', "1"), ('𑄸', "2"), ('𑄹', "3"), ('𑄺', "4"), ('𑄻', "5"), ('𑄼', "6"),
+    ('𑄽', "7"), ('𑄾', "8"), ('𑄿', "9"), ('𑇐', "0"), ('𑇑', "1"), ('𑇒', "2"),
+    ('𑇓', "3"), ('𑇔', "4"), ('𑇕', "5"), ('𑇖', "6"), ('𑇗', "7"), ('𑇘', "8"),
+    ('𑇙', "9"), ('𑋰', "0"), ('𑋱', "1"), ('𑋲', "2"), ('𑋳', "3"), ('𑋴', "4"),
+    ('𑋵', "5"), ('𑋶', "6"), ('𑋷', "7"), ('𑋸', "8"), ('𑋹', "9"), ('𑑐', "0"),
+    ('𑑑', "1"), ('𑑒', "2"), ('𑑓', "3"), ('𑑔', "4"), ('𑑕', "5"), ('𑑖', "6"),
+    ('𑑗', "7"), ('𑑘', "8"), ('𑑙', "9"), ('𑓐', "0"), ('𑓑', "1"), ('𑓒', "2"),
+    ('𑓓', "3"), ('𑓔', "4"), ('𑓕', "5"), ('𑓖', "6"), ('𑓗', "7"), ('𑓘', "8"),
+    ('𑓙', "9"), ('𑙐', "0"), ('𑙑', "1"), ('𑙒', "2"), ('𑙓', "3"), ('𑙔', "4"),
+    ('𑙕', "5"), ('𑙖', "6"), ('𑙗', "7"), ('𑙘', "8"), ('𑙙', "9"), ('𑛀', "0"),
+    ('𑛁', "1"), ('𑛂', "2"), ('𑛃', "3"), ('𑛄', "4"), ('𑛅', "5"), ('𑛆', "6"),
+    ('𑛇', "7"), ('𑛈', "8"), ('𑛉', "9"), ('\u{116D0}', "0"), ('\u{116D1}', "1"), ('\u{116D2}', "2"),
+    ('\u{116D3}', "3"), ('\u{116D4}', "4"), ('\u{116D5}', "5"), ('\u{116D6}', "6"), ('\u{116D7}', "7"), ('\u{116D8}', "8"),
+    ('\u{116D9}', "9"), ('\u{116DA}', "0"), ('\u{116DB}', "1"), ('\u{116DC}', "2"), ('\u{116DD}', "3"), ('\u{116DE}', "4"),
+    ('\u{116DF}', "5"), ('\u{116E0}', "6"), ('\u{116E1}', "7"), ('\u{116E2}', "8"), ('\u{116E3}', "9"), ('𑜰', "0"),
+    ('𑜱', "1"), ('𑜲', "2"), ('𑜳', "3"), ('𑜴', "4"), ('𑜵', "5"), ('𑜶', "6"),
+    ('𑜷', "7"), ('𑜸', "8"), ('𑜹', "9"), ('𑣠', "0"), ('𑣡', "1"), ('𑣢', "2"),
+    ('𑣣', "3"), ('𑣤', "4"), ('𑣥', "5"), ('𑣦', "6"), ('𑣧', "7"), ('𑣨', "8"),
+    ('𑣩', "9"), ('𑥐', "0"), ('𑥑', "1"), ('𑥒', "2"), ('𑥓', "3"), ('𑥔', "4"),
+    ('𑥕', "5"), ('𑥖', "6"), ('𑥗', "7"), ('𑥘', "8"), ('𑥙', "9"), ('\u{11BF0}', "0"),
+    ('\u{11BF1}', "1"), ('\u{11BF2}', "2"), ('\u{11BF3}', "3"), ('\u{11BF4}', "4"), ('\u{11BF5}', "5"), ('\u{11BF6}', "6"),
+    ('\u{11BF7}', "7"), ('\u{11BF8}', "8"), ('\u{11BF9}', "9"), ('𑱐', "0"), ('𑱑', "1"), ('𑱒', "2"),
+    ('𑱓', "3"), ('𑱔', "4"), ('𑱕', "5"), ('𑱖', "6"), ('𑱗', "7"), ('𑱘', "8"),
+    ('𑱙', "9"), ('𑵐', "0"), ('𑵑', "1"), ('𑵒', "2"), ('𑵓', "3"), ('𑵔', "4"),
+    ('𑵕', "5"), ('𑵖', "6"), ('𑵗', "7"), ('𑵘', "8"), ('𑵙', "9"), ('𑶠', "0"),
+    ('𑶡', "1"), ('𑶢', "2"), ('𑶣', "3"), ('𑶤', "4"), ('𑶥', "5"), ('𑶦', "6"),
+    ('𑶧', "7"), ('𑶨', "8"), ('𑶩', "9"), ('\u{11DE0}', "0"), ('\u{11DE1}', "1"), ('\u{11DE2}', "2"),
+    ('\u{11DE3}', "3"), ('\u{11DE4}', "4"), ('\u{11DE5}', "5"), ('\u{11DE6}', "6"), ('\u{11DE7}', "7"), ('\u{11DE8}', "8"),
+    ('\u{11DE9}', "9"), ('𑽐', "0"), ('𑽑', "1"), ('𑽒', "2"), ('𑽓', "3"), ('𑽔', "4"),
+    ('𑽕', "5"), ('𑽖', "6"), ('𑽗', "7"), ('𑽘', "8"), ('𑽙', "9"), ('\u{16130}', "0"),
+    ('\u{16131}', "1"), ('\u{16132}', "2"), ('\u{16133}', "3"), ('\u{16134}', "4"), ('\u{16135}', "5"), ('\u{16136}', "6"),
+    ('\u{16137}', "7"), ('\u{16138}', "8"), ('\u{16139}', "9"), ('𖩠', "0"), ('𖩡', "1"), ('𖩢', "2"),
+    ('𖩣', "3"), ('𖩤', "4"), ('𖩥', "5"), ('𖩦', "6"), ('𖩧', "7"), ('𖩨', "8"),
+    ('𖩩', "9"), ('𖫀', "0"), ('𖫁', "1"), ('𖫂', "2"), ('𖫃', "3"), ('𖫄', "4"),
+    ('𖫅', "5"), ('𖫆', "6"), ('𖫇', "7"), ('𖫈', "8"), ('𖫉', "9"), ('𖭐', "0"),
+    ('𖭑', "1"), ('𖭒', "2"), ('𖭓', "3"), ('𖭔', "4"), ('𖭕', "5"), ('𖭖', "6"),
+    ('𖭗', "7"), ('𖭘', "8"), ('𖭙', "9"), ('\u{16D70}', "0"), ('\u{16D71}', "1"), ('\u{16D72}', "2"),
+    ('\u{16D73}', "3"), ('\u{16D74}', "4"), ('\u{16D75}', "5"), ('\u{16D76}', "6"), ('\u{16D77}', "7"), ('\u{16D78}', "8"),
+    ('\u{16D79}', "9"), ('\u{1CCF0}', "0"), ('\u{1CCF1}', "1"), ('\u{1CCF2}', "2"), ('\u{1CCF3}', "3"), ('\u{1CCF4}', "4"),
+    ('\u{1CCF5}', "5"), ('\u{1CCF6}', "6"), ('\u{1CCF7}', "7"), ('\u{1CCF8}', "8"), ('\u{1CCF9}', "9"), ('𝐀', "A"),
+    ('𝐁', "B"), ('𝐂', "C"), ('𝐃', "D"), ('𝐄', "E"), ('𝐅', "F"), ('𝐆', "G"),
+    ('𝐇', "H"), ('𝐈', "I"), ('𝐉', "J"), ('𝐊', "K"), ('𝐋', "L"), ('𝐌', "M"),
+    ('𝐍', "N"), ('𝐎', "O"), ('𝐏', "P"), ('𝐐', "Q"), ('𝐑', "R"), ('𝐒', "S"),
+    ('𝐓', "T"), ('𝐔', "U"), ('𝐕', "V"), ('𝐖', "W"), ('𝐗', "X"), ('𝐘', "Y"),
+    ('𝐙', "Z"), ('𝐚', "a"), ('𝐛', "b"), ('𝐜', "c"), ('𝐝', "d"), ('𝐞', "e"),
+    ('𝐟', "f"), ('𝐠', "g"), ('𝐡', "h"), ('𝐢', "i"), ('𝐣', "j"), ('𝐤', "k"),
+    ('𝐥', "l"), ('𝐦', "m"), ('𝐧', "n"), ('𝐨', "o"), ('𝐩', "p"), ('𝐪', "q"),
+    ('𝐫', "r"), ('𝐬', "s"), ('𝐭', "t"), ('𝐮', "u"), ('𝐯', "v"), ('𝐰', "w"),
+    ('𝐱', "x"), ('𝐲', "y"), ('𝐳', "z"), ('𝐴', "A"), ('𝐵', "B"), ('𝐶', "C"),
+    ('𝐷', "D"), ('𝐸', "E"), ('𝐹', "F"), ('𝐺', "G"), ('𝐻', "H"), ('𝐼', "I"),
+    ('𝐽', "J"), ('𝐾', "K"), ('𝐿', "L"), ('𝑀', "M"), ('𝑁', "N"), ('𝑂', "O"),
+    ('𝑃', "P"), ('𝑄', "Q"), ('𝑅', "R"), ('𝑆', "S"), ('𝑇', "T"), ('𝑈', "U"),
+    ('𝑉', "V"), ('𝑊', "W"), ('𝑋', "X"), ('𝑌', "Y"), ('𝑍', "Z"), ('𝑎', "a"),
+    ('𝑏', "b"), ('𝑐', "c"), ('𝑑', "d"), ('𝑒', "e"), ('𝑓', "f"), ('𝑔', "g"),
+    ('𝑖', "i"), ('𝑗', "j"), ('𝑘', "k"), ('𝑙', "l"), ('𝑚', "m"), ('𝑛', "n"),
+    ('𝑜', "o"), ('𝑝', "p"), ('𝑞', "q"), ('𝑟', "r"), ('𝑠', "s"), ('𝑡', "t"),
+    ('𝑢', "u"), ('𝑣', "v"), ('𝑤', "w"), ('𝑥', "x"), ('𝑦', "y"), ('𝑧', "z"),
+    ('𝑨', "A"), ('𝑩', "B"), ('𝑪', "C"), ('𝑫', "D"), ('𝑬', "E"), ('𝑭', "F"),
+    ('𝑮', "G"), ('𝑯', "H"), ('𝑰', "I"), ('𝑱', "J"), ('𝑲', "K"), ('𝑳', "L"),
+    ('𝑴', "M"), ('𝑵', "N"), ('𝑶', "O"), ('𝑷', "P"), ('𝑸', "Q"), ('𝑹', "R"),
+    ('𝑺', "S"), ('𝑻', "T"), ('𝑼', "U"), ('𝑽', "V"), ('𝑾', "W"), ('𝑿', "X"),
+    ('𝒀', "Y"), ('𝒁', "Z"), ('𝒂', "a"), ('𝒃', "b"), ('𝒄', "c"), ('𝒅', "d"),
+    ('𝒆', "e"), ('𝒇', "f"), ('𝒈', "g"), ('𝒉', "h"), ('𝒊', "i"), ('𝒋', "j"),
+    ('𝒌', "k"), ('𝒍', "l"), ('𝒎', "m"), ('𝒏', "n"), ('𝒐', "o"), ('𝒑', "p"),
+    ('𝒒', "q"), ('𝒓', "r"), ('𝒔', "s"), ('𝒕', "t"), ('𝒖', "u"), ('𝒗', "v"),
+    ('𝒘', "w"), ('𝒙', "x"), ('𝒚', "y"), ('𝒛', "z"), ('𝒜', "A"), ('𝒞', "C"),
+    ('𝒟', "D"), ('𝒢', "G"), ('𝒥', "J"), ('𝒦', "K"), ('𝒩', "N"), ('𝒪', "O"),
+    ('𝒫', "P"), ('𝒬', "Q"), ('𝒮', "S"), ('𝒯', "T"), ('𝒰', "U"), ('𝒱', "V"),
+    ('𝒲', "W"), ('𝒳', "X"), ('𝒴', "Y"), ('𝒵', "Z"), ('𝒶', "a"), ('𝒷', "b"),
+    ('𝒸', "c"), ('𝒹', "d"), ('𝒻', "f"), ('𝒽', "h"), ('𝒾', "i"), ('𝒿', "j"),
+    ('𝓀', "k"), ('𝓁', "l"), ('𝓂', "m"), ('𝓃', "n"), ('𝓅', "p"), ('𝓆', "q"),
+    ('𝓇', "r"), ('𝓈', "s"), ('𝓉', "t"), ('𝓊', "u"), ('𝓋', "v"), ('𝓌', "w"),
+    ('𝓍', "x"), ('𝓎', "y"), ('𝓏', "z"), ('𝓐', "A"), ('𝓑', "B"), ('𝓒', "C"),
+    ('𝓓', "D"), ('𝓔', "E"), ('𝓕', "F"), ('𝓖', "G"), ('𝓗', "H"), ('𝓘', "I"),
+    ('𝓙', "J"), ('𝓚', "K"), ('𝓛', "L"), ('𝓜', "M"), ('𝓝', "N"), ('𝓞', "O"),
+    ('𝓟', "P"), ('𝓠', "Q"), ('𝓡', "R"), ('𝓢', "S"), ('𝓣', "T"), ('𝓤', "U"),
+    ('𝓥', "V"), ('𝓦', "W"), ('𝓧', "X"), ('𝓨', "Y"), ('𝓩', "Z"), ('𝓪', "a"),
+    ('𝓫', "b"), ('𝓬', "c"), ('𝓭', "d"), ('𝓮', "e"), ('𝓯', "f"), ('𝓰', "g"),
+    ('𝓱', "h"), ('𝓲', "i"), ('𝓳', "j"), ('𝓴', "k"), ('𝓵', "l"), ('𝓶', "m"),
+    ('𝓷', "n"), ('𝓸', "o"), ('𝓹', "p"), ('𝓺', "q"), ('𝓻', "r"), ('𝓼', "s"),
+    ('𝓽', "t"), ('𝓾', "u"), ('𝓿', "v"), ('𝔀', "w"), ('𝔁', "x"), ('𝔂', "y"),
+    ('𝔃', "z"), ('𝔄', "A"), ('𝔅', "B"), ('𝔇', "D"), ('𝔈', "E"), ('𝔉', "F"),
+    ('𝔊', "G"), ('𝔍', "J"), ('𝔎', "K"), ('𝔏', "L"), ('𝔐', "M"), ('𝔑', "N"),
+    ('𝔒', "O"), ('𝔓', "P"), ('𝔔', "Q"), ('𝔖', "S"), ('𝔗', "T"), ('𝔘', "U"),
+    ('𝔙', "V"), ('𝔚', "W"), ('𝔛', "X"), ('𝔜', "Y"), ('𝔞', "a"), ('𝔟', "b"),
+    ('𝔠', "c"), ('𝔡', "d"), ('𝔢', "e"), ('𝔣', "f"), ('𝔤', "g"), ('𝔥', "h"),
+    ('𝔦', "i"), ('𝔧', "j"), ('𝔨', "k"), ('𝔩', "l"), ('𝔪', "m"), ('𝔫', "n"),
+    ('𝔬', "o"), ('𝔭', "p"), ('𝔮', "q"), ('𝔯', "r"), ('𝔰', "s"), ('𝔱', "t"),
+    ('𝔲', "u"), ('𝔳', "v"), ('𝔴', "w"), ('𝔵', "x"), ('𝔶', "y"), ('𝔷', "z"),
+    ('𝔸', "A"), ('𝔹', "B"), ('𝔻', "D"), ('𝔼', "E"), ('𝔽', "F"), ('𝔾', "G"),
+    ('𝕀', "I"), ('𝕁', "J"), ('𝕂', "K"), ('𝕃', "L"), ('𝕄', "M"), ('𝕆', "O"),
+    ('𝕊', "S"), ('𝕋', "T"), ('𝕌', "U"), ('𝕍', "V"), ('𝕎', "W"), ('𝕏', "X"),
+    ('𝕐', "Y"), ('𝕒', "a"), ('𝕓', "b"), ('𝕔', "c"), ('𝕕', "d"), ('𝕖', "e"),
+    ('𝕗', "f"), ('𝕘', "g"), ('𝕙', "h"), ('𝕚', "i"), ('𝕛', "j"), ('𝕜', "k"),
+    ('𝕝', "l"), ('𝕞', "m"), ('𝕟', "n"), ('𝕠', "o"), ('𝕡', "p"), ('𝕢', "q"),
+    ('𝕣', "r"), ('𝕤', "s"), ('𝕥', "t"), ('𝕦', "u"), ('𝕧', "v"), ('𝕨', "w"),
+    ('𝕩', "x"), ('𝕪', "y"), ('𝕫', "z"), ('𝕬', "A"), ('𝕭', "B"), ('𝕮', "C"),
+    ('𝕯', "D"), ('𝕰', "E"), ('𝕱', "F"), ('𝕲', "G"), ('𝕳', "H"), ('𝕴', "I"),
+    ('𝕵', "J"), ('𝕶', "K"), ('𝕷', "L"), ('𝕸', "M"), ('𝕹', "N"), ('𝕺', "O"),
+    ('𝕻', "P"), ('𝕼', "Q"), ('𝕽', "R"), ('𝕾', "S"), ('𝕿', "T"), ('𝖀', "U"),
+    ('𝖁', "V"), ('𝖂', "W"), ('𝖃', "X"), ('𝖄', "Y"), ('𝖅', "Z"), ('𝖆', "a"),
+    ('𝖇', "b"), ('𝖈', "c"), ('𝖉', "d"), ('𝖊', "e"), ('𝖋', "f"), ('𝖌', "g"),
+    ('𝖍', "h"), ('𝖎', "i"), ('𝖏', "j"), ('𝖐', "k"), ('𝖑', "l"), ('𝖒', "m"),
+    ('𝖓', "n"), ('𝖔', "o"), ('𝖕', "p"), ('𝖖', "q"), ('𝖗', "r"), ('𝖘', "s"),
+    ('𝖙', "t"), ('𝖚', "u"), ('𝖛', "v"), ('𝖜', "w"), ('𝖝', "x"), ('𝖞', "y"),
+    ('𝖟', "z"), ('𝖠', "A"), ('𝖡', "B"), ('𝖢', "C"), ('𝖣', "D"), ('𝖤', "E"),
+    ('𝖥', "F"), ('𝖦', "G"), ('𝖧', "H"), ('𝖨', "I"), ('𝖩', "J"), ('𝖪', "K"),
+    ('𝖫', "L"), ('𝖬', "M"), ('𝖭', "N"), ('𝖮', "O"), ('𝖯', "P"), ('𝖰', "Q"),
+    ('𝖱', "R"), ('𝖲', "S"), ('𝖳', "T"), ('𝖴', "U"), ('𝖵', "V"), ('𝖶', "W"),
+    ('𝖷', "X"), ('𝖸', "Y"), ('𝖹', "Z"), ('𝖺', "a"), ('𝖻', "b"), ('𝖼', "c"),
+    ('𝖽', "d"), ('𝖾', "e"), ('𝖿', "f"), ('𝗀', "g"), ('𝗁', "h"), ('𝗂', "i"),
+    ('𝗃', "j"), ('𝗄', "k"), ('𝗅', "l"), ('𝗆', "m"), ('𝗇', "n"), ('𝗈', "o"),
+    ('𝗉', "p"), ('𝗊', "q"), ('𝗋', "r"), ('𝗌', "s"), ('𝗍', "t"), ('𝗎', "u"),
+    ('𝗏', "v"), ('𝗐', "w"), ('𝗑', "x"), ('𝗒', "y"), ('𝗓', "z"), ('𝗔', "A"),
+    ('𝗕', "B"), ('𝗖', "C"), ('𝗗', "D"), ('𝗘', "E"), ('𝗙', "F"), ('𝗚', "G"),
+    ('𝗛', "H"), ('𝗜', "I"), ('𝗝', "J"), ('𝗞', "K"), ('𝗟', "L"), ('𝗠', "M"),
+    ('𝗡', "N"), ('𝗢', "O"), ('𝗣', "P"), ('𝗤', "Q"), ('𝗥', "R"), ('𝗦', "S"),
+    ('𝗧', "T"), ('𝗨', "U"), ('𝗩', "V"), ('𝗪', "W"), ('𝗫', "X"), ('𝗬', "Y"),
+    ('𝗭', "Z"), ('𝗮', "a"), ('𝗯', "b"), ('𝗰', "c"), ('𝗱', "d"), ('𝗲', "e"),
+    ('𝗳', "f"), ('𝗴', "g"), ('𝗵', "h"), ('𝗶', "i"), ('𝗷', "j"), ('𝗸', "k"),
+    ('𝗹', "l"), ('𝗺', "m"), ('𝗻', "n"), ('𝗼', "o"), ('𝗽', "p"), ('𝗾', "q"),
+    ('𝗿', "r"), ('𝘀', "s"), ('𝘁', "t"), ('𝘂', "u"), ('𝘃', "v"), ('𝘄', "w"),
+    ('𝘅', "x"), ('𝘆', "y"), ('𝘇', "z"), ('𝘈', "A"), ('𝘉', "B"), ('𝘊', "C"),
+    ('𝘋', "D"), ('𝘌', "E"), ('𝘍', "F"), ('𝘎', "G"), ('𝘏', "H"), ('𝘐', "I"),
+    ('𝘑', "J"), ('𝘒', "K"), ('𝘓', "L"), ('𝘔', "M"), ('𝘕', "N"), ('𝘖', "O"),
+    ('𝘗', "P"), ('𝘘', "Q"), ('𝘙', "R"), ('𝘚', "S"), ('𝘛', "T"), ('𝘜', "U"),
+    ('𝘝', "V"), ('𝘞', "W"), ('𝘟', "X"), ('𝘠', "Y"), ('𝘡', "Z"), ('𝘢', "a"),
+    ('𝘣', "b"), ('𝘤', "c"), ('𝘥', "d"), ('𝘦', "e"), ('𝘧', "f"), ('𝘨', "g"),
+    ('𝘩', "h"), ('𝘪', "i"), ('𝘫', "j"), ('𝘬', "k"), ('𝘭', "l"), ('𝘮', "m"),
+    ('𝘯', "n"), ('𝘰', "o"), ('𝘱', "p"), ('𝘲', "q"), ('𝘳', "r"), ('𝘴', "s"),
+    ('𝘵', "t"), ('𝘶', "u"), ('𝘷', "v"), ('𝘸', "w"), ('𝘹', "x"), ('𝘺', "y"),
+    ('𝘻', "z"), ('𝘼', "A"), ('𝘽', "B"), ('𝘾', "C"), ('𝘿', "D"), ('𝙀', "E"),
+    ('𝙁', "F"), ('𝙂', "G"), ('𝙃', "H"), ('𝙄', "I"), ('𝙅', "J"), ('𝙆', "K"),
+    ('𝙇', "L"), ('𝙈', "M"), ('𝙉', "N"), ('𝙊', "O"), ('𝙋', "P"), ('𝙌', "Q"),
+    ('𝙍', "R"), ('𝙎', "S"), ('𝙏', "T"), ('𝙐', "U"), ('𝙑', "V"), ('𝙒', "W"),
+    ('𝙓', "X"), ('𝙔', "Y"), ('𝙕', "Z"), ('𝙖', "a"), ('𝙗', "b"), ('𝙘', "c"),
+    ('𝙙', "d"), ('𝙚', "e"), ('𝙛', "f"), ('𝙜', "g"), ('𝙝', "h"), ('𝙞', "i"),
+    ('𝙟', "j"), ('𝙠', "k"), ('𝙡', "l"), ('𝙢', "m"), ('𝙣', "n"), ('𝙤', "o"),
+    ('𝙥', "p"), ('𝙦', "q"), ('𝙧', "r"), ('𝙨', "s"), ('𝙩', "t"), ('𝙪', "u"),
+    ('𝙫', "v"), ('𝙬', "w"), ('𝙭', "x"), ('𝙮', "y"), ('𝙯', "z"), ('𝙰', "A"),
+    ('𝙱', "B"), ('𝙲', "C"), ('𝙳', "D"), ('𝙴', "E"), ('𝙵', "F"), ('𝙶', "G"),
+    ('𝙷', "H"), ('𝙸', "I"), ('𝙹', "J"), ('𝙺', "K"), ('𝙻', "L"), ('𝙼', "M"),
+    ('𝙽', "N"), ('𝙾', "O"), ('𝙿', "P"), ('𝚀', "Q"), ('𝚁', "R"), ('𝚂', "S"),
+    ('𝚃', "T"), ('𝚄', "U"), ('𝚅', "V"), ('𝚆', "W"), ('𝚇', "X"), ('𝚈', "Y"),
+    ('𝚉', "Z"), ('𝚊', "a"), ('𝚋', "b"), ('𝚌', "c"), ('𝚍', "d"), ('𝚎', "e"),
+    ('𝚏', "f"), ('𝚐', "g"), ('𝚑', "h"), ('𝚒', "i"), ('𝚓', "j"), ('𝚔', "k"),
+    ('𝚕', "l"), ('𝚖', "m"), ('𝚗', "n"), ('𝚘', "o"), ('𝚙', "p"), ('𝚚', "q"),
+    ('𝚛', "r"), ('𝚜', "s"), ('𝚝', "t"), ('𝚞', "u"), ('𝚟', "v"), ('𝚠', "w"),
+    ('𝚡', "x"), ('𝚢', "y"), ('𝚣', "z"), ('𝚤', "i"), ('𝚥', "j"), ('𝚨', "A"),
+    ('𝚩', "V"), ('𝚪', "G"), ('𝚫', "D"), ('𝚬', "E"), ('𝚭', "Z"), ('𝚮', "I"),
+    ('𝚯', "TH"), ('𝚰', "I"), ('𝚱', "K"), ('𝚲', "L"), ('𝚳', "M"), ('𝚴', "N"),
+    ('𝚵', "X"), ('𝚶', "O"), ('𝚷', "P"), ('𝚸', "R"), ('𝚹', "TH"), ('𝚺', "S"),
+    ('𝚻', "T"), ('𝚼', "Y"), ('𝚽', "F"), ('𝚾', "CH"), ('𝚿', "PS"), ('𝛀', "O"),
+    ('𝛂', "a"), ('𝛃', "v"), ('𝛄', "g"), ('𝛅', "d"), ('𝛆', "e"), ('𝛇', "z"),
+    ('𝛈', "i"), ('𝛉', "th"), ('𝛊', "i"), ('𝛋', "k"), ('𝛌', "l"), ('𝛍', "m"),
+    ('𝛎', "n"), ('𝛏', "x"), ('𝛐', "o"), ('𝛑', "p"), ('𝛒', "r"), ('𝛓', "s"),
+    ('𝛔', "s"), ('𝛕', "t"), ('𝛖', "y"), ('𝛗', "f"), ('𝛘', "ch"), ('𝛙', "ps"),
+    ('𝛚', "o"), ('𝛜', "e"), ('𝛝', "th"), ('𝛞', "k"), ('𝛟', "f"), ('𝛠', "r"),
+    ('𝛡', "p"), ('𝛢', "A"), ('𝛣', "V"), ('𝛤', "G"), ('𝛥', "D"), ('𝛦', "E"),
+    ('𝛧', "Z"), ('𝛨', "I"), ('𝛩', "TH"), ('𝛪', "I"), ('𝛫', "K"), ('𝛬', "L"),
+    ('𝛭', "M"), ('𝛮', "N"), ('𝛯', "X"), ('𝛰', "O"), ('𝛱', "P"), ('𝛲', "R"),
+    ('𝛳', "TH"), ('𝛴', "S"), ('𝛵', "T"), ('𝛶', "Y"), ('𝛷', "F"), ('𝛸', "CH"),
+    ('𝛹', "PS"), ('𝛺', "O"), ('𝛼', "a"), ('𝛽', "v"), ('𝛾', "g"), ('𝛿', "d"),
+    ('𝜀', "e"), ('𝜁', "z"), ('𝜂', "i"), ('𝜃', "th"), ('𝜄', "i"), ('𝜅', "k"),
+    ('𝜆', "l"), ('𝜇', "m"), ('𝜈', "n"), ('𝜉', "x"), ('𝜊', "o"), ('𝜋', "p"),
+    ('𝜌', "r"), ('𝜍', "s"), ('𝜎', "s"), ('𝜏', "t"), ('𝜐', "y"), ('𝜑', "f"),
+    ('𝜒', "ch"), ('𝜓', "ps"), ('𝜔', "o"), ('𝜖', "e"), ('𝜗', "th"), ('𝜘', "k"),
+    ('𝜙', "f"), ('𝜚', "r"), ('𝜛', "p"), ('𝜜', "A"), ('𝜝', "V"), ('𝜞', "G"),
+    ('𝜟', "D"), ('𝜠', "E"), ('𝜡', "Z"), ('𝜢', "I"), ('𝜣', "TH"), ('𝜤', "I"),
+    ('𝜥', "K"), ('𝜦', "L"), ('𝜧', "M"), ('𝜨', "N"), ('𝜩', "X"), ('𝜪', "O"),
+    ('𝜫', "P"), ('𝜬', "R"), ('𝜭', "TH"), ('𝜮', "S"), ('𝜯', "T"), ('𝜰', "Y"),
+    ('𝜱', "F"), ('𝜲', "CH"), ('𝜳', "PS"), ('𝜴', "O"), ('𝜶', "a"), ('𝜷', "v"),
+    ('𝜸', "g"), ('𝜹', "d"), ('𝜺', "e"), ('𝜻', "z"), ('𝜼', "i"), ('𝜽', "th"),
+    ('𝜾', "i"), ('𝜿', "k"), ('𝝀', "l"), ('𝝁', "m"), ('𝝂', "n"), ('𝝃', "x"),
+    ('𝝄', "o"), ('𝝅', "p"), ('𝝆', "r"), ('𝝇', "s"), ('𝝈', "s"), ('𝝉', "t"),
+    ('𝝊', "y"), ('𝝋', "f"), ('𝝌', "ch"), ('𝝍', "ps"), ('𝝎', "o"), ('𝝐', "e"),
+    ('𝝑', "th"), ('𝝒', "k"), ('𝝓', "f"), ('𝝔', "r"), ('𝝕', "p"), ('𝝖', "A"),
+    ('𝝗', "V"), ('𝝘', "G"), ('𝝙', "D"), ('𝝚', "E"), ('𝝛', "Z"), ('𝝜', "I"),
+    ('𝝝', "TH"), ('𝝞', "I"), ('𝝟', "K"), ('𝝠', "L"), ('𝝡', "M"), ('𝝢', "N"),
+    ('𝝣', "X"), ('𝝤', "O"), ('𝝥', "P"), ('𝝦', "R"), ('𝝧', "TH"), ('𝝨', "S"),
+    ('𝝩', "T"), ('𝝪', "Y"), ('𝝫', "F"), ('𝝬', "CH"), ('𝝭', "PS"), ('𝝮', "O"),
+    ('𝝰', "a"), ('𝝱', "v"), ('𝝲', "g"), ('𝝳', "d"), ('𝝴', "e"), ('𝝵', "z"),
+    ('𝝶', "i"), ('𝝷', "th"), ('𝝸', "i"), ('𝝹', "k"), ('𝝺', "l"), ('𝝻', "m"),
+    ('𝝼', "n"), ('𝝽', "x"), ('𝝾', "o"), ('𝝿', "p"), ('𝞀', "r"), ('𝞁', "s"),
+    ('𝞂', "s"), ('𝞃', "t"), ('𝞄', "y"), ('𝞅', "f"), ('𝞆', "ch"), ('𝞇', "ps"),
+    ('𝞈', "o"), ('𝞊', "e"), ('𝞋', "th"), ('𝞌', "k"), ('𝞍', "f"), ('𝞎', "r"),
+    ('𝞏', "p"), ('𝞐', "A"), ('𝞑', "V"), ('𝞒', "G"), ('𝞓', "D"), ('𝞔', "E"),
+    ('𝞕', "Z"), ('𝞖', "I"), ('𝞗', "TH"), ('𝞘', "I"), ('𝞙', "K"), ('𝞚', "L"),
+    ('𝞛', "M"), ('𝞜', "N"), ('𝞝', "X"), ('𝞞', "O"), ('𝞟', "P"), ('𝞠', "R"),
+    ('𝞡', "TH"), ('𝞢', "S"), ('𝞣', "T"), ('𝞤', "Y"), ('𝞥', "F"), ('𝞦', "CH"),
+    ('𝞧', "PS"), ('𝞨', "O"), ('𝞪', "a"), ('𝞫', "v"), ('𝞬', "g"), ('𝞭', "d"),
+    ('𝞮', "e"), ('𝞯', "z"), ('𝞰', "i"), ('𝞱', "th"), ('𝞲', "i"), ('𝞳', "k"),
+    ('𝞴', "l"), ('𝞵', "m"), ('𝞶', "n"), ('𝞷', "x"), ('𝞸', "o"), ('𝞹', "p"),
+    ('𝞺', "r"), ('𝞻', "s"), ('𝞼', "s"), ('𝞽', "t"), ('𝞾', "y"), ('𝞿', "f"),
+    ('𝟀', "ch"), ('𝟁', "ps"), ('𝟂', "o"), ('𝟄', "e"), ('𝟅', "th"), ('𝟆', "k"),
+    ('𝟇', "f"), ('𝟈', "r"), ('𝟉', "p"), ('𝟊', "W"), ('𝟋', "w"), ('𝟎', "0"),
+    ('𝟏', "1"), ('𝟐', "2"), ('𝟑', "3"), ('𝟒', "4"), ('𝟓', "5"), ('𝟔', "6"),
+    ('𝟕', "7"), ('𝟖', "8"), ('𝟗', "9"), ('𝟘', "0"), ('𝟙', "1"), ('𝟚', "2"),
+    ('𝟛', "3"), ('𝟜', "4"), ('𝟝', "5"), ('𝟞', "6"), ('𝟟', "7"), ('𝟠', "8"),
+    ('𝟡', "9"), ('𝟢', "0"), ('𝟣', "1"), ('𝟤', "2"), ('𝟥', "3"), ('𝟦', "4"),
+    ('𝟧', "5"), ('𝟨', "6"), ('𝟩', "7"), ('𝟪', "8"), ('𝟫', "9"), ('𝟬', "0"),
+    ('𝟭', "1"), ('𝟮', "2"), ('𝟯', "3"), ('𝟰', "4"), ('𝟱', "5"), ('𝟲', "6"),
+    ('𝟳', "7"), ('𝟴', "8"), ('𝟵', "9"), ('𝟶', "0"), ('𝟷', "1"), ('𝟸', "2"),
+    ('𝟹', "3"), ('𝟺', "4"), ('𝟻', "5"), ('𝟼', "6"), ('𝟽', "7"), ('𝟾', "8"),
+    ('𝟿', "9"), ('𝼀', "fng"), ('𝼁', "g"), ('𝼂', "g"), ('𝼃', "k"), ('𝼄', "l"),
+    ('𝼅', "l"), ('𝼆', "y"), ('𝼇', "ng"), ('𝼈', "r"), ('𝼉', "t"), ('𝼋', "sh"),
+    ('𝼌', "sh"), ('𝼍', "t"), ('𝼏', "c"), ('𝼐', "k"), ('𝼑', "l"), ('𝼒', "dzh"),
+    ('𝼓', "l"), ('𝼔', "ng"), ('𝼕', "r"), ('𝼖', "r"), ('𝼗', "tsh"), ('𝼘', "zh"),
+    ('𝼙', "dzh"), ('𝼚', "i"), ('𝼛', "o"), ('𝼜', "tsh"), ('𝼝', "c"), ('𝼞', "s"),
+    ('𝼥', "d"), ('𝼦', "l"), ('𝼧', "n"), ('𝼨', "r"), ('𝼩', "s"), ('𝼪', "t"),
+    ('𞅀', "0"), ('𞅁', "1"), ('𞅂', "2"), ('𞅃', "3"), ('𞅄', "4"), ('𞅅', "5"),
+    ('𞅆', "6"), ('𞅇', "7"), ('𞅈', "8"), ('𞅉', "9"), ('𞋰', "0"), ('𞋱', "1"),
+    ('𞋲', "2"), ('𞋳', "3"), ('𞋴', "4"), ('𞋵', "5"), ('𞋶', "6"), ('𞋷', "7"),
+    ('𞋸', "8"), ('𞋹', "9"), ('𞓰', "0"), ('𞓱', "1"), ('𞓲', "2"), ('𞓳', "3"),
+    ('𞓴', "4"), ('𞓵', "5"), ('𞓶', "6"), ('𞓷', "7"), ('𞓸', "8"), ('𞓹', "9"),
+    ('\u{1E5F1}', "0"), ('\u{1E5F2}', "1"), ('\u{1E5F3}', "2"), ('\u{1E5F4}', "3"), ('\u{1E5F5}', "4"), ('\u{1E5F6}', "5"),
+    ('\u{1E5F7}', "6"), ('\u{1E5F8}', "7"), ('\u{1E5F9}', "8"), ('\u{1E5FA}', "9"), ('𞥐', "0"), ('𞥑', "1"),
+    ('𞥒', "2"), ('𞥓', "3"), ('𞥔', "4"), ('𞥕', "5"), ('𞥖', "6"), ('𞥗', "7"),
+    ('𞥘', "8"), ('𞥙', "9"), ('🄀', "0"), ('🄁', "0"), ('🄂', "1"), ('🄃', "2"),
+    ('🄄', "3"), ('🄅', "4"), ('🄆', "5"), ('🄇', "6"), ('🄈', "7"), ('🄉', "8"),
+    ('🄊', "9"), ('🄋', "0"), ('🄌', "0"), ('🯰', "0"), ('🯱', "1"), ('🯲', "2"),
+    ('🯳', "3"), ('🯴', "4"), ('🯵', "5"), ('🯶', "6"), ('🯷', "7"), ('🯸', "8"),
+    ('🯹', "9"),
+];
+
+/// Combining marks, removed from the names without breaking the word
+pub const COMBINING_MARKS: &[RangeInclusive<char>] = &[
+    '\u{0300}'..='\u{036F}',
+    '\u{1AB0}'..='\u{1AFF}',
+    '\u{1DC0}'..='\u{1DFF}',
+];
+
+/// Bidirectional controls, like the right-to-left mark, always removed
+pub const BIDI_CONTROLS: &[RangeInclusive<char>] = &[
+    '\u{061C}'..='\u{061C}',
+    '\u{200E}'..='\u{200F}',
+    '\u{202A}'..='\u{202E}',
+    '\u{2066}'..='\u{2069}',
+];
+
+/// Space separators other than the ASCII space, like the no-break space, cleaned as a space
+pub const SPACE_SEPARATORS: &[RangeInclusive<char>] = &[
+    '\u{00A0}'..='\u{00A0}',
+    '\u{1680}'..='\u{1680}',
+    '\u{2000}'..='\u{200A}',
+    '\u{202F}'..='\u{202F}',
+    '\u{205F}'..='\u{205F}',
+    '\u{3000}'..='\u{3000}',
+];
+
+/// ASCII equivalent of a typographic punctuation, cleaned as it: `—` -> `-`, `…` -> `.`,
+/// and a space for the bullets and the space separators
+#[must_use]
+pub fn ascii_equivalent(one_char: char) -> Option<u8> {
+    match one_char {
+        '\u{2010}'..='\u{2015}'
+        | '\u{2212}'
+        | '\u{2E3A}'
+        | '\u{2E3B}'
+        | '\u{FE58}'
+        | '\u{FE63}'
+        | '\u{FF0D}' => Some(b'-'),
+        '\u{2024}'..='\u{2026}' => Some(b'.'),
+        '•' | '‣' | '⁃' | '∙' => Some(b' '),
+        _ if SPACE_SEPARATORS
+            .iter()
+            .any(|spaces| spaces.contains(&one_char)) =>
+        {
+            Some(b' ')
+        }
+        _ => None,
+    }
+}
+
+/// Latin letters without an ASCII spelling, removed from the names without breaking the word:
+/// the tone letters, the clicks, the glottal stops, the ayns, the modifier apostrophes and primes,
+/// the length marks and the medieval abbreviation marks
+pub const DROPPED_LATIN_LETTERS: &[RangeInclusive<char>] = &[
+    '\u{0184}'..='\u{0185}',
+    '\u{018D}'..='\u{018D}',
+    '\u{01A7}'..='\u{01A8}',
+    '\u{01AA}'..='\u{01AA}',
+    '\u{01B8}'..='\u{01B9}',
+    '\u{01BB}'..='\u{01BE}',
+    '\u{01C0}'..='\u{01C3}',
+    '\u{0241}'..='\u{0242}',
+    '\u{0285}'..='\u{0285}',
+    '\u{0294}'..='\u{0296}',
+    '\u{0298}'..='\u{0298}',
+    '\u{02A1}'..='\u{02A2}',
+    '\u{02AC}'..='\u{02AD}',
+    '\u{02B9}'..='\u{02C1}',
+    '\u{02D0}'..='\u{02D1}',
+    '\u{02E4}'..='\u{02E4}',
+    '\u{02EE}'..='\u{02EE}',
+    '\u{1D24}'..='\u{1D25}',
+    '\u{1D5C}'..='\u{1D5C}',
+    '\u{A724}'..='\u{A725}',
+    '\u{A72A}'..='\u{A72F}',
+    '\u{A788}'..='\u{A788}',
+    '\u{A78B}'..='\u{A78C}',
+    '\u{A78F}'..='\u{A78F}',
+    '\u{A7CE}'..='\u{A7CF}',
+    '\u{10781}'..='\u{10782}',
+    '\u{107B3}'..='\u{107B9}',
+    '\u{1DF0A}'..='\u{1DF0A}',
+    '\u{1DF0E}'..='\u{1DF0E}',
+];
+
+/// Built-in mapping of a character, an empty text for the combining marks, the bidirectional controls
+/// and the dropped Latin letters
+#[must_use]
+pub fn builtin_mapping(one_char: char) -> Option<&'static str> {
+    if COMBINING_MARKS
+        .iter()
+        .chain(BIDI_CONTROLS)
+        .chain(DROPPED_LATIN_LETTERS)
+        .any(|marks| marks.contains(&one_char))
+    {
+        return Some("");
+    }
+    BUILTIN_TABLE
+        .binary_search_by_key(&one_char, |(mapped_char, _)| *mapped_char)
+        .ok()
+        .map(|index| BUILTIN_TABLE[index].1)
+}
+
+/// Mappings of characters or sequences to ASCII, used before the built-in table
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TransliterationTable {
+    /// the mappings, the longest sequences first
+    entries: Vec<(String, String)>,
+}
+
+impl TransliterationTable {
+    /// Create an empty table
+    #[must_use]
+    pub const fn new() -> Self {
+        TransliterationTable {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Add the mapping of a character or a sequence, replacing its previous mapping
+    /// # Errors
+    /// Return an error if the sequence is empty, or if the replacement has
+    /// another character than ASCII letters, digits, `-`, `_` and `.`
+    pub fn insert(&mut self, sequence: &str, replacement: &str) -> Result<(), NotoxError> {
+        if sequence.is_empty() {
+            return Err(NotoxError::Table(format!(
+                "Empty sequence mapped to {replacement:?}"
+            )));
+        }
+        if let Some(invalid) = replacement.chars().find(|one_char| {
+            !one_char.is_ascii_alphanumeric() && !matches!(one_char, '-' | '_' | '.')
+        }) {
+            return Err(NotoxError::Table(format!(
+                "Invalid character {invalid:?} in the replacement of {sequence:?}"
+            )));
+        }
+        self.entries.retain(|(mapped, _)| mapped != sequence);
+        let position = self
+            .entries
+            .iter()
+            .position(|(mapped, _)| mapped.len() < sequence.len())
+            .unwrap_or(self.entries.len());
+        self.entries
+            .insert(position, (sequence.to_string(), replacement.to_string()));
+        Ok(())
+    }
+
+    /// Replacement of a character or a sequence, if any
+    #[must_use]
+    pub fn get(&self, sequence: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(mapped, _)| mapped == sequence)
+            .map(|(_, replacement)| replacement.as_str())
+    }
+
+    /// Check if the table has no mapping
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The mappings of the table, the longest sequences first
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .map(|(sequence, replacement)| (sequence.as_str(), replacement.as_str()))
+    }
+
+    /// Longest sequence of the table at the start of the bytes, with its length
+    pub(crate) fn longest_match(&self, bytes: &[u8]) -> Option<(usize, &str)> {
+        self.entries
+            .iter()
+            .find(|(sequence, _)| bytes.starts_with(sequence.as_bytes()))
+            .map(|(sequence, replacement)| (sequence.len(), replacement.as_str()))
+    }
+
+    /// Create a table from its mappings
+    #[cfg(feature = "serde")]
+    fn from_map(map: BTreeMap<String, String>) -> Result<Self, NotoxError> {
+        let mut table = TransliterationTable::new();
+        for (sequence, replacement) in map {
+            table.insert(&sequence, &replacement)?;
+        }
+        Ok(table)
+    }
+
+    /// Read a table from a JSON object, like `{"ß": "ss"}`
+    /// # Errors
+    /// Return an error if the JSON is not an object of strings, or if a mapping is invalid
+    #[cfg(feature = "serde")]
+    pub fn from_json(text: &str) -> Result<Self, NotoxError> {
+        let map = serde_json::from_str(text)
+            .map_err(|e| NotoxError::Table(format!("Cannot parse table: {e}")))?;
+        TransliterationTable::from_map(map)
+    }
+
+    /// Read a table from a TOML table, like `"ß" = "ss"`
+    /// # Errors
+    /// Return an error if the TOML is not a table of strings, or if a mapping is invalid
+    #[cfg(feature = "serde")]
+    pub fn from_toml(text: &str) -> Result<Self, NotoxError> {
+        let map = toml::from_str(text)
+            .map_err(|e| NotoxError::Table(format!("Cannot parse table: {e}")))?;
+        TransliterationTable::from_map(map)
+    }
+
+    /// Read a table from a file, in TOML if its extension is `.toml`, in JSON otherwise
+    /// # Errors
+    /// Return an error if the file cannot be read, or if the table is invalid
+    #[cfg(feature = "serde")]
+    pub fn from_file(path: &Path) -> Result<Self, NotoxError> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| NotoxError::Table(format!("Cannot read table: {e}")))?;
+        if path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("toml"))
+        {
+            TransliterationTable::from_toml(&text)
+        } else {
+            TransliterationTable::from_json(&text)
+        }
+    }
+}
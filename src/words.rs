@@ -0,0 +1,107 @@
+//! Symbols spelled out as words, like `&` -> `and` or `€` -> `EUR`
+
+/// Built-in words of the symbols, used by [`SymbolWords::default`]
+pub const SYMBOL_WORDS: &[(char, &str)] = &[
+    ('#', "num"),
+    ('$', "USD"),
+    ('%', "pct"),
+    ('&', "and"),
+    ('+', "plus"),
+    ('@', "at"),
+    ('¢', "ct"),
+    ('£', "GBP"),
+    ('¥', "JPY"),
+    ('©', "C"),
+    ('®', "R"),
+    ('°', "deg"),
+    ('‰', "permille"),
+    ('₩', "KRW"),
+    ('₹', "INR"),
+    ('₽', "RUB"),
+    ('€', "EUR"),
+    ('№', "No"),
+    ('™', "TM"),
+];
+
+/// Words replacing the symbols, glued to the rest of the name: `50%` -> `50pct`, `Tom & Jerry` -> `Tom_and_Jerry`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolWords {
+    /// the symbols and their word
+    entries: Vec<(char, String)>,
+}
+
+impl Default for SymbolWords {
+    fn default() -> Self {
+        SymbolWords {
+            entries: SYMBOL_WORDS
+                .iter()
+                .map(|(symbol, word)| (*symbol, (*word).to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl SymbolWords {
+    /// Create a list without any word
+    #[must_use]
+    pub const fn new() -> Self {
+        SymbolWords {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Set the word of a symbol, replacing its previous word, like `&` -> `und` in German
+    /// # Errors
+    /// Return the message of the error if the word has another character than ASCII letters, digits, `-`, `_` and `.`
+    pub fn insert(&mut self, symbol: char, word: &str) -> Result<(), String> {
+        if let Some(invalid) = word.chars().find(|one_char| {
+            !one_char.is_ascii_alphanumeric() && !matches!(one_char, '-' | '_' | '.')
+        }) {
+            return Err(format!(
+                "Invalid character {invalid:?} in the word of {symbol:?}"
+            ));
+        }
+        match self
+            .entries
+            .iter_mut()
+            .find(|(mapped, _)| *mapped == symbol)
+        {
+            Some((_, previous)) => *previous = word.to_string(),
+            None => self.entries.push((symbol, word.to_string())),
+        }
+        Ok(())
+    }
+
+    /// Set the word of a symbol from a `SYMBOL=WORD` text, like `&=und`
+    /// # Errors
+    /// Return the message of the error if the text is not one symbol, `=` and a valid word
+    pub fn insert_spec(&mut self, spec: &str) -> Result<(), String> {
+        let mut chars = spec.chars();
+        match (chars.next(), chars.next()) {
+            (Some(symbol), Some('=')) => self.insert(symbol, chars.as_str()),
+            _ => Err(format!("Invalid word {spec:?}, use SYMBOL=WORD")),
+        }
+    }
+
+    /// Word of a symbol, if any
+    #[must_use]
+    pub fn get(&self, symbol: char) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(mapped, _)| *mapped == symbol)
+            .map(|(_, word)| word.as_str())
+    }
+
+    /// Check if no symbol has a word
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The symbols and their word
+    pub fn iter(&self) -> impl Iterator<Item = (char, &str)> {
+        self.entries
+            .iter()
+            .map(|(symbol, word)| (*symbol, word.as_str()))
+    }
+}
@@ -0,0 +1,38 @@
+//! Helpers shared by the tests
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+
+use notox::{clean_name_with, NotoxArgs};
+
+/// Clean a name with the options, as notox renames it
+pub fn clean(name: &str, notox_args: &NotoxArgs) -> PathBuf {
+    PathBuf::from(clean_name_with(name, notox_args))
+}
+
+/// A file, a directory and a file in this directory, all with toxic names
+pub const NESTED_FILES: &[&str] = &["a b.txt", "c d/", "c d/e f.txt"];
+
+/// Create the directory `name` with the files in a unique temporary directory,
+/// the names ending with `/` are directories
+pub fn setup(name: &str, files: &[&str]) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("notox_{}_{name}", std::process::id()));
+    if root.exists() {
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+    let directory_path = root.join(name);
+    std::fs::create_dir_all(&directory_path).unwrap();
+    for one_file in files {
+        if one_file.ends_with('/') {
+            std::fs::create_dir_all(directory_path.join(one_file)).unwrap();
+        } else {
+            std::fs::File::create(directory_path.join(one_file)).unwrap();
+        }
+    }
+    directory_path
+}
+
+/// Remove the temporary directory created by [`setup`], with the files written next to `directory_path`
+pub fn cleanup(directory_path: &Path) {
+    std::fs::remove_dir_all(directory_path.parent().unwrap()).unwrap();
+}
@@ -22,7 +22,8 @@ mod tests {
             options,
             notox::NotoxArgs {
                 dry_run: true,
-                output: NotoxOutput::Default
+                output: NotoxOutput::Default,
+                ..notox::NotoxArgs::default()
             }
         );
         assert_eq!(res_path.len(), number + 1);
@@ -131,7 +132,8 @@ mod tests {
                 output: NotoxOutput::JsonOutput {
                     json: JsonOutput::JsonDefault,
                     pretty: false
-                }
+                },
+                ..notox::NotoxArgs::default()
             }
         );
         assert_eq!(res_path.len(), 5);
@@ -151,7 +153,8 @@ mod tests {
             options,
             notox::NotoxArgs {
                 dry_run: false,
-                output: NotoxOutput::Default
+                output: NotoxOutput::Default,
+                ..notox::NotoxArgs::default()
             }
         );
         assert_eq!(res_path.len(), 5);
@@ -0,0 +1,146 @@
+mod common;
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod tests {
+    use std::{
+        collections::HashSet,
+        path::{Path, PathBuf},
+    };
+
+    use notox::{IoError, Notox, NotoxArgs, NotoxError, NotoxMode, NotoxOutput, PathChange};
+
+    use crate::common;
+
+    fn apply(json_path: &Path, failed_only: bool) -> Vec<PathChange> {
+        let notox_args = NotoxArgs {
+            dry_run: false,
+            output: NotoxOutput::Quiet,
+            mode: NotoxMode::Apply {
+                json_path: json_path.to_path_buf(),
+                failed_only,
+            },
+            ..NotoxArgs::default()
+        };
+        Notox::new(notox_args).run(&HashSet::new())
+    }
+
+    #[test]
+    fn test_apply_edited_plan() {
+        let directory_path = common::setup("test_apply_edited", common::NESTED_FILES);
+        let json_path = directory_path.with_extension("json");
+        let notox_args = NotoxArgs {
+            dry_run: true,
+            output: NotoxOutput::Quiet,
+            ..NotoxArgs::default()
+        };
+        let dry_res = Notox::new(notox_args).run(&HashSet::from([directory_path.clone()]));
+        // the reviewer prefers another name
+        let json_string = serde_json::to_string_pretty(&dry_res)
+            .unwrap()
+            .replace("a_b.txt", "a-b.txt");
+        std::fs::write(&json_path, json_string).unwrap();
+
+        let res = apply(&json_path, false);
+        assert_eq!(res.len(), 3);
+        assert!(res.contains(&PathChange::Changed {
+            path: directory_path.join("c_d").join("e f.txt"),
+            modified: directory_path.join("c_d").join("e_f.txt"),
+            fallback: false,
+        }));
+        assert!(directory_path.join("a-b.txt").exists());
+        assert!(directory_path.join("c_d").join("e_f.txt").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_apply_checks() {
+        let directory_path = common::setup("test_apply_checks", common::NESTED_FILES);
+        let json_path = directory_path.with_extension("json");
+        std::fs::File::create(directory_path.join("taken.txt")).unwrap();
+        let changes = vec![
+            PathChange::WouldRename {
+                path: directory_path.join("missing file.txt"),
+                modified: directory_path.join("missing_file.txt"),
+                fallback: false,
+            },
+            PathChange::WouldRename {
+                path: directory_path.join("a b.txt"),
+                modified: directory_path.join("taken.txt"),
+                fallback: false,
+            },
+        ];
+        std::fs::write(&json_path, serde_json::to_string(&changes).unwrap()).unwrap();
+
+        let res = apply(&json_path, false);
+        assert!(res.contains(&PathChange::Error {
+            path: directory_path.join("missing file.txt"),
+            error: NotoxError::NotFound(IoError::new(
+                std::io::ErrorKind::NotFound,
+                "source does not exist"
+            )),
+        }));
+        assert!(res.contains(&PathChange::Conflict {
+            path: directory_path.join("a b.txt"),
+            modified: directory_path.join("taken.txt"),
+            fallback: false,
+        }));
+        assert!(directory_path.join("a b.txt").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_apply_failed_only() {
+        let directory_path = common::setup("test_apply_failed", common::NESTED_FILES);
+        let json_path = directory_path.with_extension("json");
+        let changes = vec![
+            PathChange::ErrorRename {
+                path: directory_path.join("a b.txt"),
+                modified: directory_path.join("a_b.txt"),
+                error: NotoxError::from_io(&std::io::Error::from_raw_os_error(13)),
+                fallback: false,
+            },
+            PathChange::WouldRename {
+                path: directory_path.join("c d"),
+                modified: directory_path.join("c_d"),
+                fallback: false,
+            },
+        ];
+        std::fs::write(&json_path, serde_json::to_string(&changes).unwrap()).unwrap();
+
+        let res = apply(&json_path, true);
+        assert_eq!(
+            res,
+            vec![PathChange::Changed {
+                path: directory_path.join("a b.txt"),
+                modified: directory_path.join("a_b.txt"),
+                fallback: false,
+            }]
+        );
+        assert!(directory_path.join("c d").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_parse_args_apply() {
+        let vec_args = [
+            "notox".to_string(),
+            "--apply".to_string(),
+            "plan.json".to_string(),
+            "--failed-only".to_string(),
+        ];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert_eq!(
+            options.mode,
+            NotoxMode::Apply {
+                json_path: PathBuf::from("plan.json"),
+                failed_only: true,
+            }
+        );
+
+        let vec_args = ["notox".to_string(), "--failed-only".to_string()];
+        assert_eq!(notox::parse_args(&vec_args), Err(2));
+        let vec_args = ["notox".to_string(), "--apply".to_string()];
+        assert_eq!(notox::parse_args(&vec_args), Err(2));
+    }
+}
@@ -12,6 +12,7 @@ mod tests {
                 NotoxArgs {
                     dry_run: true,
                     output: NotoxOutput::Default,
+                    ..NotoxArgs::default()
                 },
             ),
             (
@@ -23,6 +24,7 @@ mod tests {
                 NotoxArgs {
                     dry_run: false,
                     output: NotoxOutput::Default,
+                    ..NotoxArgs::default()
                 },
             ),
             #[cfg(feature = "serde")]
@@ -39,6 +41,7 @@ mod tests {
                         json: JsonOutput::JsonDefault,
                         pretty: false,
                     },
+                    ..NotoxArgs::default()
                 },
             ),
             #[cfg(feature = "serde")]
@@ -55,6 +58,7 @@ mod tests {
                         json: JsonOutput::JsonDefault,
                         pretty: false,
                     },
+                    ..NotoxArgs::default()
                 },
             ),
             #[cfg(feature = "serde")]
@@ -71,6 +75,7 @@ mod tests {
                         json: JsonOutput::JsonOnlyError,
                         pretty: false,
                     },
+                    ..NotoxArgs::default()
                 },
             ),
             #[cfg(feature = "serde")]
@@ -87,6 +92,7 @@ mod tests {
                         json: JsonOutput::JsonOnlyError,
                         pretty: false,
                     },
+                    ..NotoxArgs::default()
                 },
             ),
             #[cfg(feature = "serde")]
@@ -103,6 +109,7 @@ mod tests {
                         json: JsonOutput::JsonDefault,
                         pretty: true,
                     },
+                    ..NotoxArgs::default()
                 },
             ),
             #[cfg(feature = "serde")]
@@ -119,6 +126,7 @@ mod tests {
                         json: JsonOutput::JsonDefault,
                         pretty: true,
                     },
+                    ..NotoxArgs::default()
                 },
             ),
             (
@@ -126,6 +134,7 @@ mod tests {
                 NotoxArgs {
                     dry_run: true,
                     output: NotoxOutput::Default,
+                    ..NotoxArgs::default()
                 },
             ),
             (
@@ -133,6 +142,7 @@ mod tests {
                 NotoxArgs {
                     dry_run: true,
                     output: NotoxOutput::Default,
+                    ..NotoxArgs::default()
                 },
             ),
             (
@@ -140,6 +150,7 @@ mod tests {
                 NotoxArgs {
                     dry_run: true,
                     output: NotoxOutput::Quiet,
+                    ..NotoxArgs::default()
                 },
             ),
             (
@@ -147,6 +158,7 @@ mod tests {
                 NotoxArgs {
                     dry_run: true,
                     output: NotoxOutput::Quiet,
+                    ..NotoxArgs::default()
                 },
             ),
         ];
@@ -176,11 +188,30 @@ mod tests {
             NotoxArgs {
                 dry_run: true,
                 output: NotoxOutput::Default,
+                ..NotoxArgs::default()
             }
         );
         assert_eq!(vect.len(), 1);
     }
 
+    #[test]
+    fn test_display_args() {
+        let vec_args = [
+            "notox".to_string(),
+            "--romanize".to_string(),
+            "greek,kana".to_string(),
+            "--case".to_string(),
+            "snake".to_string(),
+            "--lower-ext".to_string(),
+        ];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        let displayed = options.to_string();
+        assert!(displayed.starts_with("NotoxArgs { dry_run: true, conflict: skip, fallback: hash,"));
+        assert!(displayed.contains("lower_ext: true, ext_synonyms: false,"));
+        assert!(displayed.contains("lang: none, romanize: greek,kana,"));
+        assert!(displayed.contains("words: none, case: snake, mode: Clean"));
+    }
+
     #[test]
     fn test_parse_args_star() {
         let vec_args = ["notox".to_string(), "*".to_string()];
@@ -191,6 +222,7 @@ mod tests {
             NotoxArgs {
                 dry_run: true,
                 output: NotoxOutput::Default,
+                ..NotoxArgs::default()
             }
         );
         let number = std::fs::read_dir(".")
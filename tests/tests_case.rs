@@ -0,0 +1,139 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use notox::{CaseMode, ExtensionOptions, Language, NotoxArgs, CASE_MODES};
+
+    use crate::common;
+
+    fn clean_with(name: &str, notox_args: NotoxArgs) -> PathBuf {
+        common::clean(name, &notox_args)
+    }
+
+    fn clean(name: &str, case: CaseMode) -> PathBuf {
+        let notox_args = NotoxArgs {
+            case: Some(case),
+            ..NotoxArgs::default()
+        };
+        clean_with(name, notox_args)
+    }
+
+    #[test]
+    fn test_case_modes() {
+        let name = "My HTMLParser-final v2.TXT";
+        assert_eq!(
+            clean(name, CaseMode::Lower),
+            PathBuf::from("my_htmlparser-final_v2.TXT")
+        );
+        assert_eq!(
+            clean(name, CaseMode::Upper),
+            PathBuf::from("MY_HTMLPARSER-FINAL_V2.TXT")
+        );
+        assert_eq!(
+            clean(name, CaseMode::Snake),
+            PathBuf::from("my_html_parser_final_v2.TXT")
+        );
+        assert_eq!(
+            clean(name, CaseMode::Kebab),
+            PathBuf::from("my-html-parser-final-v2.TXT")
+        );
+        assert_eq!(
+            clean(name, CaseMode::Camel),
+            PathBuf::from("myHtmlParserFinalV2.TXT")
+        );
+        assert_eq!(
+            clean(name, CaseMode::Pascal),
+            PathBuf::from("MyHtmlParserFinalV2.TXT")
+        );
+        assert_eq!(
+            clean(name, CaseMode::Title),
+            PathBuf::from("My_Html_Parser_Final_V2.TXT")
+        );
+    }
+
+    #[test]
+    fn test_case_transliterated_first() {
+        assert_eq!(
+            clean("İstanbul Ünite.txt", CaseMode::Snake),
+            PathBuf::from("istanbul_unite.txt")
+        );
+        let notox_args = NotoxArgs {
+            case: Some(CaseMode::Upper),
+            language: Some(Language::German),
+            ..NotoxArgs::default()
+        };
+        assert_eq!(
+            clean_with("Straße.txt", notox_args),
+            PathBuf::from("STRASSE.txt")
+        );
+    }
+
+    #[test]
+    fn test_case_extension() {
+        // the extensions are cased by --lower-ext, not by the case mode
+        let notox_args = NotoxArgs {
+            case: Some(CaseMode::Snake),
+            extension: ExtensionOptions {
+                lowercase: true,
+                synonyms: false,
+            },
+            ..NotoxArgs::default()
+        };
+        assert_eq!(
+            clean_with("HolidayPhoto.JPG", notox_args),
+            PathBuf::from("holiday_photo.jpg")
+        );
+        assert_eq!(
+            clean("Archive.TAR.GZ", CaseMode::Upper),
+            PathBuf::from("ARCHIVE.TAR.GZ")
+        );
+        assert_eq!(clean("README", CaseMode::Kebab), PathBuf::from("readme"));
+    }
+
+    #[test]
+    fn test_case_separators_kept() {
+        assert_eq!(CaseMode::Pascal.apply("__init__"), "__Init__");
+        assert_eq!(CaseMode::Camel.apply("v1.2_Final-"), "v1.2Final-");
+        assert_eq!(
+            clean(".HiddenFile", CaseMode::Snake),
+            PathBuf::from(".hidden_file")
+        );
+        assert_eq!(
+            clean("Chapter 1… Final.docx", CaseMode::Kebab),
+            PathBuf::from("chapter-1.final.docx")
+        );
+        assert_eq!(clean("my_file", CaseMode::Snake), PathBuf::from("my_file"));
+    }
+
+    #[test]
+    fn test_case_names() {
+        for case in CASE_MODES {
+            assert_eq!(CaseMode::from_name(case.name()), Some(*case));
+            assert_eq!(case.to_string(), case.name());
+        }
+        assert_eq!(CaseMode::from_name("Snake"), Some(CaseMode::Snake));
+        assert_eq!(CaseMode::from_name("screaming"), None);
+    }
+
+    #[test]
+    fn test_parse_args_case() {
+        let vec_args = [
+            "notox".to_string(),
+            "--case".to_string(),
+            "kebab".to_string(),
+        ];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert_eq!(options.case, Some(CaseMode::Kebab));
+        let vec_args = [
+            "notox".to_string(),
+            "--case".to_string(),
+            "wrong".to_string(),
+        ];
+        assert_eq!(notox::parse_args(&vec_args).err(), Some(2));
+        let vec_args = ["notox".to_string()];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert_eq!(options.case, None);
+    }
+}
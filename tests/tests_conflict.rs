@@ -0,0 +1,153 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashSet,
+        path::{Path, PathBuf},
+    };
+
+    use notox::{ConflictStrategy, Notox, NotoxArgs, NotoxOutput, PathChange};
+
+    use crate::common;
+
+    fn run(directory_path: &Path, dry_run: bool, conflict: ConflictStrategy) -> Vec<PathChange> {
+        let notox_args = NotoxArgs {
+            dry_run,
+            output: NotoxOutput::Quiet,
+            conflict,
+            ..NotoxArgs::default()
+        };
+        Notox::new(notox_args).run(&HashSet::from([directory_path.to_path_buf()]))
+    }
+
+    #[test]
+    fn test_conflict_skip() {
+        let directory_path = common::setup("test_conflict_skip", &["a b.txt", "a_b.txt"]);
+        let res = run(&directory_path, false, ConflictStrategy::Skip);
+        assert_eq!(res.len(), 3);
+        assert!(res.contains(&PathChange::Conflict {
+            path: directory_path.join("a b.txt"),
+            modified: directory_path.join("a_b.txt"),
+            fallback: false,
+        }));
+        assert!(directory_path.join("a b.txt").exists());
+        assert!(directory_path.join("a_b.txt").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_conflict_suffix() {
+        let directory_path =
+            common::setup("test_conflict_suffix", &["a b.txt", "a_b.txt", "a_b-1.txt"]);
+        let res = run(&directory_path, false, ConflictStrategy::Suffix);
+        assert!(res.contains(&PathChange::Changed {
+            path: directory_path.join("a b.txt"),
+            modified: directory_path.join("a_b-2.txt"),
+            fallback: false,
+        }));
+        assert!(!directory_path.join("a b.txt").exists());
+        assert!(directory_path.join("a_b-2.txt").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_conflict_suffix_multi_part() {
+        let directory_path =
+            common::setup("test_conflict_multi_part", &["a b.tar.gz", "a_b.tar.gz"]);
+        let res = run(&directory_path, false, ConflictStrategy::Suffix);
+        assert!(res.contains(&PathChange::Changed {
+            path: directory_path.join("a b.tar.gz"),
+            modified: directory_path.join("a_b-1.tar.gz"),
+            fallback: false,
+        }));
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_conflict_suffix_siblings() {
+        // none of the targets exists yet, both files want the same name
+        let directory_path = common::setup("test_conflict_siblings", &["a b.txt", "a?b.txt"]);
+        let res = run(&directory_path, false, ConflictStrategy::Suffix);
+        let modified: HashSet<PathBuf> = res
+            .into_iter()
+            .filter_map(|one_change| match one_change {
+                PathChange::Changed { modified, .. } => Some(modified),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            modified,
+            HashSet::from([
+                directory_path.join("a_b.txt"),
+                directory_path.join("a_b-1.txt")
+            ])
+        );
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_conflict_dry_run() {
+        let directory_path = common::setup("test_conflict_dry", &["a b.txt", "a_b.txt"]);
+        let res = run(&directory_path, true, ConflictStrategy::Skip);
+        assert!(res.contains(&PathChange::Conflict {
+            path: directory_path.join("a b.txt"),
+            modified: directory_path.join("a_b.txt"),
+            fallback: false,
+        }));
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_conflict_abort() {
+        let directory_path = common::setup("test_conflict_abort", &["a b.txt", "a_b.txt"]);
+        let res = run(&directory_path, false, ConflictStrategy::Abort);
+        assert!(res.contains(&PathChange::Conflict {
+            path: directory_path.join("a b.txt"),
+            modified: directory_path.join("a_b.txt"),
+            fallback: false,
+        }));
+        assert!(directory_path.join("a b.txt").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_parse_args_conflict() {
+        let args = [
+            ("skip", ConflictStrategy::Skip),
+            ("suffix", ConflictStrategy::Suffix),
+            ("abort", ConflictStrategy::Abort),
+        ];
+        for (name, strategy) in args {
+            let vec_args = [
+                "notox".to_string(),
+                "README.md".to_string(),
+                "--conflict".to_string(),
+                name.to_string(),
+            ];
+            let (options, _) = notox::parse_args(&vec_args).unwrap();
+            assert_eq!(options.conflict, strategy);
+        }
+        let vec_args = ["notox".to_string(), "-c".to_string(), "rename".to_string()];
+        assert_eq!(notox::parse_args(&vec_args), Err(2));
+        let vec_args = ["notox".to_string(), "-c".to_string()];
+        assert_eq!(notox::parse_args(&vec_args), Err(2));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_conflict_json() {
+        let change = PathChange::Conflict {
+            path: PathBuf::from("a b.txt"),
+            modified: PathBuf::from("a_b.txt"),
+            fallback: false,
+        };
+        let json = serde_json::to_string(&change).unwrap();
+        assert_eq!(
+            json,
+            r#"{"path":"a b.txt","modified":"a_b.txt","error":{"code":"target_exists","message":"target already exists","errno":null}}"#
+        );
+        let deserialized: PathChange = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, change);
+    }
+}
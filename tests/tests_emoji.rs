@@ -0,0 +1,113 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use notox::{NotoxArgs, PathChange};
+
+    use crate::common;
+
+    fn clean(name: &str, emoji: bool) -> PathBuf {
+        let notox_args = NotoxArgs {
+            emoji,
+            ..NotoxArgs::default()
+        };
+        common::clean(name, &notox_args)
+    }
+
+    #[test]
+    fn test_emoji() {
+        assert_eq!(
+            clean("🎉 party 🎂.jpg", true),
+            PathBuf::from("party_popper_party_birthday_cake.jpg")
+        );
+        assert_eq!(clean("a👍b", true), PathBuf::from("a_thumbs_up_b"));
+        assert_eq!(clean("🫨", true), PathBuf::from("shaking_face"));
+        // without mapping, the symbols are emoji even in the text style
+        assert_eq!(clean("©2024", true), PathBuf::from("copyright_2024"));
+    }
+
+    #[test]
+    fn test_emoji_variation_selectors() {
+        assert_eq!(clean("❤️.txt", true), PathBuf::from("red_heart.txt"));
+        assert_eq!(clean("❤.txt", true), PathBuf::from("red_heart.txt"));
+        assert_eq!(clean("a☃︎b", true), PathBuf::from("a_snowman_b"));
+    }
+
+    #[test]
+    fn test_emoji_skin_tones() {
+        assert_eq!(
+            clean("👍🏽ok", true),
+            PathBuf::from("thumbs_up_medium_skin_tone_ok")
+        );
+        assert_eq!(clean("🏿", true), PathBuf::from("dark_skin_tone"));
+    }
+
+    #[test]
+    fn test_emoji_zwj_sequences() {
+        assert_eq!(
+            clean("👨‍👩‍👧‍👦.png", true),
+            PathBuf::from("family_man_woman_girl_boy.png")
+        );
+        assert_eq!(
+            clean("👩🏽‍💻", true),
+            PathBuf::from("woman_technologist_medium_skin_tone")
+        );
+        assert_eq!(clean("🏃‍♀️", true), PathBuf::from("woman_running"));
+        assert_eq!(clean("🏳️‍🌈", true), PathBuf::from("rainbow_flag"));
+        assert_eq!(
+            clean("👩‍❤️‍👨", true),
+            PathBuf::from("couple_with_heart_woman_man")
+        );
+        assert_eq!(
+            clean("🧑🏻‍🤝‍🧑🏿", true),
+            PathBuf::from("people_holding_hands_light_skin_tone_dark_skin_tone")
+        );
+        assert_eq!(clean("👨‍🦰", true), PathBuf::from("man_red_hair"));
+        // a joiner without an emoji after it ends the sequence
+        assert_eq!(clean("🎉\u{200D}x", true), PathBuf::from("party_popper_x"));
+    }
+
+    #[test]
+    fn test_emoji_flags_and_keycaps() {
+        assert_eq!(clean("🇫🇷_paris", true), PathBuf::from("flag_fr_paris"));
+        assert_eq!(clean("🇯🇵🇫🇷", true), PathBuf::from("flag_jp_flag_fr"));
+        assert_eq!(clean("x🇫", true), PathBuf::from("x_f"));
+        assert_eq!(
+            clean(
+                "🏴\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}",
+                true
+            ),
+            PathBuf::from("flag_scotland")
+        );
+        assert_eq!(clean("1️⃣ one", true), PathBuf::from("keycap_1_one"));
+        assert_eq!(clean("#⃣", true), PathBuf::from("keycap_number_sign"));
+        assert_eq!(clean("12.txt", true), PathBuf::from("12.txt"));
+    }
+
+    #[test]
+    fn test_emoji_disabled() {
+        assert_eq!(clean("a🎉b", false), PathBuf::from("a_b"));
+    }
+
+    #[test]
+    fn test_emoji_warning() {
+        let change = PathChange::WouldRename {
+            path: PathBuf::from("🎉.txt"),
+            modified: PathBuf::from("party_popper.txt"),
+            fallback: false,
+        };
+        assert_eq!(change.warning(), None);
+    }
+
+    #[test]
+    fn test_parse_args_emoji() {
+        let vec_args = ["notox".to_string(), "--emoji".to_string()];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert!(options.emoji);
+        let vec_args = ["notox".to_string()];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert!(!options.emoji);
+    }
+}
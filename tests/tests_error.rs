@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, io, path::PathBuf};
+
+    use notox::{Notox, NotoxArgs, NotoxError, NotoxOutput, PathChange};
+
+    #[test]
+    fn test_error_from_io() {
+        let error = NotoxError::from_io(&io::Error::from(io::ErrorKind::PermissionDenied));
+        assert_eq!(error.code(), "permission_denied");
+        assert_eq!(error.kind(), Some(io::ErrorKind::PermissionDenied));
+
+        let error = NotoxError::from_io(&io::Error::from(io::ErrorKind::CrossesDevices));
+        assert_eq!(error.code(), "cross_device");
+
+        let error = NotoxError::from_io(&io::Error::other("disk full"));
+        assert_eq!(error.code(), "io");
+        assert_eq!(error.to_string(), "disk full");
+        assert_eq!(error.raw_os_error(), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_error_rename_not_found() {
+        let options = NotoxArgs {
+            dry_run: false,
+            output: NotoxOutput::Quiet,
+            ..NotoxArgs::default()
+        };
+        let res = Notox::new(options).run(&HashSet::from([PathBuf::from("not found?.txt")]));
+        match &res[0] {
+            PathChange::ErrorRename { error, .. } => {
+                assert!(matches!(error, NotoxError::NotFound(_)));
+                assert_eq!(error.kind(), Some(io::ErrorKind::NotFound));
+                assert_eq!(error.raw_os_error(), Some(2));
+            }
+            _ => panic!("Expected ErrorRename"),
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_error_read_dir() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let directory_path = PathBuf::from("test_error_read_dir");
+        std::fs::create_dir(&directory_path).unwrap();
+        std::fs::set_permissions(&directory_path, std::fs::Permissions::from_mode(0o000)).unwrap();
+        let can_read = std::fs::read_dir(&directory_path).is_ok();
+        let res = Notox::new(NotoxArgs::default()).run(&HashSet::from([directory_path.clone()]));
+        std::fs::set_permissions(&directory_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        std::fs::remove_dir(&directory_path).unwrap();
+        // root can read any directory
+        if !can_read {
+            assert!(res.iter().any(|one_change| matches!(
+                one_change,
+                PathChange::Error {
+                    error: NotoxError::ReadDir(_),
+                    ..
+                }
+            )));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_error_json() {
+        let change = PathChange::ErrorRename {
+            path: PathBuf::from("a b.txt"),
+            modified: PathBuf::from("a_b.txt"),
+            error: NotoxError::from_io(&io::Error::from_raw_os_error(2)),
+            fallback: false,
+        };
+        let json = serde_json::to_string(&change).unwrap();
+        assert_eq!(
+            json,
+            r#"{"path":"a b.txt","modified":"a_b.txt","error":{"code":"not_found","message":"No such file or directory (os error 2)","errno":2}}"#
+        );
+        let deserialized: PathChange = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, change);
+
+        // outputs of the previous versions only have a message
+        let deserialized: PathChange =
+            serde_json::from_str(r#"{"path":"a","modified":null,"error":"some error"}"#).unwrap();
+        match deserialized {
+            PathChange::Error { error, .. } => {
+                assert_eq!(error.code(), "io");
+                assert_eq!(error.to_string(), "some error");
+            }
+            _ => panic!("Expected Error"),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::OsStr, path::PathBuf};
+
+    use notox::{trace_name, ExtensionOptions, NotoxArgs, TraceAction};
+
+    use crate::common;
+
+    fn clean(name: &str, extension: ExtensionOptions) -> PathBuf {
+        let notox_args = NotoxArgs {
+            extension,
+            ..NotoxArgs::default()
+        };
+        common::clean(name, &notox_args)
+    }
+
+    #[test]
+    fn test_extension_default() {
+        // without options, the extensions are cleaned like the rest of the name
+        let names = [
+            "photo .JPG",
+            "archive.tar.gz",
+            "archive .tar.gz",
+            ".env.local",
+            "a..txt",
+            "a.-b",
+            "a_.txt",
+            "my file.v2 final",
+            "Ça_-_va.txt",
+            "é.txt",
+            "a日.txt",
+        ];
+        for name in names {
+            let (cleaned, steps) = trace_name(OsStr::new(name), &NotoxArgs::default());
+            assert!(!steps
+                .iter()
+                .any(|one_step| matches!(one_step.action, TraceAction::ExtensionCleaned(_))));
+            assert_eq!(
+                clean(name, ExtensionOptions::default()),
+                PathBuf::from(cleaned)
+            );
+        }
+    }
+
+    #[test]
+    fn test_extension_lowercase() {
+        let options = ExtensionOptions {
+            lowercase: true,
+            synonyms: false,
+        };
+        assert_eq!(clean("Photo .JPG", options), PathBuf::from("Photo.jpg"));
+        assert_eq!(clean("A.TAR.GZ", options), PathBuf::from("A.tar.gz"));
+        assert_eq!(clean(".ENV", options), PathBuf::from(".ENV"));
+        assert_eq!(clean("a.JPEG", options), PathBuf::from("a.jpeg"));
+    }
+
+    #[test]
+    fn test_extension_synonyms() {
+        let options = ExtensionOptions {
+            lowercase: false,
+            synonyms: true,
+        };
+        assert_eq!(clean("a.jpeg", options), PathBuf::from("a.jpg"));
+        assert_eq!(clean("a.JPEG", options), PathBuf::from("a.JPG"));
+        assert_eq!(clean("a.tiff", options), PathBuf::from("a.tif"));
+        assert_eq!(clean("index.htm", options), PathBuf::from("index.html"));
+        assert_eq!(clean("jpeg", options), PathBuf::from("jpeg"));
+    }
+
+    #[test]
+    fn test_extension_fallback() {
+        let options = ExtensionOptions {
+            lowercase: true,
+            synonyms: true,
+        };
+        let cleaned = clean("日本.TAR.GZ", options);
+        assert!(cleaned.to_str().unwrap().ends_with(".tar.gz"));
+        let cleaned = clean("日本.JPEG", options);
+        assert!(cleaned.to_str().unwrap().ends_with(".jpg"));
+    }
+
+    #[test]
+    fn test_extension_cleaned_to_nothing() {
+        // a part after the last dot that is not an extension is cleaned with the stem,
+        // the dot goes away with it
+        let options = ExtensionOptions::default();
+        assert_eq!(clean("x.日本", options), PathBuf::from("x"));
+        assert_eq!(clean("x.日本.txt", options), PathBuf::from("x.txt"));
+        assert_eq!(clean("x.", options), PathBuf::from("x."));
+        let cleaned = clean("日本.日本", options);
+        assert!(!cleaned.to_str().unwrap().contains('.'));
+        let (cleaned, steps) = trace_name(OsStr::new("x.日本"), &NotoxArgs::default());
+        assert_eq!(cleaned, "x");
+        assert!(steps
+            .iter()
+            .any(|one_step| one_step.action == TraceAction::TrimmedDot));
+    }
+
+    #[test]
+    fn test_parse_args_extension() {
+        let vec_args = [
+            "notox".to_string(),
+            "README.md".to_string(),
+            "--lower-ext".to_string(),
+            "--ext-synonyms".to_string(),
+        ];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert_eq!(
+            options.extension,
+            ExtensionOptions {
+                lowercase: true,
+                synonyms: true,
+            }
+        );
+    }
+}
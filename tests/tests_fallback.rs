@@ -0,0 +1,195 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashSet,
+        ffi::OsStr,
+        path::{Path, PathBuf},
+    };
+
+    use notox::{
+        needs_fallback, FallbackName, Notox, NotoxArgs, NotoxMode, NotoxOutput, PathChange,
+        TransliterationTable,
+    };
+
+    use crate::common;
+
+    fn run(directory_path: &Path, fallback: FallbackName) -> Vec<PathChange> {
+        let notox_args = NotoxArgs {
+            dry_run: false,
+            output: NotoxOutput::Quiet,
+            fallback,
+            ..NotoxArgs::default()
+        };
+        Notox::new(notox_args).run(&HashSet::from([directory_path.to_path_buf()]))
+    }
+
+    #[test]
+    fn test_fallback_hash() {
+        let directory_path =
+            common::setup("test_fallback_hash", &["日本語.txt", "Москва", "__.tar.gz"]);
+        let res = run(&directory_path, FallbackName::Hash);
+        assert_eq!(res.len(), 4);
+        assert!(directory_path.join("2ffd6801.txt").exists());
+        assert!(directory_path.join("feb471b1").exists());
+        assert!(res.contains(&PathChange::Changed {
+            path: directory_path.join("__.tar.gz"),
+            modified: directory_path.join("0c81a6ed.tar.gz"),
+            fallback: true,
+        }));
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_fallback_code_points() {
+        let directory_path = common::setup("test_fallback_code_points", &["日本語.txt"]);
+        run(&directory_path, FallbackName::CodePoints);
+        assert!(directory_path.join("u65e5_u672c_u8a9e.txt").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_fallback_placeholder() {
+        let directory_path = common::setup("test_fallback_placeholder", &["日本語.txt", ".日本"]);
+        run(
+            &directory_path,
+            FallbackName::Placeholder("sans nom".to_string()),
+        );
+        assert!(directory_path.join("sans_nom.txt").exists());
+        assert!(directory_path.join(".sans_nom").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_fallback_not_needed() {
+        let options = NotoxArgs::default();
+        assert!(!needs_fallback(OsStr::new("-"), OsStr::new("-"), &options));
+        assert!(!needs_fallback(
+            OsStr::new("日本 x.txt"),
+            OsStr::new("x.txt"),
+            &options
+        ));
+        assert!(needs_fallback(
+            OsStr::new("日本.txt"),
+            OsStr::new(".txt"),
+            &options
+        ));
+        assert!(needs_fallback(OsStr::new("日本"), OsStr::new(""), &options));
+        // with the options of the caller
+        let mut table = TransliterationTable::new();
+        table.insert("日本", "nihon").unwrap();
+        let options = NotoxArgs {
+            table,
+            ..NotoxArgs::default()
+        };
+        assert!(!needs_fallback(
+            OsStr::new("日本.txt"),
+            OsStr::new("nihon.txt"),
+            &options
+        ));
+    }
+
+    #[test]
+    fn test_fallback_only_dots() {
+        for name in [".", "..", "..."] {
+            assert_eq!(
+                common::clean(name, &NotoxArgs::default()),
+                PathBuf::from(name)
+            );
+        }
+        let directory_path = common::setup("test_fallback_only_dots", &["...", "日本"]);
+        let res = run(&directory_path, FallbackName::Hash);
+        assert!(res.contains(&PathChange::Unchanged {
+            path: directory_path.join("..."),
+        }));
+        assert!(directory_path.join("...").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_fallback_warning() {
+        let change = PathChange::WouldRename {
+            path: PathBuf::from("日本語.txt"),
+            modified: PathBuf::from("2ffd6801.txt"),
+            fallback: true,
+        };
+        assert!(change.warning().is_some());
+        let change = PathChange::WouldRename {
+            path: PathBuf::from("a b.txt"),
+            modified: PathBuf::from("a_b.txt"),
+            fallback: false,
+        };
+        assert_eq!(change.warning(), None);
+    }
+
+    #[test]
+    fn test_fallback_warning_table() {
+        // the names mapped by the table are not fallback names
+        let mut table = TransliterationTable::new();
+        table.insert("日本", "nihon").unwrap();
+        table.insert("★", "star").unwrap();
+        for (name, expected) in [("日本.txt", "nihon.txt"), ("★.txt", "star.txt")] {
+            let notox_args = NotoxArgs {
+                output: NotoxOutput::Quiet,
+                mode: NotoxMode::Explain(name.to_string()),
+                table: table.clone(),
+                ..NotoxArgs::default()
+            };
+            let change = Notox::new(notox_args).run(&HashSet::new()).remove(0);
+            assert_eq!(
+                change,
+                PathChange::WouldRename {
+                    path: PathBuf::from(name),
+                    modified: PathBuf::from(expected),
+                    fallback: false,
+                }
+            );
+            assert_eq!(change.warning(), None);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_fallback_warning_json() {
+        let change = PathChange::Changed {
+            path: PathBuf::from("日本語.txt"),
+            modified: PathBuf::from("2ffd6801.txt"),
+            fallback: true,
+        };
+        let json = serde_json::to_string(&change).unwrap();
+        assert_eq!(
+            json,
+            r#"{"path":"日本語.txt","modified":"2ffd6801.txt","error":null,"warning":"nothing left once cleaned, fallback name used"}"#
+        );
+        let deserialized: PathChange = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, change);
+    }
+
+    #[test]
+    fn test_parse_args_fallback() {
+        let vec_args = [
+            "notox".to_string(),
+            "README.md".to_string(),
+            "--fallback".to_string(),
+            "codepoints".to_string(),
+        ];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert_eq!(options.fallback, FallbackName::CodePoints);
+
+        let vec_args = [
+            "notox".to_string(),
+            "README.md".to_string(),
+            "--fallback".to_string(),
+            "unnamed".to_string(),
+        ];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert_eq!(
+            options.fallback,
+            FallbackName::Placeholder("unnamed".to_string())
+        );
+
+        let vec_args = ["notox".to_string(), "--fallback".to_string()];
+        assert_eq!(notox::parse_args(&vec_args), Err(2));
+    }
+}
@@ -0,0 +1,125 @@
+mod common;
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod tests {
+    use std::{
+        collections::HashSet,
+        path::{Path, PathBuf},
+    };
+
+    use notox::{Notox, NotoxArgs, NotoxMode, NotoxOutput, PathChange};
+
+    use crate::common;
+
+    fn run_with_journal(directory_path: &Path, journal_path: &Path) -> Vec<PathChange> {
+        let notox_args = NotoxArgs {
+            dry_run: false,
+            output: NotoxOutput::Quiet,
+            journal: Some(journal_path.to_path_buf()),
+            ..NotoxArgs::default()
+        };
+        Notox::new(notox_args).run(&HashSet::from([directory_path.to_path_buf()]))
+    }
+
+    #[test]
+    fn test_journal_undo() {
+        let directory_path = common::setup("test_journal_undo", common::NESTED_FILES);
+        let journal_path = directory_path.with_extension("json");
+        run_with_journal(&directory_path, &journal_path);
+        assert!(directory_path.join("c_d").join("e_f.txt").exists());
+
+        let journal: Vec<PathChange> =
+            serde_json::from_str(&std::fs::read_to_string(&journal_path).unwrap()).unwrap();
+        assert_eq!(journal.len(), 3);
+        assert!(journal.contains(&PathChange::Changed {
+            path: directory_path.join("c_d").join("e f.txt"),
+            modified: directory_path.join("c_d").join("e_f.txt"),
+            fallback: false,
+        }));
+
+        let notox_args = NotoxArgs {
+            dry_run: false,
+            output: NotoxOutput::Quiet,
+            ..NotoxArgs::default()
+        };
+        let res = Notox::new(notox_args).undo(&journal_path);
+        assert_eq!(res.len(), 3);
+        assert!(res
+            .iter()
+            .all(|one_change| matches!(one_change, PathChange::Changed { .. })));
+        assert!(directory_path.join("a b.txt").exists());
+        assert!(directory_path.join("c d").join("e f.txt").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_journal_undo_dry_run() {
+        let directory_path = common::setup("test_journal_undo_dry", common::NESTED_FILES);
+        let journal_path = directory_path.with_extension("json");
+        run_with_journal(&directory_path, &journal_path);
+
+        let notox_args = NotoxArgs {
+            dry_run: true,
+            output: NotoxOutput::Quiet,
+            mode: NotoxMode::Undo(journal_path.clone()),
+            ..NotoxArgs::default()
+        };
+        let res = Notox::new(notox_args).run(&HashSet::new());
+        assert_eq!(res.len(), 3);
+        assert!(directory_path.join("a_b.txt").exists());
+        assert!(directory_path.join("c_d").join("e_f.txt").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_journal_undo_missing() {
+        let directory_path = common::setup("test_journal_undo_missing", common::NESTED_FILES);
+        let journal_path = directory_path.with_extension("json");
+        run_with_journal(&directory_path, &journal_path);
+        std::fs::remove_file(directory_path.join("a_b.txt")).unwrap();
+
+        let notox_args = NotoxArgs {
+            dry_run: false,
+            output: NotoxOutput::Quiet,
+            ..NotoxArgs::default()
+        };
+        let res = Notox::new(notox_args).undo(&journal_path);
+        assert!(res.iter().any(|one_change| matches!(
+            one_change,
+            PathChange::ErrorRename { path, .. } if path == &directory_path.join("a_b.txt")
+        )));
+        assert!(directory_path.join("c d").join("e f.txt").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_journal_undo_no_journal() {
+        let res = Notox::new(NotoxArgs::default()).undo(Path::new("test_journal_none.json"));
+        assert_eq!(res.len(), 1);
+        assert!(matches!(res[0], PathChange::Error { .. }));
+    }
+
+    #[test]
+    fn test_parse_args_journal() {
+        let vec_args = [
+            "notox".to_string(),
+            "README.md".to_string(),
+            "--journal".to_string(),
+            "journal.json".to_string(),
+        ];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert_eq!(options.journal, Some(PathBuf::from("journal.json")));
+
+        let vec_args = [
+            "notox".to_string(),
+            "-u".to_string(),
+            "journal.json".to_string(),
+        ];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert_eq!(options.mode, NotoxMode::Undo(PathBuf::from("journal.json")));
+
+        let vec_args = ["notox".to_string(), "--undo".to_string()];
+        assert_eq!(notox::parse_args(&vec_args), Err(2));
+    }
+}
@@ -0,0 +1,161 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, path::PathBuf};
+
+    use notox::{
+        CaseMode, Language, Notox, NotoxArgs, NotoxMode, NotoxOutput, PathChange,
+        TransliterationTable, LANGUAGES,
+    };
+
+    use crate::common;
+
+    fn clean(name: &str, language: Option<Language>) -> PathBuf {
+        let notox_args = NotoxArgs {
+            language,
+            ..NotoxArgs::default()
+        };
+        common::clean(name, &notox_args)
+    }
+
+    #[test]
+    fn test_language_mappings() {
+        for language in LANGUAGES {
+            let mappings = language.mappings();
+            assert!(mappings.windows(2).all(|pair| pair[0].0 < pair[1].0));
+            assert!(mappings
+                .iter()
+                .all(|(_, mapped)| mapped.bytes().all(|byte| byte.is_ascii_alphabetic())));
+            assert_eq!(Language::from_code(language.code()), Some(*language));
+        }
+        assert_eq!(Language::from_code("DE"), Some(Language::German));
+        assert_eq!(Language::from_code("xx"), None);
+    }
+
+    #[test]
+    fn test_language_spellings() {
+        let german = Some(Language::German);
+        assert_eq!(clean("Müller.txt", german), PathBuf::from("Mueller.txt"));
+        assert_eq!(
+            clean("Straße Öl.txt", german),
+            PathBuf::from("Strasse_Oel.txt")
+        );
+        assert_eq!(clean("Müller.txt", None), PathBuf::from("Muller.txt"));
+        let danish = Some(Language::Danish);
+        assert_eq!(clean("Ærø", danish), PathBuf::from("Aeroe"));
+        assert_eq!(clean("Åse", danish), PathBuf::from("Aase"));
+        assert_eq!(clean("Ærø", None), PathBuf::from("AEro"));
+        assert_eq!(
+            clean("Göteborg", Some(Language::Swedish)),
+            PathBuf::from("Goeteborg")
+        );
+        assert_eq!(
+            clean("Œuvre", Some(Language::French)),
+            PathBuf::from("Oeuvre")
+        );
+    }
+
+    #[test]
+    fn test_language_case() {
+        let german = Some(Language::German);
+        assert_eq!(clean("MÜLLER.txt", german), PathBuf::from("MUELLER.txt"));
+        assert_eq!(clean("STRAßE", german), PathBuf::from("STRASSE"));
+        assert_eq!(clean("STRAẞE", german), PathBuf::from("STRASSE"));
+        assert_eq!(clean("Fuß Ö", german), PathBuf::from("Fuss_Oe"));
+        assert_eq!(clean("ÜBER uns", german), PathBuf::from("UEBER_uns"));
+        let danish = Some(Language::Danish);
+        assert_eq!(clean("ÆRØ", danish), PathBuf::from("AEROE"));
+        assert_eq!(clean("ÅSE", danish), PathBuf::from("AASE"));
+        assert_eq!(
+            clean("ŒUVRE", Some(Language::French)),
+            PathBuf::from("OEUVRE")
+        );
+    }
+
+    #[test]
+    fn test_language_turkish() {
+        let turkish = Some(Language::Turkish);
+        assert_eq!(clean("İzmir.txt", turkish), PathBuf::from("Izmir.txt"));
+        assert_eq!(clean("Işık ılık", turkish), PathBuf::from("Isik_ilik"));
+        assert_eq!(clean("DİYARBAKIR", turkish), PathBuf::from("DIYARBAKIR"));
+        assert_eq!(clean("IŞIK Ğ", turkish), PathBuf::from("ISIK_G"));
+        assert_eq!(clean("Çağrı", turkish), PathBuf::from("Cagri"));
+        // the case mode is applied to the ASCII letters once the Turkish letters are spelled
+        let cased = |name: &str, case: CaseMode| {
+            let notox_args = NotoxArgs {
+                language: turkish,
+                case: Some(case),
+                ..NotoxArgs::default()
+            };
+            common::clean(name, &notox_args)
+        };
+        assert_eq!(
+            cased("İSTANBUL Işık.txt", CaseMode::Lower),
+            PathBuf::from("istanbul_isik.txt")
+        );
+        assert_eq!(
+            cased("istanbul ılık", CaseMode::Upper),
+            PathBuf::from("ISTANBUL_ILIK")
+        );
+        assert_eq!(
+            cased("İzmir ılık", CaseMode::Pascal),
+            PathBuf::from("IzmirIlik")
+        );
+    }
+
+    #[test]
+    fn test_language_spanish_polish() {
+        let spanish = Some(Language::Spanish);
+        assert_eq!(
+            clean("Año Niño.txt", spanish),
+            PathBuf::from("Ano_Nino.txt")
+        );
+        assert_eq!(clean("CANCIÓN", spanish), PathBuf::from("CANCION"));
+        assert_eq!(clean("pingüino", spanish), PathBuf::from("pinguino"));
+        let polish = Some(Language::Polish);
+        assert_eq!(clean("Łódź.txt", polish), PathBuf::from("Lodz.txt"));
+        assert_eq!(clean("ŻÓŁĆ", polish), PathBuf::from("ZOLC"));
+        assert_eq!(
+            clean("Gdańsk Kraków", polish),
+            PathBuf::from("Gdansk_Krakow")
+        );
+    }
+
+    #[test]
+    fn test_language_with_table() {
+        // the table of the user is used before the language
+        let mut table = TransliterationTable::new();
+        table.insert("ü", "u").unwrap();
+        let notox_args = NotoxArgs {
+            output: NotoxOutput::Quiet,
+            mode: NotoxMode::Explain("Müßig".to_string()),
+            language: Some(Language::German),
+            table,
+            ..NotoxArgs::default()
+        };
+        let res = Notox::new(notox_args).run(&HashSet::new());
+        assert_eq!(
+            res,
+            vec![PathChange::WouldRename {
+                path: PathBuf::from("Müßig"),
+                modified: PathBuf::from("Mussig"),
+                fallback: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_args_lang() {
+        let vec_args = ["notox".to_string(), "--lang".to_string(), "da".to_string()];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert_eq!(options.language, Some(Language::Danish));
+        let vec_args = ["notox".to_string(), "--lang".to_string(), "tr".to_string()];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert_eq!(options.language, Some(Language::Turkish));
+        let vec_args = ["notox".to_string(), "--lang".to_string(), "xx".to_string()];
+        assert_eq!(notox::parse_args(&vec_args), Err(2));
+        let vec_args = ["notox".to_string(), "--lang".to_string()];
+        assert_eq!(notox::parse_args(&vec_args), Err(2));
+    }
+}
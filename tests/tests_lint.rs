@@ -0,0 +1,123 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, ffi::OsStr};
+
+    use notox::{
+        lint_name, LintCategory, LintFinding, Notox, NotoxArgs, NotoxMode, NotoxOutput,
+        Romanization, TransliterationTable,
+    };
+
+    use crate::common;
+
+    fn categories_with(name: &str, notox_args: &NotoxArgs) -> Vec<LintCategory> {
+        lint_name(OsStr::new(name), notox_args)
+            .into_iter()
+            .map(|one_finding| one_finding.category)
+            .collect()
+    }
+
+    fn categories(name: &str) -> Vec<LintCategory> {
+        lint_name(OsStr::new(name), &NotoxArgs::default())
+            .into_iter()
+            .map(|one_finding| one_finding.category)
+            .collect()
+    }
+
+    #[test]
+    fn test_lint_name() {
+        assert!(lint_name(OsStr::new("my-file_1.txt"), &NotoxArgs::default()).is_empty());
+        assert_eq!(
+            lint_name(OsStr::new("Ça_-_va.txt"), &NotoxArgs::default()),
+            vec![
+                LintFinding {
+                    category: LintCategory::NonAsciiLetter,
+                    index: 0,
+                    text: "Ç".to_string(),
+                },
+                LintFinding {
+                    category: LintCategory::RepeatedSeparators,
+                    index: 3,
+                    text: "_-_".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            categories("a b$c"),
+            vec![LintCategory::Whitespace, LintCategory::ShellMetacharacter]
+        );
+        assert_eq!(categories("a\nb"), vec![LintCategory::ControlCharacter]);
+        assert_eq!(categories("a,b"), vec![LintCategory::Punctuation]);
+        assert_eq!(categories("a日b"), vec![LintCategory::Dropped]);
+        assert_eq!(categories("_a"), vec![LintCategory::RepeatedSeparators]);
+    }
+
+    #[test]
+    fn test_lint_name_options() {
+        // the characters are categorized as the options clean them
+        assert_eq!(
+            categories("Ёж"),
+            vec![LintCategory::Dropped, LintCategory::Dropped]
+        );
+        let notox_args = NotoxArgs {
+            romanization: Romanization::all(),
+            ..NotoxArgs::default()
+        };
+        assert_eq!(
+            categories_with("Ёж", &notox_args),
+            vec![LintCategory::NonAsciiLetter, LintCategory::NonAsciiLetter]
+        );
+        let mut table = TransliterationTable::new();
+        table.insert("日本", "nihon").unwrap();
+        let notox_args = NotoxArgs {
+            table,
+            ..NotoxArgs::default()
+        };
+        assert_eq!(
+            categories_with("a日本", &notox_args),
+            vec![LintCategory::NonAsciiLetter, LintCategory::NonAsciiLetter]
+        );
+    }
+
+    #[test]
+    fn test_lint_directory() {
+        let directory_path =
+            common::setup("test_lint_directory", &["ok.txt", "a b.txt", "c?d.txt"]);
+        let notox_inst = Notox::new(NotoxArgs {
+            dry_run: false,
+            output: NotoxOutput::Quiet,
+            mode: NotoxMode::Check,
+            ..NotoxArgs::default()
+        });
+        let reports = notox_inst.lint(&HashSet::from([directory_path.clone()]));
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].path, directory_path.join("a b.txt"));
+        assert_eq!(reports[0].modified, directory_path.join("a_b.txt"));
+        assert_eq!(reports[0].findings[0].category, LintCategory::Whitespace);
+        assert_eq!(reports[1].path, directory_path.join("c?d.txt"));
+
+        // the check mode never renames
+        let res = notox_inst.run(&HashSet::from([directory_path.clone()]));
+        assert_eq!(res.len(), 4);
+        assert!(directory_path.join("a b.txt").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_lint_exit_code() {
+        let directory_path = common::setup("test_lint_exit", &["ok.txt", "a b.txt"]);
+        let args = |file: &str| {
+            [
+                "notox".to_string(),
+                "--check".to_string(),
+                "-q".to_string(),
+                directory_path.join(file).display().to_string(),
+            ]
+        };
+        assert_eq!(Notox::run_main_from_args(&args("ok.txt")), 0);
+        assert_eq!(Notox::run_main_from_args(&args("a b.txt")), 1);
+        assert!(directory_path.join("a b.txt").exists());
+        common::cleanup(&directory_path);
+    }
+}
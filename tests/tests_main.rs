@@ -187,6 +187,7 @@ mod tests {
                 json: JsonOutput::JsonDefault,
                 pretty: true,
             },
+            ..NotoxArgs::default()
         };
         let path_to_check: HashSet<PathBuf> =
             HashSet::from(["README.md".into(), "Cargo.toml".into()]);
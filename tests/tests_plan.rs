@@ -0,0 +1,216 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use notox::{
+        ConflictStrategy, Notox, NotoxArgs, NotoxError, NotoxOutput, PathChange, PlannedRename,
+    };
+
+    use crate::common;
+
+    fn notox(conflict: ConflictStrategy) -> Notox {
+        Notox::new(NotoxArgs {
+            dry_run: false,
+            output: NotoxOutput::Quiet,
+            conflict,
+            ..NotoxArgs::default()
+        })
+    }
+
+    #[test]
+    fn test_plan_nested() {
+        let directory_path = common::setup("test_plan_nested", common::NESTED_FILES);
+        let plan = notox(ConflictStrategy::Skip).plan(&HashSet::from([directory_path.clone()]));
+        assert!(!plan.aborted);
+        assert_eq!(
+            plan.renames,
+            vec![
+                PlannedRename {
+                    path: directory_path.join("a b.txt"),
+                    modified: directory_path.join("a_b.txt"),
+                    selected: true,
+                    fallback: false,
+                },
+                PlannedRename {
+                    path: directory_path.join("c d"),
+                    modified: directory_path.join("c_d"),
+                    selected: true,
+                    fallback: false,
+                },
+                PlannedRename {
+                    path: directory_path.join("c d").join("e f.txt"),
+                    modified: directory_path.join("c d").join("e_f.txt"),
+                    selected: true,
+                    fallback: false,
+                },
+            ]
+        );
+        // nothing is renamed by the plan
+        assert!(directory_path.join("c d").join("e f.txt").exists());
+
+        let res = plan.apply();
+        assert_eq!(res.len(), 4);
+        assert!(res.contains(&PathChange::Changed {
+            path: directory_path.join("c_d").join("e f.txt"),
+            modified: directory_path.join("c_d").join("e_f.txt"),
+            fallback: false,
+        }));
+        assert!(directory_path.join("a_b.txt").exists());
+        assert!(directory_path.join("c_d").join("e_f.txt").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_plan_deselect() {
+        let directory_path = common::setup("test_plan_deselect", common::NESTED_FILES);
+        let mut plan = notox(ConflictStrategy::Skip).plan(&HashSet::from([directory_path.clone()]));
+        for one_rename in &mut plan.renames {
+            if one_rename.path == directory_path.join("c d") {
+                one_rename.selected = false;
+            }
+        }
+        let res = plan.apply();
+        assert!(res.contains(&PathChange::Unchanged {
+            path: directory_path.join("c d"),
+        }));
+        assert!(directory_path.join("a_b.txt").exists());
+        assert!(directory_path.join("c d").join("e_f.txt").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_plan_dry_run() {
+        let directory_path = common::setup("test_plan_dry_run", common::NESTED_FILES);
+        let plan = notox(ConflictStrategy::Skip).plan(&HashSet::from([directory_path.clone()]));
+        let res = plan.dry_run();
+        assert_eq!(res.len(), 4);
+        assert!(res.contains(&PathChange::WouldRename {
+            path: directory_path.join("c d").join("e f.txt"),
+            modified: directory_path.join("c d").join("e_f.txt"),
+            fallback: false,
+        }));
+        assert!(directory_path.join("a b.txt").exists());
+        assert!(directory_path.join("c d").join("e f.txt").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_plan_duplicate_targets() {
+        let directory_path = common::setup("test_plan_duplicate", common::NESTED_FILES);
+        std::fs::File::create(directory_path.join("a?b.txt")).unwrap();
+        let plan = notox(ConflictStrategy::Skip).plan(&HashSet::from([directory_path.clone()]));
+        assert!(plan.renames.contains(&PlannedRename {
+            path: directory_path.join("a b.txt"),
+            modified: directory_path.join("a_b.txt"),
+            selected: true,
+            fallback: false,
+        }));
+        assert!(plan.results.contains(&PathChange::Conflict {
+            path: directory_path.join("a?b.txt"),
+            modified: directory_path.join("a_b.txt"),
+            fallback: false,
+        }));
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_plan_abort() {
+        let directory_path = common::setup("test_plan_abort", common::NESTED_FILES);
+        std::fs::File::create(directory_path.join("a_b.txt")).unwrap();
+        let plan = notox(ConflictStrategy::Abort).plan(&HashSet::from([directory_path.clone()]));
+        assert!(plan.aborted);
+        let res = plan.apply();
+        assert!(res.contains(&PathChange::ErrorRename {
+            path: directory_path.join("c d"),
+            modified: directory_path.join("c_d"),
+            error: NotoxError::Aborted,
+            fallback: false,
+        }));
+        // nothing has been renamed
+        assert!(directory_path.join("a b.txt").exists());
+        assert!(directory_path.join("c d").join("e f.txt").exists());
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    fn test_plan_overlapping_inputs() {
+        let directory_path = common::setup("test_plan_overlapping", common::NESTED_FILES);
+        let plan = notox(ConflictStrategy::Skip).plan(&HashSet::from([
+            directory_path.clone(),
+            directory_path.join("a b.txt"),
+            directory_path.join("c d"),
+        ]));
+        assert_eq!(plan.renames.len(), 3);
+        assert_eq!(
+            plan.results,
+            vec![PathChange::Unchanged {
+                path: directory_path.clone()
+            }]
+        );
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_plan_permission() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let directory_path = common::setup("test_plan_permission", common::NESTED_FILES);
+        let read_only = directory_path.join("c d");
+        std::fs::set_permissions(&read_only, std::fs::Permissions::from_mode(0o555)).unwrap();
+        // root can write a read-only directory, there is no missing permission to report
+        let probe = read_only.join("probe");
+        if std::fs::write(&probe, "").is_ok() {
+            std::fs::remove_file(&probe).unwrap();
+            std::fs::set_permissions(&read_only, std::fs::Permissions::from_mode(0o755)).unwrap();
+            common::cleanup(&directory_path);
+            return;
+        }
+        let plan = notox(ConflictStrategy::Skip).plan(&HashSet::from([directory_path.clone()]));
+        let error = plan
+            .results
+            .iter()
+            .find_map(|one_change| match one_change {
+                PathChange::ErrorRename {
+                    path,
+                    modified,
+                    error,
+                    fallback: false,
+                } if *path == read_only.join("e f.txt")
+                    && *modified == read_only.join("e_f.txt") =>
+                {
+                    Some(error)
+                }
+                _ => None,
+            })
+            .unwrap();
+        assert!(matches!(error, NotoxError::PermissionDenied(_)));
+        assert!(plan
+            .renames
+            .iter()
+            .all(|one_rename| one_rename.path != read_only.join("e f.txt")));
+        std::fs::set_permissions(&read_only, std::fs::Permissions::from_mode(0o755)).unwrap();
+        common::cleanup(&directory_path);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_would_rename_json() {
+        use std::path::PathBuf;
+
+        let change = PathChange::WouldRename {
+            path: PathBuf::from("a b.txt"),
+            modified: PathBuf::from("a_b.txt"),
+            fallback: false,
+        };
+        let json = serde_json::to_string(&change).unwrap();
+        assert_eq!(
+            json,
+            r#"{"path":"a b.txt","modified":"a_b.txt","error":null,"dry_run":true}"#
+        );
+        let deserialized: PathChange = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, change);
+    }
+}
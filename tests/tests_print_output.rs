@@ -12,18 +12,22 @@ mod tests {
             NotoxArgs {
                 dry_run: true,
                 output: NotoxOutput::Default,
+                ..NotoxArgs::default()
             },
             NotoxArgs {
                 dry_run: false,
                 output: NotoxOutput::Default,
+                ..NotoxArgs::default()
             },
             NotoxArgs {
                 dry_run: true,
                 output: NotoxOutput::Quiet,
+                ..NotoxArgs::default()
             },
             NotoxArgs {
                 dry_run: false,
                 output: NotoxOutput::Quiet,
+                ..NotoxArgs::default()
             },
             #[cfg(feature = "serde")]
             NotoxArgs {
@@ -32,6 +36,7 @@ mod tests {
                     json: JsonOutput::JsonDefault,
                     pretty: false,
                 },
+                ..NotoxArgs::default()
             },
             #[cfg(feature = "serde")]
             NotoxArgs {
@@ -40,6 +45,7 @@ mod tests {
                     json: JsonOutput::JsonDefault,
                     pretty: false,
                 },
+                ..NotoxArgs::default()
             },
             #[cfg(feature = "serde")]
             NotoxArgs {
@@ -48,6 +54,7 @@ mod tests {
                     json: JsonOutput::JsonDefault,
                     pretty: true,
                 },
+                ..NotoxArgs::default()
             },
             #[cfg(feature = "serde")]
             NotoxArgs {
@@ -56,6 +63,7 @@ mod tests {
                     json: JsonOutput::JsonOnlyError,
                     pretty: false,
                 },
+                ..NotoxArgs::default()
             },
             #[cfg(feature = "serde")]
             NotoxArgs {
@@ -64,6 +72,7 @@ mod tests {
                     json: JsonOutput::JsonOnlyError,
                     pretty: false,
                 },
+                ..NotoxArgs::default()
             },
             #[cfg(feature = "serde")]
             NotoxArgs {
@@ -72,6 +81,7 @@ mod tests {
                     json: JsonOutput::JsonOnlyError,
                     pretty: true,
                 },
+                ..NotoxArgs::default()
             },
         ];
         for options in args.into_iter() {
@@ -120,6 +130,7 @@ mod tests {
         let options = NotoxArgs {
             dry_run: true,
             output: NotoxOutput::Default,
+            ..NotoxArgs::default()
         };
         let to_correct = PathBuf::from("tes t verbose dry.txt");
         let read_only = PathBuf::from("test_verbose_dry.txt");
@@ -143,6 +154,7 @@ mod tests {
         let options = NotoxArgs {
             dry_run: false,
             output: NotoxOutput::Default,
+            ..NotoxArgs::default()
         };
         let to_correct = PathBuf::from("tes t verbose.txt");
         let read_only = PathBuf::from("test_verbose.txt");
@@ -170,6 +182,7 @@ mod tests {
                 json: JsonOutput::JsonDefault,
                 pretty: false,
             },
+            ..NotoxArgs::default()
         };
         let to_correct = PathBuf::from("tes t json.txt");
         let read_only = PathBuf::from("test_json.txt");
@@ -197,6 +210,7 @@ mod tests {
                 json: JsonOutput::JsonOnlyError,
                 pretty: false,
             },
+            ..NotoxArgs::default()
         };
         let to_correct = PathBuf::from("tes t json error.txt");
         let read_only = PathBuf::from("test_json_error.txt");
@@ -224,6 +238,7 @@ mod tests {
                 json: JsonOutput::JsonOnlyError,
                 pretty: false,
             },
+            ..NotoxArgs::default()
         };
         let to_correct = PathBuf::from("tes t json error dry.txt");
         let read_only = PathBuf::from("test_json_error_dry.txt");
@@ -2,10 +2,38 @@
 mod tests {
     use std::{collections::HashSet, path::PathBuf};
 
-    use notox::{Notox, NotoxArgs, NotoxOutput, PathChange};
+    use notox::{
+        ConflictStrategy, ExtensionOptions, FallbackName, Notox, NotoxArgs, NotoxMode, NotoxOutput,
+        PathChange, Romanization, TransliterationTable,
+    };
     const TESTS_FIELDS_NOT_DRY_RUN: NotoxArgs = NotoxArgs {
         dry_run: false,
         output: NotoxOutput::Default,
+        conflict: ConflictStrategy::Skip,
+        fallback: FallbackName::Hash,
+        extension: ExtensionOptions {
+            lowercase: false,
+            synonyms: false,
+        },
+        table: TransliterationTable::new(),
+        language: None,
+        romanization: Romanization {
+            cyrillic: None,
+            greek: false,
+            kana: false,
+            hangul: false,
+            hanzi: None,
+            arabic: None,
+            hebrew: false,
+            devanagari: false,
+        },
+        unicode_names: false,
+        emoji: false,
+        words: None,
+        case: None,
+        mode: NotoxMode::Clean,
+        #[cfg(feature = "serde")]
+        journal: None,
     };
 
     #[test]
@@ -33,10 +61,11 @@ mod tests {
                 path,
                 modified,
                 error,
+                ..
             } => {
                 assert_eq!(path, &base_path);
                 assert_eq!(modified, &PathBuf::from("my.file"));
-                assert_eq!(error, "No such file or directory (os error 2)");
+                assert_eq!(error.to_string(), "No such file or directory (os error 2)");
             }
             _ => {
                 println!("Result: {:?}", res[0]);
@@ -67,10 +96,11 @@ mod tests {
                     path,
                     modified,
                     error,
+                    ..
                 } => {
                     assert_eq!(path, &base_path);
                     assert_eq!(modified, &PathBuf::from("my.file"));
-                    assert_eq!(error, "No such file or directory (os error 2)");
+                    assert_eq!(error.to_string(), "No such file or directory (os error 2)");
                 }
                 _ => {
                     println!("Result: {:?}", res[0]);
@@ -100,10 +130,11 @@ mod tests {
                     path,
                     modified,
                     error,
+                    ..
                 } => {
                     assert_eq!(path, &base_path);
                     assert_eq!(modified, &PathBuf::from("my-file"));
-                    assert_eq!(error, "No such file or directory (os error 2)");
+                    assert_eq!(error.to_string(), "No such file or directory (os error 2)");
                 }
                 _ => {
                     println!("Result: {:?}", res[0]);
@@ -164,10 +195,11 @@ mod tests {
                     path,
                     modified,
                     error,
+                    ..
                 } => {
                     assert_eq!(path, &path_to_test);
                     assert_eq!(modified, &result_to_test);
-                    assert_eq!(error, "No such file or directory (os error 2)")
+                    assert_eq!(error.to_string(), "No such file or directory (os error 2)")
                 }
                 _ => {
                     println!("Result: {:?}", res[0]);
@@ -251,6 +283,14 @@ mod tests {
             } else if current_char == '/' {
                 // / is 47
                 correct_path = PathBuf::from("UNCHANGED");
+            } else if let Some(ascii) = notox::ascii_equivalent(current_char) {
+                // typographic punctuation, cleaned as its ASCII equivalent
+                let separator = if ascii == b' ' {
+                    '_'
+                } else {
+                    char::from(ascii)
+                };
+                correct_path = PathBuf::from(format!("my{separator}file.ext"));
             } else if boo {
                 // here format
                 let corrected = format!("my{}file.ext", acc);
@@ -280,6 +320,7 @@ mod tests {
                     path,
                     modified,
                     error,
+                    ..
                 } => {
                     println!(
                         "ErrorRename {} == {} | {} == {}",
@@ -291,9 +332,12 @@ mod tests {
                     assert_eq!(path, &path_to_test);
                     assert_eq!(modified, &correct_path);
                     if index == 0 {
-                        assert_eq!(error, "file name contained an unexpected NUL byte");
+                        assert_eq!(
+                            error.to_string(),
+                            "file name contained an unexpected NUL byte"
+                        );
                     } else {
-                        assert_eq!(error, "No such file or directory (os error 2)");
+                        assert_eq!(error.to_string(), "No such file or directory (os error 2)");
                     }
                 }
                 PathChange::Unchanged { path } => {
@@ -0,0 +1,349 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, path::PathBuf};
+
+    #[cfg(feature = "pinyin")]
+    use notox::PinyinSeparator;
+    use notox::{
+        ArabicScheme, CyrillicScheme, Notox, NotoxArgs, NotoxMode, NotoxOutput, PathChange,
+        Romanization,
+    };
+
+    use crate::common;
+
+    fn clean(name: &str, romanization: Romanization) -> PathBuf {
+        let notox_args = NotoxArgs {
+            romanization,
+            ..NotoxArgs::default()
+        };
+        common::clean(name, &notox_args)
+    }
+
+    fn cyrillic(scheme: CyrillicScheme) -> Romanization {
+        Romanization {
+            cyrillic: Some(scheme),
+            ..Romanization::default()
+        }
+    }
+
+    #[test]
+    fn test_cyrillic_bgn_pcgn() {
+        let bgn = cyrillic(CyrillicScheme::BgnPcgn);
+        assert_eq!(
+            clean("Отчёт 2024.pdf", bgn),
+            PathBuf::from("Otchet_2024.pdf")
+        );
+        assert_eq!(clean("Щука", bgn), PathBuf::from("Shchuka"));
+        assert_eq!(clean("ЩУКА", bgn), PathBuf::from("SHCHUKA"));
+        assert_eq!(clean("Ельцин", bgn), PathBuf::from("Yeltsin"));
+        assert_eq!(clean("Цой", bgn), PathBuf::from("Tsoy"));
+        assert_eq!(clean("объект", bgn), PathBuf::from("obyekt"));
+        assert_eq!(clean("Ђоковић", bgn), PathBuf::from("Djokovic"));
+        assert_eq!(clean("Ўладзімір", bgn), PathBuf::from("Wladzimir"));
+    }
+
+    #[test]
+    fn test_cyrillic_bgn_pcgn_languages() {
+        let bgn = cyrillic(CyrillicScheme::BgnPcgn);
+        // Ukrainian, found from `і`, `ї`, `є` or `ґ`
+        assert_eq!(clean("Київ", bgn), PathBuf::from("Kyiv"));
+        assert_eq!(clean("Харків", bgn), PathBuf::from("Kharkiv"));
+        assert_eq!(clean("Запоріжжя", bgn), PathBuf::from("Zaporizhzhia"));
+        assert_eq!(clean("Єнакієве", bgn), PathBuf::from("Yenakiieve"));
+        assert_eq!(clean("Згурівка", bgn), PathBuf::from("Zghurivka"));
+        assert_eq!(clean("ГАЛИЧ і Київ", bgn), PathBuf::from("GALICH_i_Kyiv"));
+        // Belarusian, found from `ў`, or `і` with `ы`, `э`, `ё` or `ъ`
+        assert_eq!(clean("Гаўрыла", bgn), PathBuf::from("Hawryla"));
+        assert_eq!(clean("Гродна", bgn), PathBuf::from("Grodna"));
+    }
+
+    #[test]
+    fn test_cyrillic_gost_7_79_b() {
+        let gost = cyrillic(CyrillicScheme::Gost779B);
+        assert_eq!(
+            clean("Отчёт 2024.pdf", gost),
+            PathBuf::from("Otchyot_2024.pdf")
+        );
+        assert_eq!(clean("Щука", gost), PathBuf::from("Shhuka"));
+        assert_eq!(clean("Ельцин", gost), PathBuf::from("Elcin"));
+        assert_eq!(clean("Цой", gost), PathBuf::from("Czoj"));
+        assert_eq!(clean("Хабаровск", gost), PathBuf::from("Xabarovsk"));
+        assert_eq!(clean("Київ", gost), PathBuf::from("Kyyiv"));
+        assert_eq!(clean("Кино", gost), PathBuf::from("Kino"));
+    }
+
+    #[test]
+    fn test_greek_elot_743() {
+        let greek = Romanization {
+            greek: true,
+            ..Romanization::default()
+        };
+        assert_eq!(clean("Αθήνα.txt", greek), PathBuf::from("Athina.txt"));
+        assert_eq!(clean("Ευαγγελία", greek), PathBuf::from("Evangelia"));
+        assert_eq!(clean("Μπάμπης", greek), PathBuf::from("Bampis"));
+        assert_eq!(clean("Ούζο", greek), PathBuf::from("Ouzo"));
+        assert_eq!(clean("αυτό", greek), PathBuf::from("afto"));
+        assert_eq!(clean("ΘΕΣΣΑΛΟΝΙΚΗ", greek), PathBuf::from("THESSALONIKI"));
+        assert_eq!(clean("Ψυχή", greek), PathBuf::from("Psychi"));
+        // the Cyrillic letters are not romanized
+        assert_eq!(clean("Αθήνα Москва", greek), PathBuf::from("Athina_"));
+    }
+
+    #[test]
+    fn test_kana_hepburn() {
+        let kana = Romanization {
+            kana: true,
+            ..Romanization::default()
+        };
+        assert_eq!(clean("しゃしん.jpg", kana), PathBuf::from("shashin.jpg"));
+        assert_eq!(clean("きゃく", kana), PathBuf::from("kyaku"));
+        assert_eq!(clean("ちょっと", kana), PathBuf::from("chotto"));
+        assert_eq!(clean("がっこう", kana), PathBuf::from("gakkou"));
+        assert_eq!(clean("マッチ", kana), PathBuf::from("matchi"));
+        assert_eq!(clean("コーヒー", kana), PathBuf::from("koohii"));
+        assert_eq!(clean("ファイル.txt", kana), PathBuf::from("fairu.txt"));
+        assert_eq!(clean("ティッシュ", kana), PathBuf::from("tisshu"));
+        assert_eq!(clean("ジェット", kana), PathBuf::from("jetto"));
+        assert_eq!(clean("いすゞ", kana), PathBuf::from("isuzu"));
+        // decomposed names, with the combining marks
+        assert_eq!(clean("か\u{3099}っこう", kana), PathBuf::from("gakkou"));
+        assert_eq!(clean("ハ\u{309A}ン", kana), PathBuf::from("pan"));
+        // the kanji are left to the fallback
+        assert_eq!(clean("東京タワー.jpg", kana), PathBuf::from("tawaa.jpg"));
+        assert_eq!(clean("東京.jpg", kana), PathBuf::from("32d82a42.jpg"));
+    }
+
+    #[test]
+    fn test_kana_trace() {
+        let notox_args = NotoxArgs {
+            output: NotoxOutput::Quiet,
+            mode: NotoxMode::Explain("きゃっ".to_string()),
+            romanization: Romanization {
+                kana: true,
+                ..Romanization::default()
+            },
+            ..NotoxArgs::default()
+        };
+        let res = Notox::new(notox_args).run(&HashSet::new());
+        assert_eq!(
+            res,
+            vec![PathChange::WouldRename {
+                path: PathBuf::from("きゃっ"),
+                modified: PathBuf::from("kya"),
+                fallback: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_hangul_revised() {
+        let hangul = Romanization {
+            hangul: true,
+            ..Romanization::default()
+        };
+        assert_eq!(
+            clean("한글 문서.hwp", hangul),
+            PathBuf::from("hangeul_munseo.hwp")
+        );
+        assert_eq!(clean("서울", hangul), PathBuf::from("seoul"));
+        assert_eq!(clean("한국어", hangul), PathBuf::from("hangugeo"));
+        assert_eq!(clean("신라", hangul), PathBuf::from("silla"));
+        assert_eq!(clean("설날", hangul), PathBuf::from("seollal"));
+        assert_eq!(clean("종로", hangul), PathBuf::from("jongno"));
+        assert_eq!(clean("같이", hangul), PathBuf::from("gachi"));
+        assert_eq!(clean("굳이", hangul), PathBuf::from("guji"));
+        assert_eq!(clean("밭에", hangul), PathBuf::from("bate"));
+        assert_eq!(clean("좋다", hangul), PathBuf::from("jota"));
+        assert_eq!(clean("놓고", hangul), PathBuf::from("noko"));
+        assert_eq!(clean("좋지", hangul), PathBuf::from("jochi"));
+        assert_eq!(clean("많다", hangul), PathBuf::from("manta"));
+        assert_eq!(clean("싫고", hangul), PathBuf::from("silko"));
+        assert_eq!(clean("좋아", hangul), PathBuf::from("joa"));
+        assert_eq!(clean("합니다", hangul), PathBuf::from("hamnida"));
+        assert_eq!(clean("좋아요", hangul), PathBuf::from("joayo"));
+        // decomposed names, with the conjoining jamo
+        assert_eq!(
+            clean("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}", hangul),
+            PathBuf::from("hangeul")
+        );
+        assert_eq!(
+            clean(
+                "\u{1112}\u{1161}\u{11AB}\u{1100}\u{116E}\u{11A8}\u{110B}\u{1165}",
+                hangul
+            ),
+            PathBuf::from("hangugeo")
+        );
+    }
+
+    #[cfg(feature = "pinyin")]
+    #[test]
+    fn test_hanzi_pinyin() {
+        let hanzi = |separator| Romanization {
+            hanzi: Some(separator),
+            ..Romanization::default()
+        };
+        assert_eq!(
+            clean("报告 最终版.docx", hanzi(PinyinSeparator::Joined)),
+            PathBuf::from("baogao_zuizhongban.docx")
+        );
+        assert_eq!(
+            clean("报告 最终版.docx", hanzi(PinyinSeparator::Dash)),
+            PathBuf::from("bao-gao_zui-zhong-ban.docx")
+        );
+        assert_eq!(
+            clean("报告 最终版.docx", hanzi(PinyinSeparator::Underscore)),
+            PathBuf::from("bao_gao_zui_zhong_ban.docx")
+        );
+        assert_eq!(
+            clean("绿茶v2.txt", hanzi(PinyinSeparator::Dash)),
+            PathBuf::from("lyu-chav2.txt")
+        );
+        // without the romanization, the characters are dropped
+        assert_eq!(
+            clean("报告.docx", Romanization::default()),
+            PathBuf::from("af228198.docx")
+        );
+    }
+
+    #[test]
+    fn test_arabic_ala_lc() {
+        let arabic = |scheme| Romanization {
+            arabic: Some(scheme),
+            ..Romanization::default()
+        };
+        let ala_lc = arabic(ArabicScheme::Arabic);
+        assert_eq!(clean("محمد.pdf", ala_lc), PathBuf::from("mhmd.pdf"));
+        assert_eq!(clean("سوريا", ala_lc), PathBuf::from("surya"));
+        assert_eq!(
+            clean("تقرير نهائي.docx", ala_lc),
+            PathBuf::from("tqrir_nhai.docx")
+        );
+        assert_eq!(clean("الكتاب", ala_lc), PathBuf::from("al-ktab"));
+        // with the vowel signs and the shadda
+        assert_eq!(clean("مُحَمَّد", ala_lc), PathBuf::from("muhammad"));
+        assert_eq!(clean("الْكِتَاب", ala_lc), PathBuf::from("al-kitab"));
+        assert_eq!(clean("شُكْراً", ala_lc), PathBuf::from("shukran"));
+        assert_eq!(clean("ذهب", ala_lc), PathBuf::from("dhhb"));
+        let persian = arabic(ArabicScheme::Persian);
+        assert_eq!(clean("ذهب", persian), PathBuf::from("zhb"));
+        assert_eq!(clean("کتاب فارسی", persian), PathBuf::from("ktab_farsi"));
+        assert_eq!(clean("پِدَر", persian), PathBuf::from("pedar"));
+    }
+
+    #[test]
+    fn test_hebrew_ala_lc() {
+        let hebrew = Romanization {
+            hebrew: true,
+            ..Romanization::default()
+        };
+        assert_eq!(clean("שלום.txt", hebrew), PathBuf::from("shlom.txt"));
+        assert_eq!(clean("ישראל", hebrew), PathBuf::from("yshrl"));
+        // with the points
+        assert_eq!(clean("שָׁלוֹם", hebrew), PathBuf::from("shalom"));
+        assert_eq!(clean("תֵּל אָבִיב", hebrew), PathBuf::from("tel_aviv"));
+        assert_eq!(clean("שָׂרָה", hebrew), PathBuf::from("sarah"));
+        assert_eq!(clean("סֵפֶר", hebrew), PathBuf::from("sefer"));
+        assert_eq!(clean("פּוּרִים", hebrew), PathBuf::from("purim"));
+    }
+
+    #[test]
+    fn test_devanagari_iso_15919() {
+        let devanagari = Romanization {
+            devanagari: true,
+            ..Romanization::default()
+        };
+        assert_eq!(clean("नमस्ते.txt", devanagari), PathBuf::from("namaste.txt"));
+        assert_eq!(clean("हिन्दी", devanagari), PathBuf::from("hindi"));
+        assert_eq!(clean("भारत", devanagari), PathBuf::from("bharata"));
+        assert_eq!(clean("संस्कृत", devanagari), PathBuf::from("samskrta"));
+        assert_eq!(clean("क्षत्रिय", devanagari), PathBuf::from("ksatriya"));
+        // precomposed and decomposed nukta
+        assert_eq!(clean("\u{095B}रा", devanagari), PathBuf::from("zara"));
+        assert_eq!(clean("ज\u{093C}रा", devanagari), PathBuf::from("zara"));
+    }
+
+    #[test]
+    fn test_bidi_controls() {
+        let arabic = Romanization {
+            arabic: Some(ArabicScheme::Arabic),
+            ..Romanization::default()
+        };
+        assert_eq!(
+            clean("\u{200F}محمد\u{200F}.pdf", arabic),
+            PathBuf::from("mhmd.pdf")
+        );
+        assert_eq!(
+            clean("report\u{202E}fdp.exe", Romanization::default()),
+            PathBuf::from("reportfdp.exe")
+        );
+        assert_eq!(
+            clean("\u{2067}notes\u{2069} v2.txt", Romanization::default()),
+            PathBuf::from("notes_v2.txt")
+        );
+    }
+
+    #[test]
+    fn test_romanization_warning() {
+        let change = PathChange::WouldRename {
+            path: PathBuf::from("Москва.txt"),
+            modified: PathBuf::from("Moskva.txt"),
+            fallback: false,
+        };
+        assert_eq!(change.warning(), None);
+        let change = PathChange::WouldRename {
+            path: PathBuf::from("Москва.txt"),
+            modified: PathBuf::from("feb471b1.txt"),
+            fallback: true,
+        };
+        assert!(change.warning().is_some());
+    }
+
+    #[test]
+    fn test_parse_args_romanize() {
+        let vec_args = [
+            "notox".to_string(),
+            "--romanize".to_string(),
+            "cyrillic-gost,greek".to_string(),
+        ];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert_eq!(
+            options.romanization,
+            Romanization {
+                cyrillic: Some(CyrillicScheme::Gost779B),
+                greek: true,
+                kana: false,
+                hangul: false,
+                hanzi: None,
+                arabic: None,
+                hebrew: false,
+                devanagari: false,
+            }
+        );
+        let vec_args = [
+            "notox".to_string(),
+            "--romanize".to_string(),
+            "klingon".to_string(),
+        ];
+        assert_eq!(notox::parse_args(&vec_args), Err(2));
+        let vec_args = ["notox".to_string(), "--romanize".to_string()];
+        assert_eq!(notox::parse_args(&vec_args), Err(2));
+    }
+
+    #[test]
+    fn test_parse_args_romanize_hanzi() {
+        let vec_args = [
+            "notox".to_string(),
+            "--romanize".to_string(),
+            "hanzi-dash".to_string(),
+        ];
+        #[cfg(feature = "pinyin")]
+        assert_eq!(
+            notox::parse_args(&vec_args).map(|(options, _)| options.romanization.hanzi),
+            Ok(Some(PinyinSeparator::Dash))
+        );
+        #[cfg(not(feature = "pinyin"))]
+        assert_eq!(notox::parse_args(&vec_args), Err(2));
+    }
+}
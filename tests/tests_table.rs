@@ -0,0 +1,270 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use notox::{
+        builtin_mapping, check_similar, NotoxArgs, NotoxError, TransliterationTable, BUILTIN_TABLE,
+        DROPPED_LATIN_LETTERS, SPACE_SEPARATORS,
+    };
+
+    use crate::common;
+
+    fn clean(name: &str, table: TransliterationTable) -> PathBuf {
+        let notox_args = NotoxArgs {
+            table,
+            ..NotoxArgs::default()
+        };
+        common::clean(name, &notox_args)
+    }
+
+    #[test]
+    fn test_builtin_table() {
+        assert!(BUILTIN_TABLE.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for (one_char, mapped) in BUILTIN_TABLE {
+            assert!(mapped.bytes().all(|byte| byte.is_ascii_graphic()));
+            let mut name_acc = String::new();
+            check_similar(Some(*one_char), &mut name_acc, false);
+            assert_eq!(&name_acc, mapped);
+        }
+        assert_eq!(builtin_mapping('ß'), Some("ss"));
+        assert_eq!(builtin_mapping('ẞ'), Some("SS"));
+        assert_eq!(builtin_mapping('\u{0301}'), Some(""));
+        assert_eq!(builtin_mapping('日'), None);
+    }
+
+    #[test]
+    fn test_builtin_table_latin_letters() {
+        // Latin-1 Supplement, Latin Extended-A and -B, IPA Extensions,
+        // Phonetic Extensions and their Supplement, Latin Extended Additional,
+        // Latin Extended-C, -D, -E, -F and -G, Mathematical Alphanumeric Symbols
+        let latin_blocks = [
+            '\u{00A0}'..='\u{024F}',
+            '\u{0250}'..='\u{02AF}',
+            '\u{1D00}'..='\u{1DBF}',
+            '\u{1E00}'..='\u{1EFF}',
+            '\u{2C60}'..='\u{2C7F}',
+            '\u{A720}'..='\u{A7FF}',
+            '\u{AB30}'..='\u{AB6F}',
+            '\u{10780}'..='\u{107BF}',
+            '\u{1D400}'..='\u{1D7FF}',
+            '\u{1DF00}'..='\u{1DFFF}',
+        ];
+        for one_char in latin_blocks.into_iter().flatten() {
+            if !one_char.is_alphabetic() {
+                continue;
+            }
+            let dropped = DROPPED_LATIN_LETTERS
+                .iter()
+                .any(|letters| letters.contains(&one_char));
+            let mapped = builtin_mapping(one_char).is_some_and(|mapped| !mapped.is_empty());
+            assert!(
+                mapped != dropped,
+                "{one_char} (U+{:04X}) must be mapped or dropped",
+                u32::from(one_char)
+            );
+        }
+        assert_eq!(builtin_mapping('þ'), Some("th"));
+        assert_eq!(builtin_mapping('Þ'), Some("TH"));
+        assert_eq!(builtin_mapping('ð'), Some("d"));
+        assert_eq!(builtin_mapping('ŋ'), Some("ng"));
+        assert_eq!(builtin_mapping('Ĳ'), Some("IJ"));
+        assert_eq!(builtin_mapping('ʒ'), Some("zh"));
+        assert_eq!(builtin_mapping('ſ'), Some("s"));
+        assert_eq!(builtin_mapping('ʔ'), Some(""));
+    }
+
+    #[test]
+    fn test_builtin_table_extended_latin() {
+        let table = TransliterationTable::new;
+        assert_eq!(clean("Þórður.txt", table()), PathBuf::from("THordur.txt"));
+        assert_eq!(
+            clean("Ŋaŋa ĳs.txt", table()),
+            PathBuf::from("NGanga_ijs.txt")
+        );
+        assert_eq!(clean("Əli.txt", table()), PathBuf::from("Eli.txt"));
+        // the African letters, with the tones as combining marks
+        assert_eq!(
+            clean("ɛ\u{0303}wɔ\u{0301} ƙasa.txt", table()),
+            PathBuf::from("ewo_kasa.txt")
+        );
+        // a glottal stop does not break the word
+        assert_eq!(clean("Hawaiʔi.txt", table()), PathBuf::from("Hawaii.txt"));
+    }
+
+    #[test]
+    fn test_builtin_table_math_letters() {
+        let table = TransliterationTable::new;
+        assert_eq!(
+            clean("𝐁𝐨𝐥𝐝 𝓼𝓬𝓻𝓲𝓹𝓽 𝕕𝕠𝕦𝕓𝕝𝕖.png", table()),
+            PathBuf::from("Bold_script_double.png")
+        );
+        assert_eq!(
+            clean("𝙲𝚘𝚍𝚎 𝟐𝟎𝟐𝟒.txt", table()),
+            PathBuf::from("Code_2024.txt")
+        );
+        assert_eq!(clean("ℂℍℕ ℓ.txt", table()), PathBuf::from("CHN_l.txt"));
+        // the small capitals are lowercase letters
+        assert_eq!(clean("ᴀʙᴄ.txt", table()), PathBuf::from("abc.txt"));
+        assert_eq!(clean("xʰ yₐ.txt", table()), PathBuf::from("xh_ya.txt"));
+        assert_eq!(builtin_mapping('𝛀'), Some("O"));
+        assert_eq!(builtin_mapping('ᵝ'), Some("v"));
+        // the okina and the modifier apostrophe do not break the word
+        assert_eq!(clean("Hawaiʻi.txt", table()), PathBuf::from("Hawaii.txt"));
+        assert_eq!(builtin_mapping('ʼ'), Some(""));
+        assert_eq!(builtin_mapping('ℵ'), None);
+    }
+
+    #[test]
+    fn test_builtin_table_digits() {
+        let table = TransliterationTable::new;
+        // the zeros of some decimal digits: fullwidth, Arabic-Indic, Persian,
+        // Devanagari, Bengali, Thai, Myanmar, mathematical, Garay and Ol Onal
+        let zeros = [
+            '０',
+            '٠',
+            '۰',
+            '०',
+            '০',
+            '๐',
+            '၀',
+            '𝟎',
+            '\u{10D40}',
+            '\u{1E5F1}',
+        ];
+        for zero in zeros {
+            for digit in 0..10 {
+                let one_char = char::from_u32(u32::from(zero) + digit).unwrap();
+                assert_eq!(builtin_mapping(one_char), Some(digit.to_string().as_str()));
+            }
+        }
+        assert_eq!(
+            clean("Track ０３ - ٣.mp3", table()),
+            PathBuf::from("Track_03-3.mp3")
+        );
+        assert_eq!(
+            clean("ep ⑫ ⒓ ㉑.mkv", table()),
+            PathBuf::from("ep_12_12_21.mkv")
+        );
+        assert_eq!(clean("x² y₃.txt", table()), PathBuf::from("x2_y3.txt"));
+        assert_eq!(
+            clean("½ ¾ ⅞.txt", table()),
+            PathBuf::from("1-2_3-4_7-8.txt")
+        );
+        assert_eq!(
+            clean("Chapter Ⅻ ⅳ.txt", table()),
+            PathBuf::from("Chapter_XII_iv.txt")
+        );
+        assert_eq!(builtin_mapping('❿'), Some("10"));
+        assert_eq!(builtin_mapping('⓪'), Some("0"));
+    }
+
+    #[test]
+    fn test_typographic_punctuation() {
+        let table = TransliterationTable::new;
+        assert_eq!(clean("a—b", table()), PathBuf::from("a-b"));
+        assert_eq!(
+            clean("report – final.pdf", table()),
+            PathBuf::from("report-final.pdf")
+        );
+        assert_eq!(clean("−5 °C", table()), PathBuf::from("-5_C"));
+        assert_eq!(
+            clean("don’t “stop”.txt", table()),
+            PathBuf::from("dont_stop.txt")
+        );
+        assert_eq!(clean("«Titre».txt", table()), PathBuf::from("Titre.txt"));
+        assert_eq!(
+            clean("Chapter 1… Final.docx", table()),
+            PathBuf::from("Chapter_1.Final.docx")
+        );
+        assert_eq!(clean("wait…", table()), PathBuf::from("wait."));
+        assert_eq!(clean("x • y", table()), PathBuf::from("x_y"));
+        assert_eq!(
+            clean("soft\u{00AD}ware", table()),
+            PathBuf::from("software")
+        );
+        for spaces in SPACE_SEPARATORS {
+            for space in spaces.clone() {
+                let name = format!("a{space}b {space}c.txt");
+                assert_eq!(clean(&name, table()), PathBuf::from("a_b_c.txt"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_table_layered() {
+        let mut table = TransliterationTable::new();
+        table.insert("ß", "ss").unwrap();
+        table.insert("日", "hi").unwrap();
+        table.insert("日本", "nihon").unwrap();
+        assert_eq!(
+            clean("Straße.txt", table.clone()),
+            PathBuf::from("Strasse.txt")
+        );
+        assert_eq!(
+            clean("Straße .txt", table.clone()),
+            PathBuf::from("Strasse.txt")
+        );
+        // the longest sequence wins, the built-in table is still used
+        assert_eq!(
+            clean("日本 日 ü.txt", table.clone()),
+            PathBuf::from("nihon_hi_u.txt")
+        );
+        // no fallback once the table maps the name
+        assert_eq!(clean("日本.txt", table), PathBuf::from("nihon.txt"));
+        assert_eq!(
+            clean("Straße.txt", TransliterationTable::new()),
+            PathBuf::from("Strasse.txt")
+        );
+    }
+
+    #[test]
+    fn test_table_insert() {
+        let mut table = TransliterationTable::new();
+        assert!(table.is_empty());
+        table.insert("ß", "s").unwrap();
+        table.insert("ß", "ss").unwrap();
+        table.insert("&", "").unwrap();
+        assert_eq!(table.get("ß"), Some("ss"));
+        assert_eq!(table.iter().count(), 2);
+        assert!(matches!(table.insert("", "a"), Err(NotoxError::Table(_))));
+        assert!(matches!(
+            table.insert("ß", "s s"),
+            Err(NotoxError::Table(_))
+        ));
+        assert!(matches!(table.insert("ß", "é"), Err(NotoxError::Table(_))));
+        assert_eq!(clean("a&b", table), PathBuf::from("ab"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_table_from_file() {
+        let table = TransliterationTable::from_json(r#"{"ß": "ss", "æ": "ae"}"#).unwrap();
+        assert_eq!(table.get("ß"), Some("ss"));
+        let table = TransliterationTable::from_toml("\"ß\" = \"ss\"\n\"ø\" = \"oe\"\n").unwrap();
+        assert_eq!(table.get("ø"), Some("oe"));
+        assert!(TransliterationTable::from_json(r#"{"ß": 1}"#).is_err());
+        assert!(TransliterationTable::from_toml("\"ß\" = \"s/s\"").is_err());
+
+        let table_path = PathBuf::from("test_table.toml");
+        std::fs::write(&table_path, "\"ß\" = \"ss\"\n").unwrap();
+        let vec_args = [
+            "notox".to_string(),
+            "--table".to_string(),
+            "test_table.toml".to_string(),
+        ];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert_eq!(options.table.get("ß"), Some("ss"));
+        std::fs::remove_file(&table_path).unwrap();
+
+        let vec_args = ["notox".to_string(), "--table".to_string()];
+        assert_eq!(notox::parse_args(&vec_args), Err(2));
+        let vec_args = [
+            "notox".to_string(),
+            "--table".to_string(),
+            "missing_table.json".to_string(),
+        ];
+        assert_eq!(notox::parse_args(&vec_args), Err(2));
+    }
+}
@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, ffi::OsStr, path::PathBuf};
+
+    use notox::{
+        trace_name, CaseMode, ExtensionOptions, Notox, NotoxArgs, NotoxMode, PathChange,
+        TraceAction, TraceStep,
+    };
+
+    #[test]
+    fn test_trace_name() {
+        let (cleaned, steps) = trace_name(OsStr::new("Ça_-_va"), &NotoxArgs::default());
+        assert_eq!(cleaned, "Ca-va");
+        assert_eq!(
+            steps,
+            vec![
+                TraceStep {
+                    range: 0..2,
+                    original: Some('Ç'),
+                    action: TraceAction::Mapped("C".to_string()),
+                },
+                TraceStep {
+                    range: 2..3,
+                    original: Some('a'),
+                    action: TraceAction::Kept,
+                },
+                TraceStep {
+                    range: 3..4,
+                    original: Some('_'),
+                    action: TraceAction::Kept,
+                },
+                TraceStep {
+                    range: 4..5,
+                    original: Some('-'),
+                    action: TraceAction::SeparatorRule,
+                },
+                TraceStep {
+                    range: 5..6,
+                    original: Some('_'),
+                    action: TraceAction::Collapsed,
+                },
+                TraceStep {
+                    range: 6..7,
+                    original: Some('v'),
+                    action: TraceAction::Kept,
+                },
+                TraceStep {
+                    range: 7..8,
+                    original: Some('a'),
+                    action: TraceAction::Kept,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_trace_actions() {
+        let actions = |name: &str| {
+            trace_name(OsStr::new(name), &NotoxArgs::default())
+                .1
+                .into_iter()
+                .map(|one_step| one_step.action)
+                .collect::<Vec<TraceAction>>()
+        };
+        assert_eq!(
+            actions("a b"),
+            vec![TraceAction::Kept, TraceAction::Separator, TraceAction::Kept]
+        );
+        assert_eq!(
+            actions("a日"),
+            vec![TraceAction::Kept, TraceAction::Separator]
+        );
+        assert_eq!(
+            actions("日"),
+            vec![
+                TraceAction::Dropped,
+                TraceAction::Fallback("80d4e4fd".to_string())
+            ]
+        );
+        assert_eq!(actions("é"), vec![TraceAction::Mapped("e".to_string())]);
+    }
+
+    #[test]
+    fn test_trace_ranges() {
+        let name = "Les (vieux) Ñandús -- été 2024 🦀.txt";
+        let (cleaned, steps) = trace_name(OsStr::new(name), &NotoxArgs::default());
+        let mut end = 0;
+        // the steps of the characters, without the one trimming the stem
+        for one_step in steps
+            .into_iter()
+            .filter(|one_step| one_step.original.is_some())
+        {
+            assert_eq!(one_step.range.start, end);
+            assert_eq!(
+                &name[one_step.range.clone()],
+                one_step.original.unwrap().to_string()
+            );
+            end = one_step.range.end;
+        }
+        assert_eq!(end, name.len());
+        assert_eq!(cleaned, "Les_vieux_Nandus-ete_2024.txt");
+    }
+
+    #[test]
+    fn test_trace_pipeline() {
+        let notox_args = NotoxArgs {
+            case: Some(CaseMode::Snake),
+            extension: ExtensionOptions {
+                lowercase: true,
+                synonyms: true,
+            },
+            ..NotoxArgs::default()
+        };
+        let (cleaned, steps) = trace_name(OsStr::new("My File.JPEG"), &notox_args);
+        assert_eq!(cleaned, "my_file.jpg");
+        assert_eq!(
+            steps
+                .into_iter()
+                .filter(|one_step| one_step.action != TraceAction::Kept)
+                .collect::<Vec<TraceStep>>(),
+            vec![
+                TraceStep {
+                    range: 2..3,
+                    original: Some(' '),
+                    action: TraceAction::Separator,
+                },
+                TraceStep {
+                    range: 0..7,
+                    original: None,
+                    action: TraceAction::Cased("my_file".to_string()),
+                },
+                TraceStep {
+                    range: 7..12,
+                    original: None,
+                    action: TraceAction::ExtensionCleaned(".jpg".to_string()),
+                },
+            ]
+        );
+
+        let (cleaned, steps) = trace_name(OsStr::new("a .txt"), &NotoxArgs::default());
+        assert_eq!(cleaned, "a.txt");
+        assert_eq!(
+            steps[2],
+            TraceStep {
+                range: 0..2,
+                original: None,
+                action: TraceAction::TrimmedBeforeExtension,
+            }
+        );
+        assert_eq!(steps[3].range, 2..3);
+
+        let (cleaned, steps) = trace_name(OsStr::new("日本.txt"), &NotoxArgs::default());
+        assert_eq!(cleaned, "33de0017.txt");
+        assert_eq!(
+            steps.last(),
+            Some(&TraceStep {
+                range: 0..10,
+                original: None,
+                action: TraceAction::Fallback("33de0017.txt".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_explain() {
+        let vec_args = [
+            "notox".to_string(),
+            "--explain".to_string(),
+            "a b.txt".to_string(),
+        ];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert_eq!(options.mode, NotoxMode::Explain("a b.txt".to_string()));
+        let res = Notox::new(options).run(&HashSet::new());
+        assert_eq!(
+            res,
+            vec![PathChange::WouldRename {
+                path: PathBuf::from("a b.txt"),
+                modified: PathBuf::from("a_b.txt"),
+                fallback: false,
+            }]
+        );
+
+        let vec_args = ["notox".to_string(), "--explain".to_string()];
+        assert_eq!(notox::parse_args(&vec_args), Err(2));
+    }
+}
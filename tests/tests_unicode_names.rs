@@ -0,0 +1,61 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use notox::{NotoxArgs, PathChange};
+
+    use crate::common;
+
+    fn clean(name: &str, unicode_names: bool) -> PathBuf {
+        let notox_args = NotoxArgs {
+            unicode_names,
+            ..NotoxArgs::default()
+        };
+        common::clean(name, &notox_args)
+    }
+
+    #[test]
+    fn test_unicode_names() {
+        assert_eq!(clean("☃.txt", true), PathBuf::from("snowman.txt"));
+        assert_eq!(clean("♥", true), PathBuf::from("black_heart_suit"));
+        assert_eq!(
+            clean("I ♥ NY", true),
+            PathBuf::from("I_black_heart_suit_NY")
+        );
+        assert_eq!(clean("a☃b", true), PathBuf::from("a_snowman_b"));
+        assert_eq!(clean("☃☃.txt", true), PathBuf::from("snowman_snowman.txt"));
+        assert_eq!(clean("x→y", true), PathBuf::from("x_rightwards_arrow_y"));
+        assert_eq!(clean("notes☃", true), PathBuf::from("notes_snowman"));
+        assert_eq!(clean("✂-cut", true), PathBuf::from("black_scissors-cut"));
+        // the mapped characters are not named
+        assert_eq!(clean("café☃.txt", true), PathBuf::from("cafe_snowman.txt"));
+    }
+
+    #[test]
+    fn test_unicode_names_disabled() {
+        assert_eq!(clean("a☃b", false), PathBuf::from("a_b"));
+        assert_eq!(clean("☃.txt", false), PathBuf::from("bc546a5a.txt"));
+    }
+
+    #[test]
+    fn test_unicode_names_warning() {
+        let change = PathChange::WouldRename {
+            path: PathBuf::from("☃.txt"),
+            modified: PathBuf::from("snowman.txt"),
+            fallback: false,
+        };
+        assert_eq!(change.warning(), None);
+    }
+
+    #[test]
+    fn test_parse_args_unicode_names() {
+        let vec_args = ["notox".to_string(), "--unicode-names".to_string()];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert!(options.unicode_names);
+        let vec_args = ["notox".to_string()];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert!(!options.unicode_names);
+    }
+}
@@ -0,0 +1,99 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use notox::{NotoxArgs, SymbolWords, SYMBOL_WORDS};
+
+    use crate::common;
+
+    fn clean(name: &str, words: Option<SymbolWords>) -> PathBuf {
+        let notox_args = NotoxArgs {
+            words,
+            ..NotoxArgs::default()
+        };
+        common::clean(name, &notox_args)
+    }
+
+    #[test]
+    fn test_words() {
+        let words = || Some(SymbolWords::default());
+        assert_eq!(
+            clean("Tom & Jerry.mp4", words()),
+            PathBuf::from("Tom_and_Jerry.mp4")
+        );
+        assert_eq!(clean("50%.txt", words()), PathBuf::from("50pct.txt"));
+        assert_eq!(clean("€20.pdf", words()), PathBuf::from("EUR20.pdf"));
+        assert_eq!(
+            clean("C++ notes", words()),
+            PathBuf::from("Cplusplus_notes")
+        );
+        assert_eq!(clean("20°C", words()), PathBuf::from("20degC"));
+        assert_eq!(clean("© 2024", words()), PathBuf::from("C_2024"));
+        assert_eq!(clean("me@home", words()), PathBuf::from("meathome"));
+        for (symbol, word) in SYMBOL_WORDS {
+            assert_eq!(SymbolWords::default().get(*symbol), Some(*word));
+        }
+    }
+
+    #[test]
+    fn test_words_disabled() {
+        assert_eq!(
+            clean("Tom & Jerry.mp4", None),
+            PathBuf::from("Tom_Jerry.mp4")
+        );
+        assert_eq!(clean("€20.pdf", None), PathBuf::from("20.pdf"));
+    }
+
+    #[test]
+    fn test_words_override() {
+        let mut words = SymbolWords::default();
+        words.insert('&', "und").unwrap();
+        assert_eq!(
+            clean("Tom & Jerry", Some(words.clone())),
+            PathBuf::from("Tom_und_Jerry")
+        );
+        words.insert_spec("&=et").unwrap();
+        words.insert_spec("==eq").unwrap();
+        assert_eq!(clean("a&b=c", Some(words)), PathBuf::from("aetbeqc"));
+        // only the symbols of the list are spelled out
+        let mut words = SymbolWords::new();
+        words.insert('&', "y").unwrap();
+        assert_eq!(clean("a & b%", Some(words)), PathBuf::from("a_y_b_"));
+    }
+
+    #[test]
+    fn test_words_invalid() {
+        let mut words = SymbolWords::new();
+        assert!(words.insert('&', "a b").is_err());
+        assert!(words.insert_spec("&und").is_err());
+        assert!(words.insert_spec("").is_err());
+        assert!(words.is_empty());
+    }
+
+    #[test]
+    fn test_parse_args_words() {
+        let vec_args = ["notox".to_string(), "--words".to_string()];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert_eq!(options.words, Some(SymbolWords::default()));
+        let vec_args = [
+            "notox".to_string(),
+            "--word".to_string(),
+            "&=und".to_string(),
+        ];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        let words = options.words.unwrap();
+        assert_eq!(words.get('&'), Some("und"));
+        assert_eq!(words.get('%'), Some("pct"));
+        let vec_args = [
+            "notox".to_string(),
+            "--word".to_string(),
+            "&und".to_string(),
+        ];
+        assert_eq!(notox::parse_args(&vec_args).err(), Some(2));
+        let vec_args = ["notox".to_string()];
+        let (options, _) = notox::parse_args(&vec_args).unwrap();
+        assert_eq!(options.words, None);
+    }
+}